@@ -13,6 +13,8 @@ use lettre::{Message, SmtpTransport, Transport};
 /// Enum representing the authentication methods for the CLI.
 #[derive(Debug, Clone, ValueEnum)]
 pub enum CLIAuthenticationMethod {
+    /// First working identity: environment, workload identity, managed identity, `az login`.
+    DefaultChain,
     ManagedIdentity,
     ServicePrincipal,
     SharedKey,
@@ -98,6 +100,14 @@ async fn send_email_with_api(
     display_name: &str,
 ) {
     let acs_client_builder: ACSClientBuilder = match auth_method {
+        CLIAuthenticationMethod::DefaultChain => {
+            info!("Using the default credential chain");
+            let host_name = get_env_var("ASC_URL");
+            debug!("host_name: {}", host_name);
+            ACSClientBuilder::new()
+                .default_credential_chain()
+                .host(host_name.as_str())
+        }
         CLIAuthenticationMethod::ManagedIdentity => {
             info!("Using Managed Identity");
             let host_name = get_env_var("ASC_URL");
@@ -166,7 +176,7 @@ async fn send_email_with_api(
                 tokio::time::sleep(time::Duration::from_secs(5)).await;
                 let resp_status = acs_client.get_email_status(&message_resp_id).await;
                 if let Ok(status) = resp_status {
-                    info!("{}\r\n", status.to_string());
+                    info!("{}\r\n", status);
                     if matches!(
                         status,
                         EmailSendStatusType::Unknown
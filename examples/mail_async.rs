@@ -68,17 +68,19 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Send the email with a callback
     // The callback will be called when the rest api sends the email
     let res = acs_client
-        .send_email_with_callback(&send_email, |msg_id, status, error| {
+        .send_email_with_async_callback(&send_email, |msg_id, status, error| {
             info!(
                 "Email with id: {} has status: {:?} and error: {:?}",
                 msg_id, status, error
             );
+            std::future::ready(())
         })
         .await;
 
     match res {
-        Ok((id, rx)) => {
-            let _ = rx.await;
+        Ok(handle) => {
+            let id = handle.operation_id().to_string();
+            let _ = handle.await;
             info!("Email sent successfully with id: {}", id);
         }
         Err(e) => error!("Failed to send email: {:?}", e),
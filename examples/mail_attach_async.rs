@@ -83,20 +83,22 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .build()
         .expect("Failed to build SentEmail");
 
-    // send_email_with_callback emits tracing spans — visible at RUST_LOG=debug
+    // send_email_with_async_callback emits tracing spans — visible at RUST_LOG=debug
     let res = client
-        .send_email_with_callback(&email, |msg_id, status, err| {
+        .send_email_with_async_callback(&email, |msg_id, status, err| {
             if let Some(e) = err {
                 error!(message_id = %msg_id, err = %e, "Callback error");
             } else {
                 info!(message_id = %msg_id, %status, "Status update");
             }
+            std::future::ready(())
         })
         .await;
 
     match res {
-        Ok((id, rx)) => {
-            let _ = rx.await;
+        Ok(handle) => {
+            let id = handle.operation_id().to_string();
+            let _ = handle.await;
             info!(message_id = %id, "Done");
         }
         Err(e) => error!(err = %e, "Failed to send"),
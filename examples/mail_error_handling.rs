@@ -22,9 +22,13 @@ fn get_env_var(name: &str) -> String {
 
 fn handle_error(err: ACSError) {
     match err {
-        ACSError::RateLimitExceeded { retries } => {
+        ACSError::RateLimitExceeded {
+            retries,
+            retry_after,
+        } => {
             warn!(
                 retries,
+                retry_after_ms = retry_after.map(|d| d.as_millis() as u64),
                 "Rate limit hit after all retries — implement back-off at call site"
             );
         }
@@ -64,7 +64,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     match client.send_email(&email).await {
         Ok(id) => info!(message_id = %id, "Email accepted"),
-        Err(ACSError::RateLimitExceeded { retries }) => {
+        Err(ACSError::RateLimitExceeded { retries, .. }) => {
             warn!(
                 retries,
                 "All retries exhausted — consider increasing max_retries \
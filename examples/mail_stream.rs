@@ -70,8 +70,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 info!(message_id = %message_id, "Delivery confirmed");
                 break;
             }
-            Ok(EmailSendStatusType::Failed | EmailSendStatusType::Canceled) => {
-                error!(message_id = %message_id, "Delivery failed");
+            Ok(EmailSendStatusType::Canceled) => {
+                error!(message_id = %message_id, "Delivery canceled");
+                break;
+            }
+            Err(e @ ACSError::DeliveryFailed { .. }) => {
+                error!(message_id = %message_id, err = %e, "Delivery failed");
                 break;
             }
             Ok(EmailSendStatusType::Unknown) => {
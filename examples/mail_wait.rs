@@ -89,7 +89,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .await
     {
         Ok(EmailSendStatusType::Succeeded) => info!("Delivered"),
-        Ok(EmailSendStatusType::Failed) => error!("Delivery failed"),
+        Err(e @ ACSError::DeliveryFailed { .. }) => error!(err = %e, "Delivery failed"),
         Ok(status) => warn!(%status, "Unexpected terminal status"),
         Err(ACSError::Timeout) => warn!("Timed out — email may still be in transit"),
         Err(e) => error!(err = %e, "Send failed"),
@@ -124,7 +124,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .await
     {
         Ok(EmailSendStatusType::Succeeded) => info!("Delivered"),
-        Ok(EmailSendStatusType::Failed) => error!("Delivery failed"),
+        Err(e @ ACSError::DeliveryFailed { .. }) => error!(err = %e, "Delivery failed"),
         Ok(status) => warn!(%status, "Unexpected terminal status"),
         Err(ACSError::Canceled) => {
             warn!("Wait cancelled — email is still queued in ACS and may be delivered later")
@@ -0,0 +1,170 @@
+//! Shared-key access keys with runtime rotation and secondary-key fallback.
+//!
+//! An ACS resource has a primary and a secondary access key so that one can
+//! be regenerated while clients use the other.  A connection-string client
+//! keeps its keys in [`AccessKeys`], shared by every clone of the client:
+//!
+//! - Requests are signed with the *active* key — initially the one in the
+//!   connection string.
+//! - [`ACSClient::rotate_key`] makes a new key active at runtime, for all
+//!   clones at once.  The previously active key becomes the fallback.
+//! - When ACS answers `401 Unauthorized` to a signed request and a fallback
+//!   key is known (the previous key, or one set with
+//!   [`ACSClientBuilder::secondary_key`]), the two keys swap and the request
+//!   is re-signed and sent once more.
+//!
+//! Rotating in the order *switch to secondary → regenerate primary → switch
+//! back* therefore causes no failed sends, even if a new key reaches a client
+//! before it is valid in ACS.
+//!
+//! ```rust,ignore
+//! let client = ACSClientBuilder::new()
+//!     .connection_string(&primary_connection_string)
+//!     .secondary_key(&secondary_key)
+//!     .build()?;
+//!
+//! // After regenerating the primary key in the portal:
+//! client.rotate_key(&new_primary_key)?;
+//! ```
+//!
+//! [`ACSClient::rotate_key`]: crate::adapters::gateways::acs_email::ACSClient::rotate_key
+//! [`ACSClientBuilder::secondary_key`]: crate::adapters::gateways::acs_email::ACSClientBuilder::secondary_key
+
+use crate::domain::entities::models::ACSError;
+use base64::{engine::general_purpose, Engine as _};
+use std::fmt;
+use std::sync::{Arc, RwLock};
+use tracing::warn;
+
+struct Keys {
+    active: String,
+    fallback: Option<String>,
+    /// Incremented whenever the active key changes.
+    generation: u64,
+}
+
+/// Access keys shared by the clones of a connection-string `ACSClient`.
+///
+/// Obtain it with [`ACSClient::access_keys`].
+///
+/// [`ACSClient::access_keys`]: crate::adapters::gateways::acs_email::ACSClient::access_keys
+#[derive(Clone)]
+pub struct AccessKeys {
+    inner: Arc<RwLock<Keys>>,
+}
+
+impl fmt::Debug for AccessKeys {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AccessKeys")
+            .field("has_fallback", &self.has_fallback())
+            .field("generation", &self.generation())
+            .finish_non_exhaustive()
+    }
+}
+
+impl AccessKeys {
+    /// Sign with `primary`, falling back to `secondary` on `401`.
+    pub(crate) fn new(primary: String, secondary: Option<String>) -> Self {
+        AccessKeys {
+            inner: Arc::new(RwLock::new(Keys {
+                active: primary,
+                fallback: secondary,
+                generation: 0,
+            })),
+        }
+    }
+
+    /// Make `new_key` the active key; the current one becomes the fallback.
+    ///
+    /// # Errors
+    ///
+    /// [`ACSError::Auth`] when `new_key` is not a base64 access key.
+    pub fn rotate(&self, new_key: &str) -> Result<(), ACSError> {
+        validate_key(new_key).map_err(ACSError::Auth)?;
+        let mut keys = self.inner.write().unwrap();
+        if keys.active == new_key {
+            return Ok(());
+        }
+        let previous = std::mem::replace(&mut keys.active, new_key.to_string());
+        keys.fallback = Some(previous);
+        keys.generation += 1;
+        Ok(())
+    }
+
+    /// Number of times the active key has changed, by rotation or fallback.
+    pub fn generation(&self) -> u64 {
+        self.inner.read().unwrap().generation
+    }
+
+    /// `true` when a fallback key is available.
+    pub fn has_fallback(&self) -> bool {
+        self.inner.read().unwrap().fallback.is_some()
+    }
+
+    /// The key to sign requests with.
+    pub(crate) fn active(&self) -> String {
+        self.inner.read().unwrap().active.clone()
+    }
+
+    /// Swap in the fallback key after ACS rejected a request signed when
+    /// [`generation`] was `seen`.  Returns `true` when the request should be
+    /// re-signed: the keys were swapped now, or already changed since.
+    ///
+    /// [`generation`]: AccessKeys::generation
+    pub(crate) fn fail_over(&self, seen: u64) -> bool {
+        let mut keys = self.inner.write().unwrap();
+        if keys.generation != seen {
+            return true;
+        }
+        let Some(fallback) = keys.fallback.take() else {
+            return false;
+        };
+        let rejected = std::mem::replace(&mut keys.active, fallback);
+        keys.fallback = Some(rejected);
+        keys.generation += 1;
+        warn!("ACS rejected the active access key; switched to the fallback key");
+        true
+    }
+}
+
+/// Check that `key` is a base64 access key.
+pub(crate) fn validate_key(key: &str) -> Result<(), String> {
+    general_purpose::STANDARD
+        .decode(key)
+        .map(|_| ())
+        .map_err(|e| format!("Invalid access key: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rotation_keeps_previous_key_as_fallback() {
+        let keys = AccessKeys::new("a2V5LTE=".to_string(), None);
+        assert!(!keys.has_fallback());
+        keys.rotate("a2V5LTI=").unwrap();
+        assert_eq!(keys.active(), "a2V5LTI=");
+        assert!(keys.has_fallback());
+        assert_eq!(keys.generation(), 1);
+
+        // Rotating to the active key changes nothing.
+        keys.rotate("a2V5LTI=").unwrap();
+        assert_eq!(keys.generation(), 1);
+        assert!(matches!(keys.rotate("not base64!"), Err(ACSError::Auth(_))));
+    }
+
+    #[test]
+    fn fail_over_swaps_once_per_generation() {
+        let keys = AccessKeys::new("cHJpbWFyeQ==".to_string(), Some("c2Vjb25kYXJ5".to_string()));
+        let seen = keys.generation();
+        assert!(keys.fail_over(seen));
+        assert_eq!(keys.active(), "c2Vjb25kYXJ5");
+        // A concurrent request rejected with the old key just re-signs.
+        assert!(keys.fail_over(seen));
+        assert_eq!(keys.active(), "c2Vjb25kYXJ5");
+
+        let without_fallback = AccessKeys::new("cHJpbWFyeQ==".to_string(), None);
+        assert!(!without_fallback.fail_over(0));
+    }
+}
@@ -6,10 +6,15 @@
 //!   the shared [`reqwest::Client`] once so all requests reuse the same connection pool.
 //! - [`ACSClient`] — clone-cheap handle (all fields behind `Arc` or `Clone`).
 //!   Owns the HTTP client and dispatches the public operations:
-//!   [`send_email`], [`send_email_idempotent`], [`send_email_and_wait`],
+//!   [`send_email_with`], [`send_email`], [`send_email_idempotent`], [`send_email_and_wait`],
 //!   [`send_email_and_wait_cancellable`], [`send_emails_batch`],
-//!   [`send_email_with_callback`], [`send_email_with_callback_cancellable`],
-//!   [`send_email_stream`], [`send_email_stream_cancellable`], and [`get_email_status`].
+//!   [`send_emails_batch_report`], [`send_stream`], [`send_stream_and_wait`],
+//!   [`send_email_with_async_callback`], [`operation_callback`],
+//!   [`send_email_with_callback`] and [`send_email_with_callback_cancellable`]
+//!   (deprecated),
+//!   [`send_email_stream`], [`send_email_stream_cancellable`], [`get_email_status`],
+//!   [`get_email_operation`], [`wait_for_operation`], [`operation_stream`], and
+//!   [`resume_poller`].
 //!
 //! # Pool-friendly usage
 //!
@@ -33,15 +38,34 @@
 //! for h in handles { let _ = h.await; }
 //! ```
 //!
-//! Or use [`send_emails_batch`] to send a slice concurrently in one call.
+//! Or use [`send_emails_batch`] to send a slice concurrently in one call; its
+//! concurrency adapts to ACS throttling (see the `concurrency` module).
+//!
+//! # Per-request options
+//!
+//! The specialised `send_email*` methods are thin wrappers over
+//! [`send_email_with`], which takes a [`SendOptions`] value covering the
+//! idempotency key, per-request timeout, retry budget, extra headers,
+//! cancellation token and [`WaitMode`] for a single call.
+//!
+//! # Status polling
+//!
+//! Every waiting, streaming and callback API subscribes to the client's
+//! [`OperationTracker`] (see [`track_operation`]), which polls all pending
+//! operations from one background task and shares each GET between the
+//! subscribers of an operation.
 //!
 //! # Retry behaviour
 //!
-//! `429 Too Many Requests` and `503 Service Unavailable` responses trigger automatic
-//! retries.  The delay is taken from the `Retry-After` response header when present;
-//! otherwise exponential backoff (`2^n` seconds, n = retry index) is used.
-//! When all retries are exhausted the call fails with
-//! [`ACSError::RateLimitExceeded`].  Set `.max_retries(0)` to disable retries.
+//! Sends and status polls are retried according to the client's
+//! [`RetryPolicy`] (default: [`ExponentialBackoff`] with full jitter).  By
+//! default `429`, `500`, `502`, `503` and `504` responses and network errors
+//! are retried.  The delay is taken from the `Retry-After` response header when
+//! present (`retry-after-ms`, `x-ms-retry-after-ms`, or `Retry-After` as
+//! seconds or an HTTP-date); otherwise the policy's back-off is used.  When all retries on
+//! `429` / `503` are exhausted the call fails with
+//! [`ACSError::RateLimitExceeded`].  Set `.max_retries(0)` to disable retries,
+//! or install a custom policy with [`ACSClientBuilder::retry_policy`].
 //!
 //! # Authentication
 //!
@@ -49,48 +73,111 @@
 //!
 //! | Method | `Authorization` header strategy |
 //! |---|---|
-//! | [`SharedKey`] | HMAC-SHA256 signed per-request (see `acs_shared_key`) |
-//! | [`ServicePrincipal`] | OAuth2 client-credentials token via `azure_identity` |
-//! | [`ManagedIdentity`] | Ambient managed-identity token via `azure_identity` |
+//! | [`connection_string`] | HMAC-SHA256 signed per-request (see `acs_shared_key`) |
+//! | [`service_principal`] | OAuth2 client-credentials token via `azure_identity` |
+//! | `service_principal_certificate` | Client assertion JWT signed with a certificate, exchanged for a token (`certificate` feature) |
+//! | [`managed_identity`] | Ambient managed-identity token via `azure_identity` |
+//! | [`managed_identity_client_id`], [`managed_identity_resource_id`] | User-assigned managed-identity token from IMDS |
+//! | [`workload_identity`] | Federated token file exchanged for an Entra ID token |
+//! | [`default_credential_chain`] | First working source: environment, workload identity, managed identity, Azure CLI |
+//! | [`token_credential`], [`static_token`], [`token_provider`] | Caller-supplied token (see the `credentials` module) |
 //!
-//! Token acquisition for service principal / managed identity happens inside
-//! each request; responses are not cached.
+//! Bearer tokens are kept in a
+//! [`TokenCache`] shared by every clone of the client: refreshed ahead of
+//! expiry, fetched once for concurrent requests, and refreshed when ACS
+//! answers `401` (see the `token_cache` module).  The Entra ID authority and
+//! token scope follow the Azure cloud selected with [`cloud`] (see the
+//! `cloud` module).
 //!
+//! Shared access keys are kept in [`AccessKeys`]: [`rotate_key`] swaps the
+//! active key for all clones, and a `401` falls back to the previous or
+//! [`secondary_key`] (see the `access_keys` module).
+//!
+//! [`send_email_with`]: ACSClient::send_email_with
+//! [`track_operation`]: ACSClient::track_operation
+//! [`send_email_with_async_callback`]: ACSClient::send_email_with_async_callback
+//! [`operation_callback`]: ACSClient::operation_callback
+//! [`resume_poller`]: ACSClient::resume_poller
 //! [`send_email`]: ACSClient::send_email
 //! [`send_email_idempotent`]: ACSClient::send_email_idempotent
 //! [`send_email_and_wait`]: ACSClient::send_email_and_wait
 //! [`send_email_and_wait_cancellable`]: ACSClient::send_email_and_wait_cancellable
 //! [`send_emails_batch`]: ACSClient::send_emails_batch
+//! [`send_emails_batch_report`]: ACSClient::send_emails_batch_report
+//! [`send_stream`]: ACSClient::send_stream
+//! [`send_stream_and_wait`]: ACSClient::send_stream_and_wait
 //! [`send_email_with_callback`]: ACSClient::send_email_with_callback
 //! [`send_email_with_callback_cancellable`]: ACSClient::send_email_with_callback_cancellable
 //! [`send_email_stream`]: ACSClient::send_email_stream
 //! [`send_email_stream_cancellable`]: ACSClient::send_email_stream_cancellable
 //! [`get_email_status`]: ACSClient::get_email_status
-//! [`SharedKey`]: ACSAuthMethod::SharedKey
-//! [`ServicePrincipal`]: ACSAuthMethod::ServicePrincipal
-//! [`ManagedIdentity`]: ACSAuthMethod::ManagedIdentity
+//! [`get_email_operation`]: ACSClient::get_email_operation
+//! [`wait_for_operation`]: ACSClient::wait_for_operation
+//! [`operation_stream`]: ACSClient::operation_stream
+//! [`connection_string`]: ACSClientBuilder::connection_string
+//! [`cloud`]: ACSClientBuilder::cloud
+//! [`rotate_key`]: ACSClient::rotate_key
+//! [`secondary_key`]: ACSClientBuilder::secondary_key
+//! [`service_principal`]: ACSClientBuilder::service_principal
+//! [`managed_identity`]: ACSClientBuilder::managed_identity
+//! [`managed_identity_client_id`]: ACSClientBuilder::managed_identity_client_id
+//! [`managed_identity_resource_id`]: ACSClientBuilder::managed_identity_resource_id
+//! [`workload_identity`]: ACSClientBuilder::workload_identity
+//! [`default_credential_chain`]: ACSClientBuilder::default_credential_chain
+//! [`token_credential`]: ACSClientBuilder::token_credential
+//! [`static_token`]: ACSClientBuilder::static_token
+//! [`token_provider`]: ACSClientBuilder::token_provider
 
 // License: MIT
 // This file is part of the Azure Communication Services Email Client Library, an open-source project.
 // This source code is licensed under the MIT license found in the LICENSE file in the root directory of this source tree.
 
+use crate::adapters::gateways::access_keys::{validate_key, AccessKeys};
 use crate::adapters::gateways::acs_shared_key::{get_request_header, parse_endpoint};
+use crate::adapters::gateways::batch::BatchReport;
+use crate::adapters::gateways::cloud::AzureCloud;
+use crate::adapters::gateways::concurrency::{
+    AdaptiveConcurrency, ConcurrencyPermit, ConcurrencySnapshot, DEFAULT_CONCURRENCY_CEILING,
+    DEFAULT_CONCURRENCY_FLOOR,
+};
+use crate::adapters::gateways::credential_chain::{ChainedCredential, CredentialChain};
+#[cfg(feature = "certificate")]
+use crate::adapters::gateways::credentials::ClientCertificateCredential;
+use crate::adapters::gateways::credentials::{
+    ManagedIdentityCredential, ManagedIdentityId, StaticTokenCredential, TokenProviderCredential,
+    WorkloadIdentityCredential,
+};
+use crate::adapters::gateways::events::{EmailEvent, EventBus};
+use crate::adapters::gateways::poll::{FixedInterval, PollStrategy};
+use crate::adapters::gateways::poller::EmailPoller;
+use crate::adapters::gateways::rate_limit::{RateLimitMode, RateLimiter};
+use crate::adapters::gateways::resend::ResendPolicy;
+use crate::adapters::gateways::retry::{
+    parse_retry_after, ExponentialBackoff, RetryPolicy, DEFAULT_MAX_RETRIES,
+};
+use crate::adapters::gateways::token_cache::{TokenCache, DEFAULT_REFRESH_MARGIN};
+use crate::adapters::gateways::tracker::{OperationSubscription, OperationTracker};
+use crate::adapters::gateways::watchdog::{DeliverySla, SlaSummary};
 use crate::domain::entities::models::{
-    ACSError, EmailSendStatusType, ErrorResponse, SentEmail, SentEmailResponse,
+    ACSError, EmailSendStatusType, ErrorDetail, ErrorResponse, SentEmail, SentEmailResponse,
 };
 use async_stream::stream;
-use azure_core::auth::TokenCredential;
+use azure_core::auth::{AccessToken, TokenCredential};
 use azure_core::HttpClient;
 use azure_identity::{create_credential, ClientSecretCredential};
-use futures::stream::Stream;
-use reqwest::header::RETRY_AFTER;
+use futures::stream::{Stream, StreamExt};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
 use reqwest::{Client, StatusCode};
+use std::future::Future;
+use std::pin::Pin;
 use std::sync::Arc;
-use std::time::Duration;
-use tokio::sync::oneshot;
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant, SystemTime};
+use tokio::sync::{broadcast, oneshot};
+use tokio::task::JoinHandle;
 use tokio::time::sleep;
 use tokio_util::sync::CancellationToken;
-use tracing::{debug, error, instrument};
+use tracing::{debug, error, instrument, warn};
 use url::Url;
 use uuid::Uuid;
 
@@ -124,23 +211,50 @@ impl ACSApiVersion {
             ACSApiVersion::V20250901 => "2025-09-01",
         }
     }
+
+    /// Inverse of [`as_str`](ACSApiVersion::as_str).
+    pub(crate) fn from_version_str(version: &str) -> Option<Self> {
+        match version {
+            "2023-03-31" => Some(ACSApiVersion::V20230331),
+            "2025-09-01" => Some(ACSApiVersion::V20250901),
+            _ => None,
+        }
+    }
 }
 
 // Azure Communication Services (ACS) authentication method
 #[derive(Clone)]
 enum ACSAuthMethod {
-    SharedKey(String),
+    /// HMAC signing with the active key; shared by every clone of the client.
+    SharedKey(AccessKeys),
+    /// Entra ID bearer token, cached and shared by every clone of the client.
+    Bearer(TokenCache),
+}
+
+// Token-based authentication selected on the builder; turned into a
+// credential for `ACSAuthMethod::Bearer` by `build`.
+enum TokenSource {
     ServicePrincipal {
         tenant_id: String,
         client_id: String,
         client_secret: String,
     },
-    ManagedIdentity,
+    #[cfg(feature = "certificate")]
+    ServicePrincipalCertificate {
+        tenant_id: String,
+        client_id: String,
+        certificate_path: std::path::PathBuf,
+    },
+    ManagedIdentity(ManagedIdentityId),
+    WorkloadIdentity {
+        tenant_id: String,
+        client_id: String,
+        token_file: std::path::PathBuf,
+    },
+    Chain(CredentialChain),
+    Credential(Arc<dyn TokenCredential>),
 }
 
-/// Default number of retries for `429 Too Many Requests` and `503 Service Unavailable` responses.
-const DEFAULT_MAX_RETRIES: u32 = 3;
-
 /// Default interval between status-poll requests.
 const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(5);
 
@@ -185,7 +299,13 @@ pub struct ACSClient {
     api_version: ACSApiVersion,
     http_client: Client,
     max_retries: u32,
-    poll_interval: Duration,
+    retry_policy: Arc<dyn RetryPolicy>,
+    rate_limiter: Option<RateLimiter>,
+    batch_concurrency: AdaptiveConcurrency,
+    tracker: OperationTracker,
+    events: EventBus,
+    poll_strategy: Arc<dyn PollStrategy>,
+    resend_policy: Option<Arc<ResendPolicy>>,
 }
 
 /// Fluent builder for [`ACSClient`].
@@ -198,19 +318,41 @@ pub struct ACSClient {
 ///
 /// Exactly one of the following must be set:
 /// - `.connection_string(…)` for shared-key auth, **or**
-/// - `.host(…)` + `.service_principal(…)` for service principal auth, **or**
-/// - `.host(…)` + `.managed_identity()` for managed identity auth.
+/// - `.host(…)` + `.service_principal(…)` or `.service_principal_certificate(…)`
+///   for service principal auth, **or**
+/// - `.host(…)` + `.managed_identity()` (or `.managed_identity_client_id(…)` /
+///   `.managed_identity_resource_id(…)`) for managed identity auth, **or**
+/// - `.host(…)` + `.workload_identity(…)` for Entra Workload ID auth, **or**
+/// - `.host(…)` + `.default_credential_chain()` or `.credential_chain(…)` to
+///   use the first working identity, **or**
+/// - `.host(…)` + `.token_credential(…)`, `.static_token(…)` or
+///   `.token_provider(…)` for a caller-supplied token.
 ///
 /// [`build`]: ACSClientBuilder::build
 pub struct ACSClientBuilder {
     host: Option<String>,
     connection_string: Option<String>,
-    auth_method: Option<ACSAuthMethod>,
+    secondary_key: Option<String>,
+    token_source: Option<TokenSource>,
+    #[cfg(feature = "certificate")]
+    certificate_password: Option<String>,
+    token_refresh_margin: Duration,
     api_version: ACSApiVersion,
     max_retries: u32,
+    retry_policy: Option<Arc<dyn RetryPolicy>>,
+    request_quota: Option<(u32, u32)>,
+    recipient_quota: Option<(u32, u32)>,
+    rate_limit_mode: RateLimitMode,
+    batch_concurrency: (usize, usize),
+    status_poll_rate: Option<u32>,
+    delivery_sla: Option<DeliverySla>,
+    resend_policy: Option<Arc<ResendPolicy>>,
     timeout: Option<Duration>,
-    poll_interval: Duration,
+    poll_strategy: Arc<dyn PollStrategy>,
     base_url_override: Option<String>,
+    cloud: AzureCloud,
+    authority_host: Option<String>,
+    managed_identity_endpoint: Option<String>,
 }
 
 impl Default for ACSClientBuilder {
@@ -230,12 +372,27 @@ impl ACSClientBuilder {
         ACSClientBuilder {
             host: None,
             connection_string: None,
-            auth_method: None,
+            secondary_key: None,
+            token_source: None,
+            #[cfg(feature = "certificate")]
+            certificate_password: None,
+            token_refresh_margin: DEFAULT_REFRESH_MARGIN,
             api_version: ACSApiVersion::default(),
             max_retries: DEFAULT_MAX_RETRIES,
+            retry_policy: None,
+            request_quota: None,
+            recipient_quota: None,
+            rate_limit_mode: RateLimitMode::default(),
+            batch_concurrency: (DEFAULT_CONCURRENCY_FLOOR, DEFAULT_CONCURRENCY_CEILING),
+            status_poll_rate: None,
+            delivery_sla: None,
+            resend_policy: None,
             timeout: None,
-            poll_interval: DEFAULT_POLL_INTERVAL,
+            poll_strategy: Arc::new(FixedInterval::new(DEFAULT_POLL_INTERVAL)),
             base_url_override: None,
+            cloud: AzureCloud::default(),
+            authority_host: None,
+            managed_identity_endpoint: None,
         }
     }
 
@@ -257,16 +414,140 @@ impl ACSClientBuilder {
         self
     }
 
-    /// Maximum number of retries on `429 Too Many Requests` or `503 Service Unavailable`.
+    /// Maximum number of retries for transient failures under the default
+    /// [`ExponentialBackoff`] policy.
     ///
-    /// Retries use exponential backoff (`2^n` seconds) unless the server supplies a
+    /// Retries use exponential backoff with full jitter unless the server supplies a
     /// `Retry-After` header, in which case that value is used instead. Exhausting all
-    /// retries yields [`ACSError::RateLimitExceeded`]. Default: `3`.
+    /// retries on `429`/`503` yields [`ACSError::RateLimitExceeded`]. Default: `3`.
+    ///
+    /// Ignored when a custom policy is installed with [`retry_policy`].
+    ///
+    /// [`retry_policy`]: ACSClientBuilder::retry_policy
     pub fn max_retries(mut self, max_retries: u32) -> Self {
         self.max_retries = max_retries;
         self
     }
 
+    /// Install the [`RetryPolicy`] used for sends and status polls.
+    ///
+    /// The policy decides which status codes and error kinds are retried, the
+    /// back-off between attempts, and the attempt / elapsed-time caps.  It
+    /// replaces the default [`ExponentialBackoff`] and takes precedence over
+    /// [`max_retries`].
+    ///
+    /// [`max_retries`]: ACSClientBuilder::max_retries
+    pub fn retry_policy(mut self, policy: impl RetryPolicy + 'static) -> Self {
+        self.retry_policy = Some(Arc::new(policy));
+        self
+    }
+
+    /// Limit sends to `per_minute` and `per_hour` requests on the client side.
+    ///
+    /// The quotas are enforced by a token-bucket [`RateLimiter`] shared by all
+    /// clones of the built client, so sends wait for capacity (or fail fast —
+    /// see [`rate_limit_mode`]) instead of running into ACS `429` responses.
+    /// Status polls are not counted.  Both quotas must be at least 1.
+    /// Default: no limit.
+    ///
+    /// [`rate_limit_mode`]: ACSClientBuilder::rate_limit_mode
+    pub fn rate_limit(mut self, per_minute: u32, per_hour: u32) -> Self {
+        self.request_quota = Some((per_minute, per_hour));
+        self
+    }
+
+    /// Limit sends to `per_minute` and `per_hour` recipients on the client side.
+    ///
+    /// Each send costs the number of its `to`, `cc` and `bcc` addresses.
+    /// Combines with [`rate_limit`]; a send must fit in every configured bucket.
+    /// Both quotas must be at least 1.  Default: no limit.
+    ///
+    /// [`rate_limit`]: ACSClientBuilder::rate_limit
+    pub fn recipient_rate_limit(mut self, per_minute: u32, per_hour: u32) -> Self {
+        self.recipient_quota = Some((per_minute, per_hour));
+        self
+    }
+
+    /// Choose whether sends wait for rate-limit capacity
+    /// ([`RateLimitMode::Wait`], the default) or fail immediately with
+    /// [`ACSError::QuotaExhausted`] ([`RateLimitMode::FailFast`]).
+    pub fn rate_limit_mode(mut self, mode: RateLimitMode) -> Self {
+        self.rate_limit_mode = mode;
+        self
+    }
+
+    /// Bounds for the adaptive concurrency limit used by [`send_emails_batch`].
+    ///
+    /// The limit starts at `floor`, grows while ACS accepts sends and halves on
+    /// every `429` / `503`, but never leaves `[floor, ceiling]`.  The limit is
+    /// shared by all clones of the built client.  Default: `1..=32`.
+    ///
+    /// [`build`] fails when `floor` is `0` or greater than `ceiling`.
+    ///
+    /// [`send_emails_batch`]: ACSClient::send_emails_batch
+    /// [`build`]: ACSClientBuilder::build
+    pub fn batch_concurrency(mut self, floor: usize, ceiling: usize) -> Self {
+        self.batch_concurrency = (floor, ceiling);
+        self
+    }
+
+    /// Limit the status GETs issued by the client's [`OperationTracker`] to
+    /// `per_second` requests per second across all pending operations.
+    ///
+    /// All polling APIs share the tracker, so this caps the total status
+    /// traffic of the client and its clones.  Default: no limit (at most
+    /// [`MAX_CONCURRENT_POLLS`] GETs in flight).
+    ///
+    /// [`MAX_CONCURRENT_POLLS`]: crate::adapters::gateways::tracker::MAX_CONCURRENT_POLLS
+    pub fn status_poll_rate(mut self, per_second: u32) -> Self {
+        self.status_poll_rate = Some(per_second);
+        self
+    }
+
+    /// Watch every operation sent by the client, and any other operation
+    /// tracked by the shared status poller, against a delivery deadline.
+    ///
+    /// Sent operations are polled until they reach a terminal status, even
+    /// when nothing waits on them.  An operation still `NotStarted` /
+    /// `Running` past its threshold is reported once, as soon as the
+    /// threshold passes, with an `Overdue` event and the SLA's handler.  Tag
+    /// sends with [`SendOptions::email_class`] to apply per-class thresholds;
+    /// query [`ACSClient::sla_summary`] for the currently overdue operations.
+    /// Default: no watchdog.
+    pub fn delivery_sla(mut self, sla: DeliverySla) -> Self {
+        self.delivery_sla = Some(sla);
+        self
+    }
+
+    /// Resend emails whose operation ends `Failed` with an error code listed
+    /// in `policy`, in every API that waits for a terminal status:
+    /// [`send_email_and_wait`], [`send_email_with`] with
+    /// [`WaitMode::Terminal`] and [`send_stream_and_wait`].
+    ///
+    /// [`SendOptions::resend`] overrides the policy for a single call.
+    /// Default: no resends.
+    ///
+    /// The policy is ignored by [`send_email_stream`] and the callback APIs
+    /// ([`send_email_with_async_callback`], [`send_email_with_callback`]),
+    /// which report the first operation only, and by the APIs that follow an
+    /// existing operation ID ([`wait_for_operation`], [`operation_stream`],
+    /// [`operation_callback`], [`poller`]), which have no email to resend.
+    ///
+    /// [`send_email_and_wait`]: ACSClient::send_email_and_wait
+    /// [`send_email_with`]: ACSClient::send_email_with
+    /// [`send_stream_and_wait`]: ACSClient::send_stream_and_wait
+    /// [`send_email_stream`]: ACSClient::send_email_stream
+    /// [`send_email_with_async_callback`]: ACSClient::send_email_with_async_callback
+    /// [`send_email_with_callback`]: ACSClient::send_email_with_callback
+    /// [`wait_for_operation`]: ACSClient::wait_for_operation
+    /// [`operation_stream`]: ACSClient::operation_stream
+    /// [`operation_callback`]: ACSClient::operation_callback
+    /// [`poller`]: ACSClient::poller
+    pub fn resend_policy(mut self, policy: ResendPolicy) -> Self {
+        self.resend_policy = Some(Arc::new(policy));
+        self
+    }
+
     /// Per-request HTTP timeout. Applies to every individual request including retries.
     ///
     /// When the timeout elapses before a response is received the request fails with
@@ -279,10 +560,27 @@ impl ACSClientBuilder {
     /// Interval between status-poll requests used by [`send_email_stream`],
     /// [`send_email_and_wait`], and the callback variants. Default: 5 s.
     ///
+    /// Shorthand for `.poll_strategy(FixedInterval::new(interval))`.
+    ///
     /// [`send_email_stream`]: ACSClient::send_email_stream
     /// [`send_email_and_wait`]: ACSClient::send_email_and_wait
     pub fn poll_interval(mut self, interval: Duration) -> Self {
-        self.poll_interval = interval;
+        self.poll_strategy = Arc::new(FixedInterval::new(interval));
+        self
+    }
+
+    /// Install the [`PollStrategy`] that spaces out status polls for every
+    /// polling API, e.g. [`ExponentialPoll`] or [`FibonacciPoll`].  Replaces
+    /// any [`poll_interval`] set earlier.
+    ///
+    /// A `Retry-After` header on a status response overrides the strategy's
+    /// delay for the next poll of that operation.
+    ///
+    /// [`ExponentialPoll`]: crate::adapters::gateways::poll::ExponentialPoll
+    /// [`FibonacciPoll`]: crate::adapters::gateways::poll::FibonacciPoll
+    /// [`poll_interval`]: ACSClientBuilder::poll_interval
+    pub fn poll_strategy(mut self, strategy: impl PollStrategy + 'static) -> Self {
+        self.poll_strategy = Arc::new(strategy);
         self
     }
 
@@ -319,22 +617,35 @@ impl ACSClientBuilder {
         self
     }
 
+    /// Secondary access key, used when ACS rejects a request signed with the
+    /// key from the [`connection_string`].
+    ///
+    /// Keys can also be rotated at runtime with [`ACSClient::rotate_key`]; see
+    /// the `access_keys` module.  Ignored for token-based auth.
+    ///
+    /// [`connection_string`]: ACSClientBuilder::connection_string
+    pub fn secondary_key(mut self, key: &str) -> Self {
+        self.secondary_key = Some(key.to_string());
+        self
+    }
+
     /// Configure Service Principal (client-credentials) authentication.
     ///
-    /// An OAuth2 client-credentials token is fetched from Azure AD on every
-    /// request using the supplied `tenant_id`, `client_id`, and
-    /// `client_secret`.  Tokens are not cached between requests.
+    /// An OAuth2 client-credentials token is fetched from Azure AD using the
+    /// supplied `tenant_id`, `client_id`, and `client_secret`, and cached
+    /// until shortly before it expires (see [`token_refresh_margin`]).
     ///
     /// Requires [`host`] to also be set.
     ///
     /// [`host`]: ACSClientBuilder::host
+    /// [`token_refresh_margin`]: ACSClientBuilder::token_refresh_margin
     pub fn service_principal(
         mut self,
         tenant_id: &str,
         client_id: &str,
         client_secret: &str,
     ) -> Self {
-        self.auth_method = Some(ACSAuthMethod::ServicePrincipal {
+        self.token_source = Some(TokenSource::ServicePrincipal {
             tenant_id: tenant_id.to_string(),
             client_id: client_id.to_string(),
             client_secret: client_secret.to_string(),
@@ -342,10 +653,49 @@ impl ACSClientBuilder {
         self
     }
 
+    /// Configure Service Principal authentication with a client certificate
+    /// instead of a secret.
+    ///
+    /// `certificate_path` is a PEM file holding the certificate and its
+    /// private key, or a PKCS#12 / PFX file (see [`certificate_password`]).  A
+    /// client assertion JWT signed with the key is exchanged for a token,
+    /// which is cached like a client-secret token.  The file is re-read on
+    /// every token refresh, so a certificate rotated on disk is picked up
+    /// without rebuilding the client.
+    ///
+    /// Requires [`host`] to also be set, and the `certificate` Cargo feature.
+    ///
+    /// [`host`]: ACSClientBuilder::host
+    /// [`certificate_password`]: ACSClientBuilder::certificate_password
+    #[cfg(feature = "certificate")]
+    pub fn service_principal_certificate(
+        mut self,
+        tenant_id: &str,
+        client_id: &str,
+        certificate_path: impl AsRef<std::path::Path>,
+    ) -> Self {
+        self.token_source = Some(TokenSource::ServicePrincipalCertificate {
+            tenant_id: tenant_id.to_string(),
+            client_id: client_id.to_string(),
+            certificate_path: certificate_path.as_ref().to_path_buf(),
+        });
+        self
+    }
+
+    /// Password of the PKCS#12 / PFX file given to
+    /// [`service_principal_certificate`].
+    ///
+    /// [`service_principal_certificate`]: ACSClientBuilder::service_principal_certificate
+    #[cfg(feature = "certificate")]
+    pub fn certificate_password(mut self, password: &str) -> Self {
+        self.certificate_password = Some(password.to_string());
+        self
+    }
+
     /// Configure Managed Identity authentication.
     ///
     /// An ambient token is obtained from the Azure Instance Metadata Service
-    /// (IMDS) on every request.  This works inside Azure VMs, App Services,
+    /// (IMDS) and cached like a service principal token.  This works inside Azure VMs, App Services,
     /// Container Apps, AKS pods with a workload identity, and any other
     /// environment where a managed identity is assigned.  No credentials need
     /// to be stored in code or environment variables.
@@ -354,7 +704,160 @@ impl ACSClientBuilder {
     ///
     /// [`host`]: ACSClientBuilder::host
     pub fn managed_identity(mut self) -> Self {
-        self.auth_method = Some(ACSAuthMethod::ManagedIdentity);
+        self.token_source = Some(TokenSource::ManagedIdentity(
+            ManagedIdentityId::SystemAssigned,
+        ));
+        self
+    }
+
+    /// Configure authentication as the user-assigned managed identity with
+    /// client (application) ID `client_id`.
+    ///
+    /// Tokens come from IMDS, or from the App Service / Container Apps
+    /// identity endpoint when `IDENTITY_ENDPOINT` and `IDENTITY_HEADER` are
+    /// set.  Requires [`host`] to also be set.
+    ///
+    /// [`host`]: ACSClientBuilder::host
+    pub fn managed_identity_client_id(mut self, client_id: &str) -> Self {
+        self.token_source = Some(TokenSource::ManagedIdentity(ManagedIdentityId::ClientId(
+            client_id.to_string(),
+        )));
+        self
+    }
+
+    /// Configure authentication as the user-assigned managed identity with
+    /// Azure resource ID `resource_id`
+    /// (`/subscriptions/…/providers/Microsoft.ManagedIdentity/userAssignedIdentities/…`).
+    ///
+    /// Requires [`host`] to also be set.
+    ///
+    /// [`host`]: ACSClientBuilder::host
+    pub fn managed_identity_resource_id(mut self, resource_id: &str) -> Self {
+        self.token_source = Some(TokenSource::ManagedIdentity(ManagedIdentityId::ResourceId(
+            resource_id.to_string(),
+        )));
+        self
+    }
+
+    /// Request managed identity tokens from `url` (IMDS protocol) instead of
+    /// the platform endpoint, e.g. a local stand-in in tests.
+    pub fn managed_identity_endpoint(mut self, url: &str) -> Self {
+        self.managed_identity_endpoint = Some(url.to_string());
+        self
+    }
+
+    /// Configure Entra Workload ID authentication: the federated token in
+    /// `token_file` is exchanged for a token of application `client_id` in
+    /// `tenant_id`.
+    ///
+    /// On AKS the three values are injected as `AZURE_TENANT_ID`,
+    /// `AZURE_CLIENT_ID` and `AZURE_FEDERATED_TOKEN_FILE`.  The file is
+    /// re-read on every token refresh, so rotated tokens are picked up.
+    /// Requires [`host`] to also be set.
+    ///
+    /// [`host`]: ACSClientBuilder::host
+    pub fn workload_identity(
+        mut self,
+        tenant_id: &str,
+        client_id: &str,
+        token_file: impl AsRef<std::path::Path>,
+    ) -> Self {
+        self.token_source = Some(TokenSource::WorkloadIdentity {
+            tenant_id: tenant_id.to_string(),
+            client_id: client_id.to_string(),
+            token_file: token_file.as_ref().to_path_buf(),
+        });
+        self
+    }
+
+    /// Authenticate with the first working source of the default credential
+    /// chain: environment service principal, workload identity,
+    /// system-assigned managed identity, then the Azure CLI (`az login`).
+    ///
+    /// Lets the same code run on a developer machine and in Azure without
+    /// branching on the environment.  Requires [`host`] to also be set.
+    ///
+    /// [`host`]: ACSClientBuilder::host
+    pub fn default_credential_chain(self) -> Self {
+        self.credential_chain(CredentialChain::default())
+    }
+
+    /// Authenticate with the first working source of `chain`, tried in
+    /// order.  See the `credential_chain` module.  Requires [`host`] to also
+    /// be set.
+    ///
+    /// [`host`]: ACSClientBuilder::host
+    pub fn credential_chain(mut self, chain: CredentialChain) -> Self {
+        self.token_source = Some(TokenSource::Chain(chain));
+        self
+    }
+
+    /// Select the Azure cloud of the ACS resource: its Entra ID authority
+    /// host, ACS token scope and expected endpoint suffix.  Default:
+    /// [`AzureCloud::Public`].
+    ///
+    /// A host outside the cloud's endpoint suffix is logged as a warning when
+    /// the client is built.  See the `cloud` module.
+    pub fn cloud(mut self, cloud: AzureCloud) -> Self {
+        self.cloud = cloud;
+        self
+    }
+
+    /// Entra ID authority host for service principal (secret or certificate)
+    /// and workload identity tokens, overriding the one of the [`cloud`].
+    /// Default: `https://login.microsoftonline.com/`.
+    ///
+    /// [`cloud`]: ACSClientBuilder::cloud
+    pub fn authority_host(mut self, url: &str) -> Self {
+        self.authority_host = Some(url.to_string());
+        self
+    }
+
+    /// Authenticate with any `azure_core` [`TokenCredential`], such as one
+    /// reading tokens from a sidecar.
+    ///
+    /// Tokens are requested for the ACS scope and cached like service
+    /// principal tokens (see [`token_refresh_margin`]).  Requires [`host`] to
+    /// also be set.
+    ///
+    /// [`host`]: ACSClientBuilder::host
+    /// [`token_refresh_margin`]: ACSClientBuilder::token_refresh_margin
+    pub fn token_credential(mut self, credential: Arc<dyn TokenCredential>) -> Self {
+        self.token_source = Some(TokenSource::Credential(credential));
+        self
+    }
+
+    /// Authenticate every request with a fixed bearer `token`.
+    ///
+    /// The token is never refreshed, so this suits short-lived tools and
+    /// tests.  Requires [`host`] to also be set.
+    ///
+    /// [`host`]: ACSClientBuilder::host
+    pub fn static_token(self, token: &str) -> Self {
+        self.token_credential(Arc::new(StaticTokenCredential::new(token)))
+    }
+
+    /// Authenticate with tokens returned by an async closure.
+    ///
+    /// `provider` is called whenever a new token is needed — on the first
+    /// request, before the cached token expires, and after ACS rejects it.
+    /// Its error is reported as [`ACSError::Auth`].  Requires [`host`] to
+    /// also be set.
+    ///
+    /// [`host`]: ACSClientBuilder::host
+    pub fn token_provider<F, Fut, E>(self, provider: F) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<AccessToken, E>> + Send + 'static,
+        E: std::fmt::Display,
+    {
+        self.token_credential(Arc::new(TokenProviderCredential::new(provider)))
+    }
+
+    /// How long before expiry a cached bearer token is refreshed.  Default:
+    /// 5 minutes.  Ignored for connection-string (shared key) auth.
+    pub fn token_refresh_margin(mut self, margin: Duration) -> Self {
+        self.token_refresh_margin = margin;
         self
     }
 
@@ -370,9 +873,50 @@ impl ACSClientBuilder {
     /// - The connection string is present but malformed.
     /// - Neither a connection string nor a host was provided.
     /// - A host was provided but no authentication method was set.
+    /// - The managed identity credential could not be created.
+    /// - The [`authority_host`] or [`managed_identity_endpoint`] is not a valid URL.
+    /// - The [`batch_concurrency`] bounds are invalid.
+    /// - A [`rate_limit`] or [`recipient_rate_limit`] quota is zero.
     /// - The underlying HTTP client could not be built (rare; usually a TLS
     ///   configuration issue).
+    ///
+    /// [`batch_concurrency`]: ACSClientBuilder::batch_concurrency
+    /// [`rate_limit`]: ACSClientBuilder::rate_limit
+    /// [`recipient_rate_limit`]: ACSClientBuilder::recipient_rate_limit
+    /// [`authority_host`]: ACSClientBuilder::authority_host
+    /// [`managed_identity_endpoint`]: ACSClientBuilder::managed_identity_endpoint
     pub fn build(self) -> Result<ACSClient, String> {
+        let (floor, ceiling) = self.batch_concurrency;
+        if floor == 0 || floor > ceiling {
+            return Err(format!(
+                "Invalid batch concurrency bounds: floor {} must be between 1 and ceiling {}",
+                floor, ceiling
+            ));
+        }
+        for (name, quota) in [
+            ("rate limit", self.request_quota),
+            ("recipient rate limit", self.recipient_quota),
+        ] {
+            if let Some((per_minute, per_hour)) = quota {
+                if per_minute == 0 || per_hour == 0 {
+                    return Err(format!(
+                        "Invalid {}: per-minute ({}) and per-hour ({}) quotas must be at least 1",
+                        name, per_minute, per_hour
+                    ));
+                }
+            }
+        }
+        let batch_concurrency = AdaptiveConcurrency::new(floor, ceiling);
+        let tracker = OperationTracker::new(self.status_poll_rate, self.delivery_sla);
+        let events = EventBus::new();
+        let retry_policy = self
+            .retry_policy
+            .unwrap_or_else(|| Arc::new(ExponentialBackoff::new().max_retries(self.max_retries)));
+        let rate_limiter = RateLimiter::from_quotas(
+            self.request_quota,
+            self.recipient_quota,
+            self.rate_limit_mode,
+        );
         let mut client_builder = Client::builder();
         if let Some(timeout) = self.timeout {
             client_builder = client_builder.timeout(timeout);
@@ -385,18 +929,31 @@ impl ACSClientBuilder {
             let parsed_res = parse_endpoint(&connection_string)
                 .map_err(|e| format!("Failed to parse connection string: {}", e))?;
             let host = parsed_res.host_name;
+            warn_foreign_host(&self.cloud, &host);
             let base_url = self
                 .base_url_override
                 .unwrap_or_else(|| format!("https://{}", host));
-            let auth_method = ACSAuthMethod::SharedKey(parsed_res.access_key);
+            if let Some(secondary_key) = &self.secondary_key {
+                validate_key(secondary_key).map_err(|e| format!("Invalid secondary key: {}", e))?;
+            }
+            let auth_method = ACSAuthMethod::SharedKey(AccessKeys::new(
+                parsed_res.access_key,
+                self.secondary_key,
+            ));
             return Ok(ACSClient {
                 host,
                 base_url,
                 auth_method,
                 api_version: self.api_version,
                 http_client,
-                max_retries: self.max_retries,
-                poll_interval: self.poll_interval,
+                max_retries: retry_policy.max_retries(),
+                retry_policy,
+                rate_limiter,
+                batch_concurrency,
+                tracker,
+                events,
+                poll_strategy: self.poll_strategy,
+                resend_policy: self.resend_policy,
             });
         }
 
@@ -404,102 +961,594 @@ impl ACSClientBuilder {
         let clean_host = host
             .trim_start_matches("https://")
             .trim_start_matches("http://");
+        warn_foreign_host(&self.cloud, clean_host);
         let base_url = self
             .base_url_override
             .unwrap_or_else(|| format!("https://{}", clean_host));
-        let auth_method = self
-            .auth_method
+        let token_source = self
+            .token_source
             .ok_or_else(|| "Authentication method is required".to_string())?;
+        let authority_host = Url::parse(
+            self.authority_host
+                .as_deref()
+                .unwrap_or(self.cloud.authority_host()),
+        )
+        .map_err(|e| format!("Invalid authority host: {}", e))?;
+        let managed_identity_endpoint = self
+            .managed_identity_endpoint
+            .as_deref()
+            .map(Url::parse)
+            .transpose()
+            .map_err(|e| format!("Invalid managed identity endpoint: {}", e))?;
+        let credential: Arc<dyn TokenCredential> = match token_source {
+            TokenSource::ServicePrincipal {
+                tenant_id,
+                client_id,
+                client_secret,
+            } => Arc::new(ClientSecretCredential::new(
+                wrap_http_client(&http_client),
+                authority_host,
+                tenant_id,
+                client_id,
+                client_secret,
+            )),
+            #[cfg(feature = "certificate")]
+            TokenSource::ServicePrincipalCertificate {
+                tenant_id,
+                client_id,
+                certificate_path,
+            } => {
+                let credential = ClientCertificateCredential::new(
+                    http_client.clone(),
+                    &authority_host,
+                    &tenant_id,
+                    &client_id,
+                    certificate_path,
+                )?;
+                Arc::new(match self.certificate_password.as_deref() {
+                    Some(password) => credential.password(password),
+                    None => credential,
+                })
+            }
+            TokenSource::ManagedIdentity(ManagedIdentityId::SystemAssigned)
+                if managed_identity_endpoint.is_none() =>
+            {
+                create_credential().map_err(|e| format!("Failed to create credential: {}", e))?
+            }
+            TokenSource::ManagedIdentity(id) => {
+                let credential = ManagedIdentityCredential::new(http_client.clone(), id);
+                Arc::new(match managed_identity_endpoint {
+                    Some(endpoint) => credential.endpoint(endpoint),
+                    None => credential,
+                })
+            }
+            TokenSource::WorkloadIdentity {
+                tenant_id,
+                client_id,
+                token_file,
+            } => Arc::new(WorkloadIdentityCredential::new(
+                http_client.clone(),
+                &authority_host,
+                &tenant_id,
+                &client_id,
+                token_file,
+            )?),
+            TokenSource::Chain(chain) => Arc::new(ChainedCredential::new(
+                chain,
+                http_client.clone(),
+                authority_host,
+                managed_identity_endpoint,
+            )?),
+            TokenSource::Credential(credential) => credential,
+        };
+        let auth_method = ACSAuthMethod::Bearer(TokenCache::new(
+            credential,
+            self.cloud.token_scope(),
+            self.token_refresh_margin,
+        ));
         Ok(ACSClient {
             host,
             base_url,
             auth_method,
             api_version: self.api_version,
             http_client,
-            max_retries: self.max_retries,
-            poll_interval: self.poll_interval,
+            max_retries: retry_policy.max_retries(),
+            retry_policy,
+            rate_limiter,
+            batch_concurrency,
+            tracker,
+            events,
+            poll_strategy: self.poll_strategy,
+            resend_policy: self.resend_policy,
         })
     }
 }
 
-impl ACSClient {
-    /// Submit an email for delivery and return the ACS operation ID.
-    ///
-    /// The returned `String` is the opaque operation ID (message ID) assigned by
-    /// ACS.  Pass it to [`get_email_status`] or use [`send_email_stream`] /
-    /// [`send_email_with_callback`] to track delivery.
-    ///
-    /// Transient `429` / `503` responses are retried automatically up to
-    /// `max_retries` times (default: 3) with exponential back-off.
-    ///
-    /// # Errors
-    ///
-    /// - [`ACSError::Network`] — request could not be sent.
-    /// - [`ACSError::Auth`] — token acquisition failed (service principal / managed identity).
-    /// - [`ACSError::Api`] — ACS returned a non-202 error response.
-    /// - [`ACSError::RateLimitExceeded`] — all retries exhausted on `429`/`503`.
-    ///
-    /// [`get_email_status`]: ACSClient::get_email_status
-    /// [`send_email_stream`]: ACSClient::send_email_stream
-    /// [`send_email_with_callback`]: ACSClient::send_email_with_callback
-    #[instrument(skip(self, email), fields(host = %self.host, api_version = %self.api_version.as_str()))]
-    pub async fn send_email(&self, email: &SentEmail) -> EmailResult<String> {
-        let request_id = format!("{}", Uuid::new_v4());
-        acs_send_email(
-            &self.http_client,
-            &self.base_url,
-            &self.auth_method,
-            request_id.as_str(),
-            email,
-            &self.api_version,
-            self.max_retries,
-            None,
-        )
-        .await
+/// How [`ACSClient::send_email_with`] behaves once ACS has accepted the email.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum WaitMode {
+    /// Return as soon as ACS answers `202 Accepted`; no status polling.
+    #[default]
+    Accepted,
+    /// Poll until a terminal delivery status is observed, failing with
+    /// [`ACSError::Timeout`] when the given deadline elapses first.
+    Terminal(Duration),
+}
+
+/// Per-request options for [`ACSClient::send_email_with`].
+///
+/// Every field is optional; `SendOptions::new()` (or `default()`) reproduces
+/// the behaviour of [`ACSClient::send_email`].  Options apply to a single call
+/// and override the corresponding client-wide setting for that call only.
+///
+/// ```rust,ignore
+/// let options = SendOptions::new()
+///     .idempotency_key(&Uuid::new_v4().to_string())
+///     .timeout(Duration::from_secs(10))
+///     .cancellation_token(token.clone())
+///     .wait(WaitMode::Terminal(Duration::from_secs(120)));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct SendOptions {
+    idempotency_key: Option<String>,
+    first_sent: Option<SystemTime>,
+    timeout: Option<Duration>,
+    max_retries: Option<u32>,
+    extra_headers: HeaderMap,
+    cancellation_token: Option<CancellationToken>,
+    wait: WaitMode,
+    correlation_id: Option<String>,
+    email_class: Option<String>,
+    resend: Option<Arc<ResendPolicy>>,
+}
+
+impl SendOptions {
+    /// Create an empty set of options — equivalent to `SendOptions::default()`.
+    pub fn new() -> Self {
+        Self::default()
     }
 
-    /// Submit an email with a caller-supplied idempotency key.
-    ///
-    /// Behaves like [`send_email`] but sets `repeatability-request-id` and
-    /// `repeatability-first-sent` headers on every attempt (including retries).
-    /// ACS deduplicates requests that share the same key within a short window,
-    /// making application-level retries safe against double-sends.
+    /// Send `repeatability-request-id` / `repeatability-first-sent` headers
+    /// carrying `key` on every attempt, as [`ACSClient::send_email_idempotent`] does.
     ///
-    /// `idempotency_key` must be a valid UUID string (e.g. `Uuid::new_v4().to_string()`).
-    /// Do not reuse the same key for a different email payload.
+    /// Without a key each call generates its own, which protects the call's
+    /// retries but not a resend by the application.
+    pub fn idempotency_key(mut self, key: &str) -> Self {
+        self.idempotency_key = Some(key.to_string());
+        self
+    }
+
+    /// Time sent in the `repeatability-first-sent` header alongside the
+    /// [`idempotency_key`].  Defaults to the time of the call.
     ///
-    /// # Errors
+    /// When resending with a key used before, pass the time of the first
+    /// attempt so ACS recognises the resend as the same request.
     ///
-    /// Same variants as [`send_email`].
+    /// [`idempotency_key`]: SendOptions::idempotency_key
+    pub fn first_sent(mut self, first_sent: SystemTime) -> Self {
+        self.first_sent = Some(first_sent);
+        self
+    }
+
+    /// Caller-defined ID (e.g. an order or database row ID) attached to every
+    /// [`EmailEvent`] published for this send's operation.  Not sent to ACS.
     ///
-    /// [`send_email`]: ACSClient::send_email
-    #[instrument(skip(self, email), fields(host = %self.host, api_version = %self.api_version.as_str()))]
-    pub async fn send_email_idempotent(
-        &self,
-        email: &SentEmail,
-        idempotency_key: &str,
-    ) -> EmailResult<String> {
-        let request_id = Uuid::new_v4().to_string();
-        acs_send_email(
-            &self.http_client,
-            &self.base_url,
-            &self.auth_method,
-            &request_id,
-            email,
-            &self.api_version,
-            self.max_retries,
-            Some(idempotency_key),
-        )
-        .await
+    /// [`EmailEvent`]: crate::adapters::gateways::events::EmailEvent
+    pub fn correlation_id(mut self, correlation_id: &str) -> Self {
+        self.correlation_id = Some(correlation_id.to_string());
+        self
     }
 
-    /// Submit an email and receive delivery status updates via a callback.
+    /// Caller-defined class of the email (e.g. `"receipt"`, `"newsletter"`)
+    /// selecting its [`DeliverySla`] threshold.  Also attached to every
+    /// [`EmailEvent`] of the operation.  Not sent to ACS.
     ///
-    /// Sends the email, then spawns a Tokio task that polls
-    /// [`get_email_status`] every **5 seconds** and invokes `call_back` on each
-    /// update.  The task stops when a terminal status
-    /// (`Succeeded`, `Failed`, `Canceled`, `Unknown`) or a poll error is
-    /// observed.
+    /// [`EmailEvent`]: crate::adapters::gateways::events::EmailEvent
+    pub fn email_class(mut self, class: &str) -> Self {
+        self.email_class = Some(class.to_string());
+        self
+    }
+
+    /// HTTP timeout for each individual request of this call, overriding
+    /// [`ACSClientBuilder::timeout`].
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Retry budget for this call, overriding [`ACSClientBuilder::max_retries`].
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = Some(max_retries);
+        self
+    }
+
+    /// Add an HTTP header to the send request and all of its retries.
+    ///
+    /// Headers added here are applied after the authentication and
+    /// repeatability headers and replace any header of the same name.
+    pub fn header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.extra_headers.insert(name, value);
+        self
+    }
+
+    /// Cancel retry back-off and the [`WaitMode::Terminal`] wait when `token`
+    /// fires.  A request already on the wire is allowed to complete, and the
+    /// email stays queued in ACS if it was accepted.
+    pub fn cancellation_token(mut self, token: CancellationToken) -> Self {
+        self.cancellation_token = Some(token);
+        self
+    }
+
+    /// Select whether the call returns on acceptance or waits for a terminal status.
+    pub fn wait(mut self, wait: WaitMode) -> Self {
+        self.wait = wait;
+        self
+    }
+
+    /// Resend the email under `policy` when its operation ends `Failed`,
+    /// overriding [`ACSClientBuilder::resend_policy`].  Only takes effect
+    /// with [`WaitMode::Terminal`], whose deadline then covers all attempts.
+    pub fn resend(mut self, policy: ResendPolicy) -> Self {
+        self.resend = Some(Arc::new(policy));
+        self
+    }
+}
+
+/// Result of [`ACSClient::send_email_with`].
+#[derive(Debug)]
+pub struct SendOutcome {
+    /// Operation ID assigned by ACS.
+    pub operation_id: String,
+    /// Terminal delivery status; `Some` only with [`WaitMode::Terminal`].
+    pub status: Option<EmailSendStatusType>,
+    /// Operation IDs of every send of the email, oldest first; the last one
+    /// is `operation_id`.  Holds more than one ID only when a
+    /// [`ResendPolicy`] resent the email.
+    pub attempts: Vec<String>,
+}
+
+/// Full state of an ACS email operation, returned by
+/// [`ACSClient::get_email_operation`].
+#[derive(Clone, Debug)]
+pub struct EmailOperation {
+    /// Operation ID (as returned by ACS, or the requested ID when the
+    /// response omits it).
+    pub id: String,
+    /// Current delivery status.
+    pub status: EmailSendStatusType,
+    /// Error tree ACS attached to the operation; usually present when
+    /// `status` is `Failed`.
+    pub error: Option<ErrorDetail>,
+    /// Headers of the status response (e.g. `retry-after`, `x-ms-request-id`).
+    pub headers: HeaderMap,
+}
+
+impl EmailOperation {
+    /// `true` once the operation has reached `Succeeded`, `Failed`,
+    /// `Canceled` or `Unknown`.
+    pub fn is_terminal(&self) -> bool {
+        is_terminal_status(&self.status)
+    }
+
+    /// The status, or [`ACSError::DeliveryFailed`] carrying the operation's
+    /// error tree when the status is `Failed`.
+    pub fn into_status(self) -> EmailResult<EmailSendStatusType> {
+        match self.status {
+            EmailSendStatusType::Failed => Err(ACSError::DeliveryFailed {
+                attempts: vec![self.id.clone()],
+                operation_id: self.id,
+                error: self.error.map(Box::new),
+            }),
+            status => Ok(status),
+        }
+    }
+}
+
+/// Handle to the task started by [`ACSClient::send_email_with_async_callback`]
+/// or [`ACSClient::operation_callback`].
+///
+/// Await the handle (or call [`join`](CallbackHandle::join)) for the final
+/// status once the callback has seen the last update: `Ok` with the terminal
+/// status, [`ACSError::DeliveryFailed`] for a `Failed` operation, or the poll
+/// error that ended tracking.  [`abort`](CallbackHandle::abort) stops polling
+/// and makes the handle resolve to [`ACSError::Canceled`].  Dropping the
+/// handle detaches the task, which keeps running.
+#[derive(Debug)]
+pub struct CallbackHandle {
+    operation_id: String,
+    task: JoinHandle<EmailResult<EmailSendStatusType>>,
+}
+
+impl CallbackHandle {
+    /// The operation whose updates are delivered to the callback.
+    pub fn operation_id(&self) -> &str {
+        &self.operation_id
+    }
+
+    /// Stop polling; no further callbacks are made.
+    pub fn abort(&self) {
+        self.task.abort();
+    }
+
+    /// `true` once the task has finished or been aborted.
+    pub fn is_finished(&self) -> bool {
+        self.task.is_finished()
+    }
+
+    /// Wait for the task and return the final status.
+    pub async fn join(self) -> EmailResult<EmailSendStatusType> {
+        self.await
+    }
+}
+
+impl Future for CallbackHandle {
+    type Output = EmailResult<EmailSendStatusType>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.task)
+            .poll(cx)
+            .map(|joined| match joined {
+                Ok(result) => result,
+                Err(e) if e.is_cancelled() => Err(ACSError::Canceled),
+                Err(e) => std::panic::resume_unwind(e.into_panic()),
+            })
+    }
+}
+
+impl ACSClient {
+    /// Submit an email for delivery and return the ACS operation ID.
+    ///
+    /// The returned `String` is the opaque operation ID (message ID) assigned by
+    /// ACS.  Pass it to [`get_email_status`] or use [`send_email_stream`] /
+    /// [`operation_callback`] to track delivery.
+    ///
+    /// Transient failures (`429`, `5xx`, network errors) are retried according
+    /// to the client's [`RetryPolicy`] — by default up to 3 times with
+    /// exponential back-off.
+    ///
+    /// # Errors
+    ///
+    /// - [`ACSError::Network`] — request could not be sent.
+    /// - [`ACSError::Auth`] — token acquisition failed (service principal / managed identity).
+    /// - [`ACSError::Api`] — ACS returned a non-202 error response.
+    /// - [`ACSError::RateLimitExceeded`] — all retries exhausted on `429`/`503`.
+    ///
+    /// [`get_email_status`]: ACSClient::get_email_status
+    /// [`send_email_stream`]: ACSClient::send_email_stream
+    /// [`operation_callback`]: ACSClient::operation_callback
+    #[instrument(skip(self, email), fields(host = %self.host, api_version = %self.api_version.as_str()))]
+    pub async fn send_email(&self, email: &SentEmail) -> EmailResult<String> {
+        self.send_email_with(email, &SendOptions::new())
+            .await
+            .map(|outcome| outcome.operation_id)
+    }
+
+    /// Submit an email with a caller-supplied idempotency key.
+    ///
+    /// Behaves like [`send_email`] but sets `repeatability-request-id` and
+    /// `repeatability-first-sent` headers on every attempt (including retries).
+    /// ACS deduplicates requests that share the same key within a short window,
+    /// making application-level retries safe against double-sends.
+    ///
+    /// `idempotency_key` must be a valid UUID string (e.g. `Uuid::new_v4().to_string()`).
+    /// Do not reuse the same key for a different email payload.
+    ///
+    /// # Errors
+    ///
+    /// Same variants as [`send_email`].
+    ///
+    /// [`send_email`]: ACSClient::send_email
+    #[instrument(skip(self, email), fields(host = %self.host, api_version = %self.api_version.as_str()))]
+    pub async fn send_email_idempotent(
+        &self,
+        email: &SentEmail,
+        idempotency_key: &str,
+    ) -> EmailResult<String> {
+        let options = SendOptions::new().idempotency_key(idempotency_key);
+        self.send_email_with(email, &options)
+            .await
+            .map(|outcome| outcome.operation_id)
+    }
+
+    /// Submit an email using per-request [`SendOptions`].
+    ///
+    /// This is the general form behind every other `send_email*` method: the
+    /// options select the idempotency key, per-request timeout, retry budget,
+    /// extra HTTP headers, cancellation token and [`WaitMode`] for this call
+    /// only, leaving the client configuration untouched.
+    ///
+    /// With [`WaitMode::Accepted`] (the default) the call returns as soon as
+    /// ACS accepts the email and [`SendOutcome::status`] is `None`.  With
+    /// [`WaitMode::Terminal`] the operation is polled on the client's
+    /// [`poll_strategy`] until a terminal status is observed, and that status
+    /// is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let options = SendOptions::new()
+    ///     .idempotency_key(&Uuid::new_v4().to_string())
+    ///     .max_retries(5)
+    ///     .wait(WaitMode::Terminal(Duration::from_secs(60)));
+    /// let outcome = client.send_email_with(&email, &options).await?;
+    /// println!("{} → {:?}", outcome.operation_id, outcome.status);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// - All errors from [`send_email`].
+    /// - [`ACSError::Timeout`] — [`WaitMode::Terminal`] deadline elapsed.
+    /// - [`ACSError::DeliveryFailed`] — the operation ended `Failed` (and the
+    ///   [`resend`] policy, if any, did not resend it); carries the error
+    ///   details ACS reported.
+    /// - [`ACSError::Canceled`] — the cancellation token fired while waiting for
+    ///   rate-limit capacity, during a retry or resend back-off, or while
+    ///   waiting for a terminal status.
+    /// - [`ACSError::QuotaExhausted`] — the client-side rate limiter is in
+    ///   [`RateLimitMode::FailFast`] and has no capacity.
+    ///
+    /// [`send_email`]: ACSClient::send_email
+    /// [`poll_strategy`]: ACSClientBuilder::poll_strategy
+    /// [`resend`]: SendOptions::resend
+    #[instrument(skip(self, email, options), fields(host = %self.host, api_version = %self.api_version.as_str()))]
+    pub async fn send_email_with(
+        &self,
+        email: &SentEmail,
+        options: &SendOptions,
+    ) -> EmailResult<SendOutcome> {
+        self.submit(email, options, None).await
+    }
+
+    /// Body of [`send_email_with`]; `permit` receives throttling feedback when
+    /// the send runs under the batch concurrency limit.
+    ///
+    /// [`send_email_with`]: ACSClient::send_email_with
+    async fn submit(
+        &self,
+        email: &SentEmail,
+        options: &SendOptions,
+        permit: Option<&ConcurrencyPermit>,
+    ) -> EmailResult<SendOutcome> {
+        let operation_id = self.submit_once(email, options, permit).await?;
+        match options.wait {
+            WaitMode::Accepted => Ok(SendOutcome {
+                attempts: vec![operation_id.clone()],
+                operation_id,
+                status: None,
+            }),
+            WaitMode::Terminal(timeout) => {
+                self.deliver(email, options, operation_id, timeout).await
+            }
+        }
+    }
+
+    /// Send `email` once, as configured by `options`, record the accepted
+    /// operation on the event bus and put it under the delivery SLA, if any.
+    async fn submit_once(
+        &self,
+        email: &SentEmail,
+        options: &SendOptions,
+        permit: Option<&ConcurrencyPermit>,
+    ) -> EmailResult<String> {
+        if let Some(limiter) = &self.rate_limiter {
+            let reserve = limiter.reserve(email.recipients.count());
+            match &options.cancellation_token {
+                Some(token) => {
+                    tokio::select! {
+                        _ = token.cancelled() => return Err(ACSError::Canceled),
+                        reserved = reserve => reserved?,
+                    }
+                }
+                None => reserve.await?,
+            }
+        }
+        let request_id = Uuid::new_v4().to_string();
+        let operation_id = acs_send_email(
+            &self.http_client,
+            &self.base_url,
+            &self.auth_method,
+            &request_id,
+            email,
+            &self.api_version,
+            self.retry_policy.as_ref(),
+            options.max_retries.unwrap_or(self.max_retries),
+            options,
+            permit,
+        )
+        .await?;
+        self.events.submitted(
+            &operation_id,
+            options.correlation_id.as_deref(),
+            options.email_class.as_deref(),
+        );
+        self.tracker.watch(self, &operation_id);
+        Ok(operation_id)
+    }
+
+    /// Wait up to `timeout` for `operation_id` to finish, resending `email`
+    /// under the call's (or the client's) [`ResendPolicy`] while it ends
+    /// `Failed` with a listed error code.
+    async fn deliver(
+        &self,
+        email: &SentEmail,
+        options: &SendOptions,
+        operation_id: String,
+        timeout: Duration,
+    ) -> EmailResult<SendOutcome> {
+        let deadline = Instant::now() + timeout;
+        let policy = options.resend.as_ref().or(self.resend_policy.as_ref());
+        let token = options.cancellation_token.as_ref();
+        let mut attempts = vec![operation_id];
+        loop {
+            let current = attempts[attempts.len() - 1].clone();
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            let result = self.wait_until_terminal(&current, remaining, token).await;
+            let delay = match (&result, policy) {
+                (Err(error), Some(policy)) => policy.resend_delay(error, attempts.len() as u32),
+                _ => None,
+            };
+            let Some(delay) = delay else {
+                return match result {
+                    Ok(status) => Ok(SendOutcome {
+                        operation_id: current,
+                        status: Some(status),
+                        attempts,
+                    }),
+                    Err(ACSError::DeliveryFailed {
+                        operation_id,
+                        error,
+                        ..
+                    }) => Err(ACSError::DeliveryFailed {
+                        operation_id,
+                        error,
+                        attempts,
+                    }),
+                    Err(e) => Err(e),
+                };
+            };
+
+            let resume_at = Instant::now() + delay;
+            if resume_at >= deadline {
+                return Err(ACSError::Timeout);
+            }
+            match token {
+                Some(token) => {
+                    tokio::select! {
+                        _ = token.cancelled() => return Err(ACSError::Canceled),
+                        _ = tokio::time::sleep_until(resume_at.into()) => {}
+                    }
+                }
+                None => tokio::time::sleep_until(resume_at.into()).await,
+            }
+            let mut resend_options = options.clone();
+            resend_options.idempotency_key = Some(Uuid::new_v4().to_string());
+            resend_options.first_sent = None;
+            let next = self.submit_once(email, &resend_options, None).await?;
+            let attempt = attempts.len() as u32 + 1;
+            warn!(
+                failed_operation_id = %current,
+                operation_id = %next,
+                attempt,
+                "Resent email after delivery failure"
+            );
+            self.events.resent(&next, &current, attempt);
+            attempts.push(next);
+        }
+    }
+
+    /// Submit an email and receive delivery status updates via a callback.
+    ///
+    /// Deprecated in favour of [`send_email_with_async_callback`], whose
+    /// callback may be async and `FnMut` and which returns a
+    /// [`CallbackHandle`] carrying the final status instead of an opaque
+    /// `oneshot::Receiver<()>`.
+    ///
+    /// Sends the email, then spawns a Tokio task that polls
+    /// [`get_email_status`] on the client's [`poll_strategy`] (every 5 seconds
+    /// by default, or as a `Retry-After` header requests) and invokes
+    /// `call_back` on each update.  The task stops when a terminal status
+    /// (`Succeeded`, `Failed`, `Canceled`, `Unknown`) or a poll error is
+    /// observed.
     ///
     /// # Returns
     ///
@@ -513,8 +1562,9 @@ impl ACSClient {
     /// fn(message_id: String, status: &EmailSendStatusType, error: Option<ACSError>)
     /// ```
     ///
-    /// `error` is `Some` only when a status-poll request itself fails; a
-    /// `Failed` delivery status still has `error = None`.
+    /// `error` is `Some` when a status-poll request itself fails, and when the
+    /// delivery status is `Failed` — then it is [`ACSError::DeliveryFailed`]
+    /// carrying the error details ACS reported.
     ///
     /// # Errors
     ///
@@ -522,7 +1572,9 @@ impl ACSClient {
     /// delivered through the callback rather than propagated.
     ///
     /// [`get_email_status`]: ACSClient::get_email_status
-    #[allow(dead_code)]
+    /// [`poll_strategy`]: ACSClientBuilder::poll_strategy
+    /// [`send_email_with_async_callback`]: ACSClient::send_email_with_async_callback
+    #[deprecated(note = "use send_email_with_async_callback / operation_callback")]
     #[instrument(skip(self, email, call_back), fields(host = %self.host, api_version = %self.api_version.as_str()))]
     pub async fn send_email_with_callback<F>(
         self,
@@ -532,58 +1584,115 @@ impl ACSClient {
     where
         F: Fn(String, &EmailSendStatusType, Option<ACSError>) + Send + Sync + 'static,
     {
-        let request_id = format!("{}", Uuid::new_v4());
-        let result = acs_send_email(
-            &self.http_client,
-            &self.base_url,
-            &self.auth_method,
-            request_id.as_str(),
-            email,
-            &self.api_version,
-            self.max_retries,
-            None,
-        )
-        .await?;
+        let result = self.send_email(email).await?;
 
         let message_id = result.clone();
+        let mut updates = self.track_operation(&message_id);
         let (tx, rx) = oneshot::channel();
-        tokio::spawn(async move {
-            loop {
-                sleep(self.poll_interval).await;
-                let resp_status = self.get_email_status(&message_id).await;
-                if let Ok(status) = resp_status {
-                    call_back(message_id.clone(), &status, None);
-                    if matches!(
-                        status,
-                        EmailSendStatusType::Unknown
-                            | EmailSendStatusType::Canceled
-                            | EmailSendStatusType::Failed
-                            | EmailSendStatusType::Succeeded
-                    ) {
-                        let _ = tx.send(());
-                        break;
-                    }
-                } else {
-                    call_back(
-                        message_id.clone(),
-                        &EmailSendStatusType::Failed,
-                        resp_status.err(),
-                    );
-                    let _ = tx.send(());
+        self.tracker.spawn(async move {
+            while let Some(update) = updates.recv().await {
+                if !deliver_update(&call_back, &message_id, update) {
                     break;
                 }
             }
+            let _ = tx.send(());
         });
 
         Ok((result, rx))
     }
 
+    /// Submit an email and deliver status updates to an async callback.
+    ///
+    /// Sends the email, then runs [`operation_callback`] for the new operation.
+    /// Unlike [`send_email_with_callback`], the callback may be `FnMut` and
+    /// returns a future that is awaited before the next update, so it can do
+    /// async work such as database writes.  A synchronous callback can return
+    /// `std::future::ready(())`.
+    ///
+    /// # Callback signature
+    ///
+    /// ```text
+    /// FnMut(message_id: String, status: EmailSendStatusType, error: Option<ACSError>) -> impl Future<Output = ()>
+    /// ```
+    ///
+    /// Updates are reported as for [`send_email_with_callback`].  The client's
+    /// [`resend_policy`](ACSClientBuilder::resend_policy) does not apply.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` only if the initial *send* fails; the outcome of polling
+    /// is returned by the [`CallbackHandle`].
+    ///
+    /// [`operation_callback`]: ACSClient::operation_callback
+    /// [`send_email_with_callback`]: ACSClient::send_email_with_callback
+    #[instrument(skip(self, email, call_back), fields(host = %self.host, api_version = %self.api_version.as_str()))]
+    pub async fn send_email_with_async_callback<F, Fut>(
+        &self,
+        email: &SentEmail,
+        call_back: F,
+    ) -> EmailResult<CallbackHandle>
+    where
+        F: FnMut(String, EmailSendStatusType, Option<ACSError>) -> Fut + Send + 'static,
+        Fut: Future<Output = ()> + Send,
+    {
+        let operation_id = self.send_email(email).await?;
+        Ok(self.operation_callback(&operation_id, call_back))
+    }
+
+    /// Deliver status updates for an existing operation to an async callback.
+    ///
+    /// Spawns a task that subscribes to the client's [`OperationTracker`] and
+    /// awaits `call_back` for every update, stopping after the terminal status
+    /// or a poll error.  `error` is `Some` when a poll fails and when the
+    /// status is `Failed` (then it is [`ACSError::DeliveryFailed`]).
+    pub fn operation_callback<F, Fut>(&self, operation_id: &str, mut call_back: F) -> CallbackHandle
+    where
+        F: FnMut(String, EmailSendStatusType, Option<ACSError>) -> Fut + Send + 'static,
+        Fut: Future<Output = ()> + Send,
+    {
+        let mut updates = self.track_operation(operation_id);
+        let message_id = operation_id.to_string();
+        let task = self.tracker.spawn(async move {
+            while let Some(update) = updates.recv().await {
+                match update {
+                    Ok(status) => {
+                        call_back(message_id.clone(), status, None).await;
+                        if is_terminal_status(&status) {
+                            return Ok(status);
+                        }
+                    }
+                    Err(e) => {
+                        call_back(
+                            message_id.clone(),
+                            EmailSendStatusType::Failed,
+                            Some(e.clone()),
+                        )
+                        .await;
+                        return Err(e);
+                    }
+                }
+            }
+            Err(create_missing_status_error())
+        });
+        CallbackHandle {
+            operation_id: operation_id.to_string(),
+            task,
+        }
+    }
+
     /// Stream delivery status updates for a sent email.
     ///
     /// Sends the email, then returns a `Stream` that yields one
-    /// `Result<EmailSendStatusType, ACSError>` per poll interval (5 s).
-    /// The stream ends after the first terminal status
-    /// (`Succeeded`, `Failed`, `Canceled`, `Unknown`) or on a poll error.
+    /// `Result<EmailSendStatusType, ACSError>` per poll, on the schedule of
+    /// the client's [`poll_strategy`] (every 5 s by default, or as a
+    /// `Retry-After` header requests).  The stream ends after the first
+    /// terminal status (`Succeeded`, `Failed`, `Canceled`, `Unknown`) or on a
+    /// poll error.
+    /// A `Failed` status is yielded as [`ACSError::DeliveryFailed`] carrying
+    /// the error details ACS reported.  The client's
+    /// [`resend_policy`](ACSClientBuilder::resend_policy) does not apply; use
+    /// [`send_email_with`](ACSClient::send_email_with) with
+    /// [`WaitMode::Terminal`] to resend failed deliveries.
     ///
     /// # Example
     ///
@@ -593,6 +1702,8 @@ impl ACSClient {
     /// tokio::pin!(stream);
     /// while let Some(item) = stream.next().await { /* … */ }
     /// ```
+    ///
+    /// [`poll_strategy`]: ACSClientBuilder::poll_strategy
     #[instrument(skip(self, email), fields(host = %self.host, api_version = %self.api_version.as_str()))]
     pub async fn send_email_stream(
         &self,
@@ -601,45 +1712,22 @@ impl ACSClient {
         String,
         impl Stream<Item = Result<EmailSendStatusType, ACSError>> + '_,
     )> {
-        let request_id = Uuid::new_v4().to_string();
-        let message_id = acs_send_email(
-            &self.http_client,
-            &self.base_url,
-            &self.auth_method,
-            &request_id,
-            email,
-            &self.api_version,
-            self.max_retries,
-            None,
-        )
-        .await?;
-
-        let returned_id = message_id.clone();
-        let poll_stream = stream! {
-            loop {
-                sleep(self.poll_interval).await;
-                match self.get_email_status(&message_id).await {
-                    Ok(status) => {
-                        let terminal = is_terminal_status(&status);
-                        yield Ok(status);
-                        if terminal { break; }
-                    }
-                    Err(e) => {
-                        yield Err(e);
-                        break;
-                    }
-                }
-            }
-        };
-
-        Ok((returned_id, poll_stream))
+        let message_id = self.send_email(email).await?;
+        let poll_stream = self.operation_stream(&message_id);
+        Ok((message_id, poll_stream))
     }
 
     /// Send multiple emails concurrently and collect all results.
     ///
-    /// Dispatches one [`send_email`] per entry in `emails`, awaits all of them,
-    /// and returns results in input order.  A failed send is captured as `Err`
-    /// in its slot — it does not abort the remaining sends.
+    /// Dispatches one [`send_email`] per entry in `emails` and returns results
+    /// in input order.  A failed send is captured as `Err` in its slot — it
+    /// does not abort the remaining sends.
+    ///
+    /// The number of sends in flight is governed by an adaptive (AIMD) limit:
+    /// it grows while ACS accepts sends and halves on every `429` / `503`,
+    /// staying within the bounds set by [`batch_concurrency`].  The limit is
+    /// shared with concurrent batches on clones of this client; read it with
+    /// [`ACSClient::batch_concurrency`].
     ///
     /// All sends share the same underlying connection pool, so no extra TLS
     /// handshakes are incurred compared to sequential sends.
@@ -649,50 +1737,200 @@ impl ACSClient {
     /// Each element follows the same error variants as [`send_email`].
     ///
     /// [`send_email`]: ACSClient::send_email
+    /// [`batch_concurrency`]: ACSClientBuilder::batch_concurrency
     #[instrument(skip(self, emails), fields(host = %self.host, count = emails.len()))]
     pub async fn send_emails_batch(&self, emails: &[SentEmail]) -> Vec<EmailResult<String>> {
-        futures::future::join_all(emails.iter().map(|e| self.send_email(e))).await
+        let options = SendOptions::new();
+        self.submit_batch(emails.iter().map(|email| (email, options.clone())))
+            .await
     }
 
-    /// Send an email and block until a terminal delivery status is observed or `timeout` elapses.
-    ///
-    /// Sends the email via [`send_email`], then polls [`get_email_status`] every
-    /// [`poll_interval`] until `Succeeded`, `Failed`, `Canceled`, or `Unknown` is
-    /// returned, or until `timeout` has elapsed.
-    ///
-    /// # Errors
+    /// Send `emails` as a batch and summarise the outcome in a [`BatchReport`].
     ///
-    /// - [`ACSError::Timeout`] — no terminal status observed within `timeout`.
-    /// - All errors from [`send_email`] and [`get_email_status`].
+    /// Each email is assigned a stable idempotency key, so
+    /// [`BatchReport::retry_failed`] can resend the failed slots without any
+    /// risk of a double send.  Sends run under the same adaptive concurrency
+    /// limit as [`send_emails_batch`].
     ///
-    /// [`send_email`]: ACSClient::send_email
-    /// [`get_email_status`]: ACSClient::get_email_status
-    /// [`poll_interval`]: ACSClientBuilder::poll_interval
-    #[instrument(skip(self, email), fields(host = %self.host, api_version = %self.api_version.as_str()))]
-    pub async fn send_email_and_wait(
+    /// [`send_emails_batch`]: ACSClient::send_emails_batch
+    #[instrument(skip(self, emails), fields(host = %self.host))]
+    pub async fn send_emails_batch_report(
         &self,
-        email: &SentEmail,
-        timeout: Duration,
-    ) -> EmailResult<EmailSendStatusType> {
-        let operation_id = self.send_email(email).await?;
-        tokio::time::timeout(timeout, async {
-            loop {
-                sleep(self.poll_interval).await;
-                let status = self.get_email_status(&operation_id).await?;
-                if is_terminal_status(&status) {
-                    return Ok(status);
-                }
-            }
-        })
-        .await
-        .map_err(|_| ACSError::Timeout)?
+        emails: impl IntoIterator<Item = SentEmail>,
+    ) -> BatchReport {
+        let mut report = BatchReport::new(emails);
+        report.retry_failed(self).await;
+        report
     }
 
-    /// Send an email and block until terminal status, timeout, or cancellation.
-    ///
-    /// Identical to [`send_email_and_wait`] but adds cooperative cancellation via
-    /// a [`CancellationToken`].  When the token is cancelled before a terminal
-    /// status is observed the method returns [`ACSError::Canceled`] immediately,
+    /// Submit each `(email, options)` pair under the adaptive concurrency
+    /// limit, returning the operation IDs in input order.
+    pub(crate) async fn submit_batch<'a>(
+        &self,
+        jobs: impl IntoIterator<Item = (&'a SentEmail, SendOptions)>,
+    ) -> Vec<EmailResult<String>> {
+        futures::stream::iter(jobs)
+            .map(|(email, options)| async move { self.submit_limited(email, &options).await })
+            .buffered(self.batch_concurrency.snapshot().ceiling)
+            .collect()
+            .await
+    }
+
+    /// Submit one email while holding a slot of the adaptive concurrency limit.
+    pub(crate) async fn submit_limited(
+        &self,
+        email: &SentEmail,
+        options: &SendOptions,
+    ) -> EmailResult<String> {
+        let permit = self.batch_concurrency.acquire().await;
+        let result = self.submit(email, options, Some(&permit)).await;
+        if result.is_ok() {
+            permit.succeeded();
+        }
+        result.map(|outcome| outcome.operation_id)
+    }
+
+    /// Send emails pulled lazily from `emails`, yielding `(index, result)`
+    /// pairs as sends complete.
+    ///
+    /// `index` is the position of the email in the input stream; results are
+    /// yielded in completion order, not input order.  At most
+    /// [`batch_concurrency`]`.ceiling` sends are in flight, and within that
+    /// bound the adaptive concurrency limit applies exactly as in
+    /// [`send_emails_batch`].  The next email is only pulled from `emails`
+    /// when a slot frees up and the caller polls for more output, so memory
+    /// use stays constant however long the input is.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use futures::StreamExt;
+    /// let results = client.send_stream(cursor_stream);
+    /// tokio::pin!(results);
+    /// while let Some((index, result)) = results.next().await { /* … */ }
+    /// ```
+    ///
+    /// [`batch_concurrency`]: ACSClientBuilder::batch_concurrency
+    /// [`send_emails_batch`]: ACSClient::send_emails_batch
+    pub fn send_stream<'a, S>(
+        &'a self,
+        emails: S,
+    ) -> impl Stream<Item = (usize, EmailResult<String>)> + 'a
+    where
+        S: Stream<Item = SentEmail> + 'a,
+    {
+        self.send_stream_keeping(emails)
+            .map(|(index, _, result)| (index, result))
+    }
+
+    /// [`send_stream`] that also yields each email, for callers that still
+    /// need it after the send.
+    ///
+    /// [`send_stream`]: ACSClient::send_stream
+    fn send_stream_keeping<'a, S>(
+        &'a self,
+        emails: S,
+    ) -> impl Stream<Item = (usize, SentEmail, EmailResult<String>)> + 'a
+    where
+        S: Stream<Item = SentEmail> + 'a,
+    {
+        let options = SendOptions::new();
+        emails
+            .enumerate()
+            .map(move |(index, email)| {
+                let options = options.clone();
+                async move {
+                    let result = self.submit_limited(&email, &options).await;
+                    (index, email, result)
+                }
+            })
+            .buffer_unordered(self.batch_concurrency.snapshot().ceiling)
+    }
+
+    /// Like [`send_stream`], but each accepted email moves on to status
+    /// polling and the stream yields its terminal delivery status.
+    ///
+    /// Polling does not hold a send slot: up to `max_waiting` accepted emails
+    /// are polled concurrently (on the client's [`poll_strategy`], for at most
+    /// `timeout` each), and sending pauses while that many are waiting.  One pipeline
+    /// therefore turns an unbounded input stream into final statuses in
+    /// constant memory.
+    ///
+    /// Items are `(index, Ok(SendOutcome))` with [`SendOutcome::status`] set,
+    /// or `(index, Err(_))` when the send failed, a poll failed, or `timeout`
+    /// elapsed ([`ACSError::Timeout`]).  Failed emails are resent under the
+    /// client's [`resend_policy`], within the same `timeout`.
+    ///
+    /// [`resend_policy`]: ACSClientBuilder::resend_policy
+    /// [`send_stream`]: ACSClient::send_stream
+    /// [`poll_strategy`]: ACSClientBuilder::poll_strategy
+    pub fn send_stream_and_wait<'a, S>(
+        &'a self,
+        emails: S,
+        timeout: Duration,
+        max_waiting: usize,
+    ) -> impl Stream<Item = (usize, EmailResult<SendOutcome>)> + 'a
+    where
+        S: Stream<Item = SentEmail> + 'a,
+    {
+        self.send_stream_keeping(emails)
+            .map(move |(index, email, sent)| async move {
+                let operation_id = match sent {
+                    Ok(id) => id,
+                    Err(e) => return (index, Err(e)),
+                };
+                let outcome = self
+                    .deliver(&email, &SendOptions::new(), operation_id, timeout)
+                    .await;
+                (index, outcome)
+            })
+            .buffer_unordered(max_waiting.max(1))
+    }
+
+    /// Current state of the adaptive concurrency limit used by
+    /// [`send_emails_batch`], for capacity monitoring.
+    ///
+    /// The limit is shared with every clone of this client.
+    ///
+    /// [`send_emails_batch`]: ACSClient::send_emails_batch
+    pub fn batch_concurrency(&self) -> ConcurrencySnapshot {
+        self.batch_concurrency.snapshot()
+    }
+
+    /// Send an email and block until a terminal delivery status is observed or `timeout` elapses.
+    ///
+    /// Sends the email via [`send_email`], then polls [`get_email_status`] on
+    /// the client's [`poll_strategy`] until `Succeeded`, `Failed`, `Canceled`,
+    /// or `Unknown` is returned, or until `timeout` has elapsed.
+    ///
+    /// # Errors
+    ///
+    /// - [`ACSError::Timeout`] — no terminal status observed within `timeout`.
+    /// - [`ACSError::DeliveryFailed`] — the operation ended `Failed`; carries
+    ///   the error details ACS reported.
+    /// - All errors from [`send_email`] and [`get_email_status`].
+    ///
+    /// [`send_email`]: ACSClient::send_email
+    /// [`get_email_status`]: ACSClient::get_email_status
+    /// [`poll_strategy`]: ACSClientBuilder::poll_strategy
+    #[instrument(skip(self, email), fields(host = %self.host, api_version = %self.api_version.as_str()))]
+    pub async fn send_email_and_wait(
+        &self,
+        email: &SentEmail,
+        timeout: Duration,
+    ) -> EmailResult<EmailSendStatusType> {
+        let options = SendOptions::new().wait(WaitMode::Terminal(timeout));
+        self.send_email_with(email, &options)
+            .await?
+            .status
+            .ok_or_else(create_missing_status_error)
+    }
+
+    /// Send an email and block until terminal status, timeout, or cancellation.
+    ///
+    /// Identical to [`send_email_and_wait`] but adds cooperative cancellation via
+    /// a [`CancellationToken`].  When the token is cancelled before a terminal
+    /// status is observed the method returns [`ACSError::Canceled`] immediately,
     /// without issuing another status poll.  If the deadline elapses first,
     /// [`ACSError::Timeout`] is returned instead.
     ///
@@ -700,6 +1938,8 @@ impl ACSClient {
     ///
     /// - [`ACSError::Canceled`] — `token` was cancelled before terminal status.
     /// - [`ACSError::Timeout`] — `timeout` elapsed before terminal status.
+    /// - [`ACSError::DeliveryFailed`] — the operation ended `Failed`; carries
+    ///   the error details ACS reported.
     /// - All errors from [`send_email`] and [`get_email_status`].
     ///
     /// [`send_email_and_wait`]: ACSClient::send_email_and_wait
@@ -713,24 +1953,13 @@ impl ACSClient {
         timeout: Duration,
         token: CancellationToken,
     ) -> EmailResult<EmailSendStatusType> {
-        let operation_id = self.send_email(email).await?;
-        tokio::time::timeout(timeout, async {
-            loop {
-                tokio::select! {
-                    _ = token.cancelled() => {
-                        return Err(ACSError::Canceled);
-                    }
-                    _ = sleep(self.poll_interval) => {
-                        let status = self.get_email_status(&operation_id).await?;
-                        if is_terminal_status(&status) {
-                            return Ok(status);
-                        }
-                    }
-                }
-            }
-        })
-        .await
-        .map_err(|_| ACSError::Timeout)?
+        let options = SendOptions::new()
+            .wait(WaitMode::Terminal(timeout))
+            .cancellation_token(token);
+        self.send_email_with(email, &options)
+            .await?
+            .status
+            .ok_or_else(create_missing_status_error)
     }
 
     /// Stream delivery status updates with cooperative cancellation.
@@ -766,46 +1995,100 @@ impl ACSClient {
         String,
         impl Stream<Item = Result<EmailSendStatusType, ACSError>> + '_,
     )> {
-        let request_id = Uuid::new_v4().to_string();
-        let message_id = acs_send_email(
-            &self.http_client,
-            &self.base_url,
-            &self.auth_method,
-            &request_id,
-            email,
-            &self.api_version,
-            self.max_retries,
-            None,
-        )
-        .await?;
+        let message_id = self.send_email(email).await?;
+        let poll_stream = self.operation_stream_cancellable(&message_id, token);
+        Ok((message_id, poll_stream))
+    }
+
+    /// Block until the existing operation `operation_id` reaches a terminal
+    /// status or `timeout` elapses.
+    ///
+    /// The counterpart of [`send_email_and_wait`] for an email that was sent
+    /// earlier — by this process before a restart, or by another service.
+    /// Polls on the client's [`poll_strategy`].
+    ///
+    /// # Errors
+    ///
+    /// - [`ACSError::Timeout`] — no terminal status observed within `timeout`.
+    /// - [`ACSError::DeliveryFailed`] — the operation ended `Failed`; carries
+    ///   the error details ACS reported.
+    /// - All errors from [`get_email_status`].
+    ///
+    /// [`send_email_and_wait`]: ACSClient::send_email_and_wait
+    /// [`poll_strategy`]: ACSClientBuilder::poll_strategy
+    /// [`get_email_status`]: ACSClient::get_email_status
+    #[instrument(skip(self), fields(host = %self.host, api_version = %self.api_version.as_str()))]
+    pub async fn wait_for_operation(
+        &self,
+        operation_id: &str,
+        timeout: Duration,
+    ) -> EmailResult<EmailSendStatusType> {
+        self.wait_until_terminal(operation_id, timeout, None).await
+    }
+
+    /// [`wait_for_operation`] with cooperative cancellation.
+    ///
+    /// # Errors
+    ///
+    /// As [`wait_for_operation`], plus [`ACSError::Canceled`] when `token` is
+    /// cancelled before a terminal status is observed.
+    ///
+    /// [`wait_for_operation`]: ACSClient::wait_for_operation
+    #[instrument(skip(self, token), fields(host = %self.host, api_version = %self.api_version.as_str()))]
+    pub async fn wait_for_operation_cancellable(
+        &self,
+        operation_id: &str,
+        timeout: Duration,
+        token: CancellationToken,
+    ) -> EmailResult<EmailSendStatusType> {
+        self.wait_until_terminal(operation_id, timeout, Some(&token))
+            .await
+    }
+
+    /// Stream status updates for the existing operation `operation_id`.
+    ///
+    /// The counterpart of [`send_email_stream`] for an email that was sent
+    /// earlier: yields one status per poll, on the client's [`poll_strategy`],
+    /// and ends after the first terminal status or poll error.  A `Failed` status is yielded as
+    /// [`ACSError::DeliveryFailed`].
+    ///
+    /// [`send_email_stream`]: ACSClient::send_email_stream
+    /// [`poll_strategy`]: ACSClientBuilder::poll_strategy
+    pub fn operation_stream(
+        &self,
+        operation_id: &str,
+    ) -> impl Stream<Item = Result<EmailSendStatusType, ACSError>> + '_ {
+        self.track_operation(operation_id)
+    }
 
-        let returned_id = message_id.clone();
-        let poll_stream = stream! {
+    /// [`operation_stream`] with cooperative cancellation: the stream ends
+    /// without issuing further polls once `token` is cancelled.
+    ///
+    /// [`operation_stream`]: ACSClient::operation_stream
+    pub fn operation_stream_cancellable(
+        &self,
+        operation_id: &str,
+        token: CancellationToken,
+    ) -> impl Stream<Item = Result<EmailSendStatusType, ACSError>> + '_ {
+        let mut updates = self.track_operation(operation_id);
+        stream! {
             loop {
                 tokio::select! {
                     _ = token.cancelled() => { break; }
-                    _ = sleep(self.poll_interval) => {
-                        match self.get_email_status(&message_id).await {
-                            Ok(status) => {
-                                let terminal = is_terminal_status(&status);
-                                yield Ok(status);
-                                if terminal { break; }
-                            }
-                            Err(e) => {
-                                yield Err(e);
-                                break;
-                            }
-                        }
-                    }
+                    update = updates.recv() => match update {
+                        Some(update) => yield update,
+                        None => break,
+                    },
                 }
             }
-        };
-
-        Ok((returned_id, poll_stream))
+        }
     }
 
     /// Callback-based status polling with cooperative cancellation.
     ///
+    /// Deprecated in favour of [`send_email_with_async_callback`]: call
+    /// [`CallbackHandle::abort`] on the returned handle to stop polling.
+    ///
     /// Identical to [`send_email_with_callback`] except that the background
     /// polling task exits cleanly when `token` is cancelled — no further status
     /// polls are issued and `done_rx` resolves once the task has fully stopped.
@@ -816,7 +2099,8 @@ impl ACSClient {
     /// cancellation are both delivered via `done_rx` resolving.
     ///
     /// [`send_email_with_callback`]: ACSClient::send_email_with_callback
-    #[allow(dead_code)]
+    /// [`send_email_with_async_callback`]: ACSClient::send_email_with_async_callback
+    #[deprecated(note = "use send_email_with_async_callback / operation_callback")]
     #[instrument(skip(self, email, token, call_back), fields(host = %self.host, api_version = %self.api_version.as_str()))]
     pub async fn send_email_with_callback_cancellable<F>(
         self,
@@ -827,53 +2111,182 @@ impl ACSClient {
     where
         F: Fn(String, &EmailSendStatusType, Option<ACSError>) + Send + Sync + 'static,
     {
-        let request_id = Uuid::new_v4().to_string();
-        let result = acs_send_email(
-            &self.http_client,
-            &self.base_url,
-            &self.auth_method,
-            &request_id,
-            email,
-            &self.api_version,
-            self.max_retries,
-            None,
-        )
-        .await?;
+        let result = self.send_email(email).await?;
 
         let message_id = result.clone();
+        let mut updates = self.track_operation(&message_id);
         let (tx, rx) = oneshot::channel();
-        tokio::spawn(async move {
+        self.tracker.spawn(async move {
             loop {
                 tokio::select! {
-                    _ = token.cancelled() => {
-                        let _ = tx.send(());
-                        break;
-                    }
-                    _ = sleep(self.poll_interval) => {
-                        let resp_status = self.get_email_status(&message_id).await;
-                        if let Ok(status) = resp_status {
-                            call_back(message_id.clone(), &status, None);
-                            if is_terminal_status(&status) {
-                                let _ = tx.send(());
-                                break;
-                            }
-                        } else {
-                            call_back(
-                                message_id.clone(),
-                                &EmailSendStatusType::Failed,
-                                resp_status.err(),
-                            );
-                            let _ = tx.send(());
+                    _ = token.cancelled() => break,
+                    update = updates.recv() => {
+                        let more = update.is_some_and(|update| {
+                            deliver_update(&call_back, &message_id, update)
+                        });
+                        if !more {
                             break;
                         }
                     }
                 }
             }
+            let _ = tx.send(());
         });
 
         Ok((result, rx))
     }
 
+    /// Poll `operation_id` until a terminal status, the deadline, or cancellation.
+    async fn wait_until_terminal(
+        &self,
+        operation_id: &str,
+        timeout: Duration,
+        token: Option<&CancellationToken>,
+    ) -> EmailResult<EmailSendStatusType> {
+        let mut updates = self.track_operation(operation_id);
+        tokio::time::timeout(timeout, async {
+            loop {
+                let update = match token {
+                    Some(token) => {
+                        tokio::select! {
+                            _ = token.cancelled() => return Err(ACSError::Canceled),
+                            update = updates.recv() => update,
+                        }
+                    }
+                    None => updates.recv().await,
+                };
+                match update {
+                    Some(Ok(status)) if is_terminal_status(&status) => return Ok(status),
+                    Some(Ok(_)) => {}
+                    Some(Err(e)) => return Err(e),
+                    None => return Err(create_missing_status_error()),
+                }
+            }
+        })
+        .await
+        .map_err(|_| ACSError::Timeout)?
+    }
+
+    /// Subscribe to status updates for `operation_id` through the client's
+    /// shared [`OperationTracker`].
+    ///
+    /// The tracker polls the operation as scheduled by the client's
+    /// [`PollStrategy`] — one GET per poll however many subscribers there
+    /// are — and evicts it after the terminal status or a poll error.  This is
+    /// the primitive behind every waiting, streaming and callback API.
+    pub fn track_operation(&self, operation_id: &str) -> OperationSubscription {
+        self.tracker.subscribe(self, operation_id)
+    }
+
+    /// Receive an [`EmailEvent`] for every operation sent through this client
+    /// or any of its clones: `Submitted` on acceptance, then `StatusChanged`,
+    /// `Terminal` or `Error` as the shared status poller observes them.
+    ///
+    /// Only events published after the call are received.  See the `events`
+    /// module for details.
+    pub fn subscribe(&self) -> broadcast::Receiver<EmailEvent> {
+        self.events.subscribe()
+    }
+
+    pub(crate) fn events(&self) -> &EventBus {
+        &self.events
+    }
+
+    /// Stop status polling gracefully, e.g. on `SIGTERM`.
+    ///
+    /// Shuts down the background work shared by this client and all of its
+    /// clones:
+    ///
+    /// 1. No new operations are polled — subsequent waits, streams and
+    ///    callbacks end immediately with [`ACSError::Canceled`].  Sending is
+    ///    not affected.
+    /// 2. Operations already being polled, and the callback tasks following
+    ///    them, get up to `grace` to reach a terminal status.
+    /// 3. Whatever is still pending after `grace` is cancelled: its
+    ///    subscribers receive [`ACSError::Canceled`] and an `Error` event is
+    ///    published.
+    ///
+    /// Returns the IDs of the cancelled operations, sorted, so they can be
+    /// persisted and picked up later (e.g. with [`resume_poller`] or
+    /// [`wait_for_operation`]).  Empty when everything finished in time.
+    ///
+    /// [`resume_poller`]: ACSClient::resume_poller
+    /// [`wait_for_operation`]: ACSClient::wait_for_operation
+    #[instrument(skip(self), fields(host = %self.host))]
+    pub async fn shutdown(&self, grace: Duration) -> Vec<String> {
+        let unresolved = self.tracker.shutdown(grace).await;
+        for operation_id in &unresolved {
+            self.events.finished(operation_id, &Err(ACSError::Canceled));
+        }
+        unresolved
+    }
+
+    /// The client's shared [`OperationTracker`], for monitoring the number of
+    /// pending operations.  Shared with every clone of this client.
+    pub fn operation_tracker(&self) -> &OperationTracker {
+        &self.tracker
+    }
+
+    /// Pending operations past their [`DeliverySla`] threshold, oldest first,
+    /// e.g. for a health endpoint.  Empty when no SLA is configured.
+    pub fn sla_summary(&self) -> SlaSummary {
+        self.tracker.sla_summary()
+    }
+
+    /// Make `new_key` the active shared access key for this client and all
+    /// its clones; the previous key becomes the fallback used on `401`.
+    ///
+    /// # Errors
+    ///
+    /// [`ACSError::Auth`] when the client does not use connection-string
+    /// (shared key) auth or `new_key` is not a base64 access key.
+    pub fn rotate_key(&self, new_key: &str) -> Result<(), ACSError> {
+        match &self.auth_method {
+            ACSAuthMethod::SharedKey(keys) => keys.rotate(new_key),
+            ACSAuthMethod::Bearer(_) => Err(auth_err(
+                "Key rotation requires connection-string (shared key) auth",
+            )),
+        }
+    }
+
+    /// The shared access keys; `None` for token-based auth.
+    pub fn access_keys(&self) -> Option<&AccessKeys> {
+        match &self.auth_method {
+            ACSAuthMethod::SharedKey(keys) => Some(keys),
+            ACSAuthMethod::Bearer(_) => None,
+        }
+    }
+
+    /// The shared bearer-token cache; `None` for connection-string (shared
+    /// key) auth.
+    pub fn token_cache(&self) -> Option<&TokenCache> {
+        match &self.auth_method {
+            ACSAuthMethod::Bearer(cache) => Some(cache),
+            ACSAuthMethod::SharedKey(_) => None,
+        }
+    }
+
+    /// The [`PollStrategy`] that spaces out status polls.
+    pub fn poll_strategy(&self) -> &dyn PollStrategy {
+        self.poll_strategy.as_ref()
+    }
+
+    /// The API version used for requests.
+    pub fn api_version(&self) -> &ACSApiVersion {
+        &self.api_version
+    }
+
+    /// The client-side [`RateLimiter`], if [`rate_limit`] or
+    /// [`recipient_rate_limit`] was configured.
+    ///
+    /// The limiter is shared with every clone of this client.
+    ///
+    /// [`rate_limit`]: ACSClientBuilder::rate_limit
+    /// [`recipient_rate_limit`]: ACSClientBuilder::recipient_rate_limit
+    pub fn rate_limiter(&self) -> Option<&RateLimiter> {
+        self.rate_limiter.as_ref()
+    }
+
     /// Poll the delivery status of a previously submitted email.
     ///
     /// `message_id` is the operation ID returned by [`send_email`].  ACS
@@ -902,47 +2315,107 @@ impl ACSClient {
     /// [`send_email_stream`]: ACSClient::send_email_stream
     #[instrument(skip(self), fields(host = %self.host, api_version = %self.api_version.as_str()))]
     pub async fn get_email_status(&self, message_id: &str) -> EmailResult<EmailSendStatusType> {
-        acs_get_email_status(
+        self.get_email_operation(message_id)
+            .await
+            .map(|operation| operation.status)
+    }
+
+    /// Fetch the full state of a previously submitted email operation.
+    ///
+    /// Unlike [`get_email_status`], the returned [`EmailOperation`] keeps the
+    /// `error` tree ACS attaches to a `Failed` operation — code, message,
+    /// nested details and additional info — together with the response
+    /// headers.
+    ///
+    /// # Errors
+    ///
+    /// Same variants as [`get_email_status`].  A `Failed` operation is *not*
+    /// an error here; use [`EmailOperation::into_status`] to convert it into
+    /// [`ACSError::DeliveryFailed`].
+    ///
+    /// [`get_email_status`]: ACSClient::get_email_status
+    #[instrument(skip(self), fields(host = %self.host, api_version = %self.api_version.as_str()))]
+    pub async fn get_email_operation(&self, operation_id: &str) -> EmailResult<EmailOperation> {
+        self.get_email_operation_at(operation_id, &self.api_version)
+            .await
+    }
+
+    /// [`get_email_operation`](ACSClient::get_email_operation) against an
+    /// explicit API version, for pollers resumed from a token.
+    pub(crate) async fn get_email_operation_at(
+        &self,
+        operation_id: &str,
+        api_version: &ACSApiVersion,
+    ) -> EmailResult<EmailOperation> {
+        acs_get_email_operation(
             &self.http_client,
             &self.base_url,
             &self.auth_method,
-            message_id,
-            &self.api_version,
+            operation_id,
+            api_version,
+            self.retry_policy.as_ref(),
+            self.max_retries,
         )
         .await
     }
+
+    /// An [`EmailPoller`] for `operation_id` that has not polled yet.
+    pub fn poller(&self, operation_id: &str) -> EmailPoller {
+        EmailPoller::new(self.clone(), operation_id)
+    }
+
+    /// Rebuild an [`EmailPoller`] from a token produced by
+    /// [`EmailPoller::to_resume_token`], possibly in another process.
+    ///
+    /// The poller keeps the token's attempt count, first-poll time, last
+    /// status and API version, so its poll schedule carries on where the
+    /// original stopped.
+    ///
+    /// # Errors
+    ///
+    /// [`ACSError::Deserialization`] when the token is malformed or names an
+    /// unknown API version.
+    pub fn resume_poller(&self, token: &str) -> EmailResult<EmailPoller> {
+        EmailPoller::from_resume_token(self.clone(), token)
+    }
+}
+
+/// Credential one request attempt is signed with, taken once per attempt so
+/// the signature matches the generation a `401` is checked against.
+enum RequestAuth {
+    /// Access key for HMAC-SHA256 signing.
+    SharedKey(String),
+    /// Bearer token from the client's [`TokenCache`].
+    Bearer(String),
 }
 
-#[instrument(skip(http_client, body, acs_auth_method, extra_headers), fields(method = %method, url = %url))]
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip(http_client, body, auth, extra_headers), fields(method = %method, url = %url))]
 async fn send_request<T>(
     http_client: &Client,
     method: reqwest::Method,
     url: &str,
     request_id: &str,
     body: Option<&T>,
-    acs_auth_method: &ACSAuthMethod,
+    auth: &RequestAuth,
     extra_headers: Option<&reqwest::header::HeaderMap>,
+    timeout: Option<Duration>,
 ) -> EmailResult<reqwest::Response>
 where
     T: serde::Serialize,
 {
     let url_endpoint = parse_url(url)?;
     let json_body = serialize_body(body)?;
-    let mut headers = create_headers(
-        http_client,
-        &url_endpoint,
-        method.as_str(),
-        request_id,
-        &json_body,
-        acs_auth_method,
-    )
-    .await?;
+    let mut headers = create_headers(&url_endpoint, method.as_str(), request_id, &json_body, auth)?;
     if let Some(extra) = extra_headers {
         for (key, value) in extra.iter() {
             headers.insert(key.clone(), value.clone());
         }
     }
-    let request_builder = http_client.request(method, url).headers(headers);
+    let mut request_builder = http_client.request(method, url).headers(headers);
+    if let Some(timeout) = timeout {
+        request_builder = request_builder.timeout(timeout);
+    }
     let request_builder = if let Some(body) = body {
         request_builder.json(body)
     } else {
@@ -963,81 +2436,43 @@ fn serialize_body<T: serde::Serialize>(body: Option<&T>) -> EmailResult<String>
     }
 }
 
-fn wrap_http_client(client: &Client) -> Arc<dyn HttpClient> {
-    Arc::new(client.clone()) as Arc<dyn HttpClient>
+/// Warn when `host` is not an ACS endpoint of `cloud`: its tokens would be
+/// rejected.
+fn warn_foreign_host(cloud: &AzureCloud, host: &str) {
+    if !cloud.is_endpoint(host) {
+        warn!(
+            host,
+            expected_suffix = cloud.endpoint_suffix(),
+            "ACS host is not an endpoint of the configured Azure cloud"
+        );
+    }
 }
 
-/// Acquire a bearer token for the given authentication method.
-///
-/// Returns an empty string for `SharedKey` (auth is handled via HMAC headers
-/// instead).  For `ServicePrincipal` and `ManagedIdentity` the token is
-/// fetched from Azure AD / IMDS on every call — responses are not cached.
-async fn get_access_token(
-    http_client: &Client,
-    auth_method: &ACSAuthMethod,
-) -> Result<String, String> {
-    match auth_method {
-        ACSAuthMethod::ServicePrincipal {
-            tenant_id,
-            client_id,
-            client_secret,
-        } => {
-            let azure_http_client = wrap_http_client(http_client);
-            let token_url = "https://login.microsoftonline.com/";
-
-            let credential = ClientSecretCredential::new(
-                azure_http_client,
-                Url::parse(token_url).unwrap(),
-                tenant_id.to_string(),
-                client_id.to_string(),
-                client_secret.to_string(),
-            );
-            let token = credential
-                .get_token(&["https://communication.azure.com/.default"])
-                .await
-                .map_err(|e| format!("Failed to get access token: {}", e))?;
-
-            return Ok(token.token.secret().to_owned());
-        }
-        ACSAuthMethod::ManagedIdentity => {
-            let credential =
-                create_credential().map_err(|e| format!("Failed to create credential: {}", e))?;
-            let token = credential
-                .get_token(&["https://communication.azure.com/.default"])
-                .await
-                .map_err(|e| format!("Failed to get access token: {}", e))?;
-            return Ok(token.token.secret().to_owned());
-        }
-        _ => {}
-    }
-    Ok(String::new())
+fn wrap_http_client(client: &Client) -> Arc<dyn HttpClient> {
+    Arc::new(client.clone()) as Arc<dyn HttpClient>
 }
 
 /// Build the required HTTP headers for a single request.
 ///
 /// For `SharedKey` auth the full HMAC-SHA256 header set is computed via
-/// `acs_shared_key`.  For `ServicePrincipal` / `ManagedIdentity` a bearer
-/// token is fetched and `Authorization`, `Content-Type`, and
-/// `x-ms-client-request-id` headers are set.
-async fn create_headers(
-    http_client: &Client,
+/// `acs_shared_key` with the given access key.  For `Bearer` auth the
+/// `Authorization`, `Content-Type`, and `x-ms-client-request-id` headers are
+/// set from the given token.
+fn create_headers(
     url_endpoint: &Url,
     method: &str,
     request_id: &str,
     json_body: &str,
-    auth_method: &ACSAuthMethod,
+    auth: &RequestAuth,
 ) -> EmailResult<reqwest::header::HeaderMap> {
     let mut headers = reqwest::header::HeaderMap::new();
 
-    match auth_method {
-        ACSAuthMethod::SharedKey(share_key) => {
-            headers = get_request_header(url_endpoint, method, request_id, json_body, share_key)
+    match auth {
+        RequestAuth::SharedKey(key) => {
+            headers = get_request_header(url_endpoint, method, request_id, json_body, key)
                 .map_err(header_err)?
         }
-        ACSAuthMethod::ServicePrincipal { .. } | ACSAuthMethod::ManagedIdentity => {
-            let token = get_access_token(http_client, auth_method)
-                .await
-                .map_err(auth_err)?;
+        RequestAuth::Bearer(token) => {
             headers.insert(
                 reqwest::header::AUTHORIZATION,
                 format!("Bearer {}", token).parse().unwrap(),
@@ -1076,19 +2511,24 @@ fn header_err(detail: impl ToString) -> ACSError {
     ACSError::Header(detail.to_string())
 }
 
-fn auth_err(detail: impl ToString) -> ACSError {
+pub(crate) fn auth_err(detail: impl ToString) -> ACSError {
     ACSError::Auth(detail.to_string())
 }
 
-/// Fetch the current delivery status for a single ACS operation ID.
-#[instrument(skip(http_client, acs_auth_method), fields(base_url = %base_url))]
-async fn acs_get_email_status(
+/// Fetch the current state of a single ACS email operation.
+///
+/// Transient failures are retried according to `retry_policy`.
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip(http_client, acs_auth_method, retry_policy), fields(base_url = %base_url))]
+async fn acs_get_email_operation(
     http_client: &Client,
     base_url: &str,
     acs_auth_method: &ACSAuthMethod,
     request_id: &str,
     api_version: &ACSApiVersion,
-) -> EmailResult<EmailSendStatusType> {
+    retry_policy: &dyn RetryPolicy,
+    max_retries: u32,
+) -> EmailResult<EmailOperation> {
     let url = format!(
         "{}/emails/operations/{}?api-version={}",
         base_url,
@@ -1097,7 +2537,7 @@ async fn acs_get_email_status(
     );
     debug!("end point URL: {}", url);
 
-    let response = send_request::<()>(
+    let response = send_request_with_retry::<()>(
         http_client,
         reqwest::Method::GET,
         &url,
@@ -1105,14 +2545,26 @@ async fn acs_get_email_status(
         None,
         acs_auth_method,
         None,
+        None,
+        retry_policy,
+        max_retries,
+        None,
+        None,
     )
     .await?;
     if response.status() == StatusCode::OK {
+        let headers = response.headers().clone();
         let email_response = parse_response::<SentEmailResponse>(response).await?;
-        email_response
+        let status = email_response
             .status
-            .map(|status| Ok(status.to_type()))
-            .unwrap_or_else(|| Err(create_missing_status_error()))
+            .ok_or_else(create_missing_status_error)?
+            .to_type();
+        Ok(EmailOperation {
+            id: email_response.id.unwrap_or_else(|| request_id.to_string()),
+            status,
+            error: email_response.error,
+            headers,
+        })
     } else {
         let error_response = parse_response::<ErrorResponse>(response).await?;
         Err(ACSError::from(error_response))
@@ -1121,6 +2573,7 @@ async fn acs_get_email_status(
 
 fn build_repeatability_headers(
     idempotency_key: Option<&str>,
+    first_sent: Option<SystemTime>,
 ) -> Option<reqwest::header::HeaderMap> {
     let key = idempotency_key?;
     let mut headers = reqwest::header::HeaderMap::new();
@@ -1130,21 +2583,38 @@ fn build_repeatability_headers(
     );
     headers.insert(
         reqwest::header::HeaderName::from_static("repeatability-first-sent"),
-        httpdate::fmt_http_date(std::time::SystemTime::now())
+        httpdate::fmt_http_date(first_sent.unwrap_or_else(SystemTime::now))
             .parse()
             .unwrap(),
     );
     Some(headers)
 }
 
+/// Merge the repeatability headers and caller-supplied extra headers of `options`.
+///
+/// Extra headers are applied last, so a caller can override any header this
+/// crate sets — including the repeatability pair.
+fn build_extra_headers(options: &SendOptions) -> Option<reqwest::header::HeaderMap> {
+    let mut headers =
+        build_repeatability_headers(options.idempotency_key.as_deref(), options.first_sent);
+    if !options.extra_headers.is_empty() {
+        let merged = headers.get_or_insert_with(reqwest::header::HeaderMap::new);
+        for (key, value) in options.extra_headers.iter() {
+            merged.insert(key.clone(), value.clone());
+        }
+    }
+    headers
+}
+
 /// POST a single email to the ACS `emails:send` endpoint and return the operation ID.
 ///
-/// Handles the initial request and delegates retry / backoff logic to
-/// [`handle_response_and_retry_if_needed`].  When `idempotency_key` is `Some`,
-/// the `repeatability-request-id` and `repeatability-first-sent` headers are
-/// included on the initial request and all retries.
+/// Retry / backoff is delegated to [`send_request_with_retry`].  The
+/// repeatability headers derived from `options.idempotency_key` and any
+/// `options.extra_headers` are included on the initial request and all retries.
+/// Without a caller key a fresh one is generated for this call, so a retry
+/// after a network error or `5xx` cannot deliver the email twice.
 #[allow(clippy::too_many_arguments)]
-#[instrument(skip(http_client, acs_auth_method, email, idempotency_key), fields(base_url = %base_url, max_retries = %max_retries))]
+#[instrument(skip(http_client, acs_auth_method, email, retry_policy, options), fields(base_url = %base_url, max_retries = %max_retries))]
 async fn acs_send_email(
     http_client: &Client,
     base_url: &str,
@@ -1152,8 +2622,10 @@ async fn acs_send_email(
     request_id: &str,
     email: &SentEmail,
     api_version: &ACSApiVersion,
+    retry_policy: &dyn RetryPolicy,
     max_retries: u32,
-    idempotency_key: Option<&str>,
+    options: &SendOptions,
+    permit: Option<&ConcurrencyPermit>,
 ) -> EmailResult<String> {
     let url = format!(
         "{}/emails:send?api-version={}",
@@ -1161,8 +2633,15 @@ async fn acs_send_email(
         api_version.as_str()
     );
     debug!("end point URL: {}", url);
-    let extra_headers = build_repeatability_headers(idempotency_key);
-    let response = send_request(
+    let generated;
+    let options = if options.idempotency_key.is_none() {
+        generated = options.clone().idempotency_key(&Uuid::new_v4().to_string());
+        &generated
+    } else {
+        options
+    };
+    let extra_headers = build_extra_headers(options);
+    let response = send_request_with_retry(
         http_client,
         reqwest::Method::POST,
         &url,
@@ -1170,102 +2649,174 @@ async fn acs_send_email(
         Some(email),
         acs_auth_method,
         extra_headers.as_ref(),
+        options.timeout,
+        retry_policy,
+        max_retries,
+        options.cancellation_token.as_ref(),
+        permit,
     )
     .await?;
     debug!("{:#?}", response);
-    handle_response_and_retry_if_needed(
-        http_client,
-        response,
-        reqwest::Method::POST,
-        &url,
-        request_id,
-        Some(email),
-        acs_auth_method,
-        max_retries,
-        extra_headers.as_ref(),
+    if response.status() == StatusCode::ACCEPTED {
+        parse_response::<SentEmailResponse>(response)
+            .await?
+            .id
+            .ok_or_else(create_missing_id_error)
+    } else {
+        error!("Failed to send email: {:#?}", response);
+        parse_error_response(response).await
+    }
+}
+
+/// `429` and `503` are ACS's throttling responses.
+fn is_throttling_status(status: StatusCode) -> bool {
+    matches!(
+        status,
+        StatusCode::TOO_MANY_REQUESTS | StatusCode::SERVICE_UNAVAILABLE
     )
-    .await
 }
-/// Inspect an HTTP response and retry on `429` / `503` up to `max_retries` times.
+
+/// Send a request, retrying transient failures according to `retry_policy`.
 ///
-/// On `202 Accepted` the operation ID is extracted from the response body.
-/// On `429 Too Many Requests` or `503 Service Unavailable` the request is
-/// retried after the delay specified in the `Retry-After` header, or after
-/// exponential backoff (`2^n` seconds) when that header is absent.
-/// Any other status code is treated as a permanent failure.
+/// Network errors and responses whose status the policy deems retryable are
+/// retried up to `max_retries` times, or until the next attempt would start
+/// after the policy's `max_elapsed` budget.  The delay before each retry is
+/// taken from the service's throttling headers when present (see
+/// [`parse_retry_after`], clamped to [`RetryPolicy::max_retry_after`]),
+/// otherwise from [`RetryPolicy::backoff`].  A cancelled `token` interrupts the back-off
+/// with [`ACSError::Canceled`].  Every throttling response is reported to
+/// `permit`, when the request runs under the batch concurrency limit.
+///
+/// A `401 Unauthorized` forces one token refresh (bearer auth) or a switch
+/// to the fallback access key (shared key auth), and an immediate resend
+/// that does not count as a retry.
+///
+/// Returns the first non-retryable response for the caller to interpret.
+/// When retries are exhausted on `429` / `503` the call fails with
+/// [`ACSError::RateLimitExceeded`]; other exhausted statuses return the last
+/// response, and exhausted network errors return the last error.
 #[allow(clippy::too_many_arguments)]
-async fn handle_response_and_retry_if_needed<T>(
+async fn send_request_with_retry<T>(
     http_client: &Client,
-    mut response: reqwest::Response,
     method: reqwest::Method,
     url: &str,
     request_id: &str,
     body: Option<&T>,
     acs_auth_method: &ACSAuthMethod,
-    max_retries: u32,
     extra_headers: Option<&reqwest::header::HeaderMap>,
-) -> EmailResult<String>
+    timeout: Option<Duration>,
+    retry_policy: &dyn RetryPolicy,
+    max_retries: u32,
+    token: Option<&CancellationToken>,
+    permit: Option<&ConcurrencyPermit>,
+) -> EmailResult<reqwest::Response>
 where
     T: serde::Serialize,
 {
+    let started = tokio::time::Instant::now();
     let mut retries = 0;
+    let mut reauthenticated = false;
 
     loop {
-        match response.status() {
-            StatusCode::ACCEPTED => {
-                return parse_response::<SentEmailResponse>(response)
-                    .await?
-                    .id
-                    .ok_or_else(create_missing_id_error);
+        // The key or token this attempt is signed with and its generation,
+        // so a 401 only triggers a refresh if nobody has refreshed since.
+        let (auth, auth_generation) = match acs_auth_method {
+            ACSAuthMethod::SharedKey(keys) => {
+                (RequestAuth::SharedKey(keys.active()), keys.generation())
             }
-            StatusCode::TOO_MANY_REQUESTS | StatusCode::SERVICE_UNAVAILABLE => {
-                if retries >= max_retries {
-                    error!("Max retries exceeded");
-                    return Err(ACSError::RateLimitExceeded { retries });
-                }
+            ACSAuthMethod::Bearer(cache) => {
+                let (token, generation) = cache.token_with_generation().await?;
+                (RequestAuth::Bearer(token), generation)
+            }
+        };
+        let result = send_request(
+            http_client,
+            method.clone(),
+            url,
+            request_id,
+            body,
+            &auth,
+            extra_headers,
+            timeout,
+        )
+        .await;
+
+        if let (Ok(response), Some(permit)) = (&result, permit) {
+            if is_throttling_status(response.status()) {
+                permit.throttled();
+            }
+        }
 
-                if let Some(retry_after) = response.headers().get(RETRY_AFTER) {
-                    if let Ok(retry_after_value) = retry_after.to_str() {
-                        if let Ok(retry_after_secs) = retry_after_value.parse::<u64>() {
-                            debug!("Retrying after {} seconds", retry_after_secs);
-                            sleep(Duration::from_secs(retry_after_secs)).await;
-                        } else {
-                            error!("Failed to parse Retry-After header value");
-                            return parse_error_response(response).await;
+        if let Ok(response) = &result {
+            if response.status() == StatusCode::UNAUTHORIZED && !reauthenticated {
+                match acs_auth_method {
+                    ACSAuthMethod::Bearer(cache) => {
+                        debug!("ACS rejected the access token; refreshing");
+                        cache.refresh_rejected(auth_generation).await?;
+                        reauthenticated = true;
+                        continue;
+                    }
+                    ACSAuthMethod::SharedKey(keys) => {
+                        if keys.fail_over(auth_generation) {
+                            debug!("ACS rejected the access key; re-signing");
+                            reauthenticated = true;
+                            continue;
                         }
-                    } else {
-                        error!("Failed to parse Retry-After header value");
-                        return parse_error_response(response).await;
                     }
-                } else {
-                    // Implement exponential backoff
-                    let backoff_secs = 2u64.pow(retries);
+                }
+            }
+        }
+
+        let (retry_after, throttled) = match &result {
+            Ok(response) if retry_policy.is_retryable_status(response.status()) => {
+                let throttled = is_throttling_status(response.status());
+                let requested = parse_retry_after(response.headers(), SystemTime::now());
+                if let Some(requested) = requested {
                     debug!(
-                        "Retry-After header not found. Retrying after {} seconds",
-                        backoff_secs
+                        status = %response.status(),
+                        requested_ms = requested.as_millis() as u64,
+                        "Service requested back-off"
                     );
-                    sleep(Duration::from_secs(backoff_secs)).await;
                 }
-
-                retries += 1;
-
-                let new_response = send_request(
-                    http_client,
-                    method.clone(),
-                    url,
-                    request_id,
-                    body,
-                    acs_auth_method,
-                    extra_headers,
-                )
-                .await?;
-                response = new_response;
+                let clamped = requested.map(|d| d.min(retry_policy.max_retry_after()));
+                (clamped, throttled)
             }
-            _ => {
-                error!("Failed to send email: {:#?}", response);
-                return parse_error_response(response).await;
+            Err(e) if retry_policy.is_retryable_error(e) => (None, false),
+            _ => return result,
+        };
+
+        let delay = retry_after.unwrap_or_else(|| retry_policy.backoff(retries));
+        let over_budget = retry_policy
+            .max_elapsed()
+            .is_some_and(|budget| started.elapsed() + delay > budget);
+        if retries >= max_retries || over_budget {
+            error!("Max retries exceeded");
+            return if throttled {
+                Err(ACSError::RateLimitExceeded {
+                    retries,
+                    retry_after,
+                })
+            } else {
+                result
+            };
+        }
+
+        debug!(
+            retry = retries + 1,
+            delay_ms = delay.as_millis() as u64,
+            from_server = retry_after.is_some(),
+            "Retrying request"
+        );
+        match token {
+            Some(token) => {
+                tokio::select! {
+                    _ = token.cancelled() => return Err(ACSError::Canceled),
+                    _ = sleep(delay) => {}
+                }
             }
+            None => sleep(delay).await,
         }
+        retries += 1;
     }
 }
 
@@ -1283,7 +2834,33 @@ async fn parse_error_response(response: reqwest::Response) -> EmailResult<String
     Err(ACSError::from(error_response))
 }
 
-fn is_terminal_status(status: &EmailSendStatusType) -> bool {
+/// Pass one tracker update to a status callback; returns `false` once the
+/// update ends the operation.  Errors are reported with a `Failed` status.
+fn deliver_update<F>(
+    call_back: &F,
+    message_id: &str,
+    update: EmailResult<EmailSendStatusType>,
+) -> bool
+where
+    F: Fn(String, &EmailSendStatusType, Option<ACSError>),
+{
+    match update {
+        Ok(status) => {
+            call_back(message_id.to_string(), &status, None);
+            !is_terminal_status(&status)
+        }
+        Err(e) => {
+            call_back(
+                message_id.to_string(),
+                &EmailSendStatusType::Failed,
+                Some(e),
+            );
+            false
+        }
+    }
+}
+
+pub(crate) fn is_terminal_status(status: &EmailSendStatusType) -> bool {
     matches!(
         status,
         EmailSendStatusType::Succeeded
@@ -1293,7 +2870,7 @@ fn is_terminal_status(status: &EmailSendStatusType) -> bool {
     )
 }
 
-fn create_missing_status_error() -> ACSError {
+pub(crate) fn create_missing_status_error() -> ACSError {
     ACSError::MissingField("status")
 }
 
@@ -1487,7 +3064,10 @@ mod tests {
 
     #[test]
     fn acs_error_display_rate_limit() {
-        let e = ACSError::RateLimitExceeded { retries: 3 };
+        let e = ACSError::RateLimitExceeded {
+            retries: 3,
+            retry_after: None,
+        };
         assert!(e.to_string().contains("3"));
     }
 
@@ -1539,6 +3119,56 @@ mod tests {
         assert!(std::sync::Arc::strong_count(&wrapped) >= 1);
     }
 
+    // ── batch concurrency ────────────────────────────────────────────────────
+
+    #[test]
+    fn builder_default_batch_concurrency_bounds() {
+        let conn = "endpoint=https://example.com;accesskey=c2VjcmV0";
+        let client = ACSClientBuilder::new()
+            .connection_string(conn)
+            .build()
+            .unwrap();
+        let snapshot = client.batch_concurrency();
+        assert_eq!(snapshot.floor, DEFAULT_CONCURRENCY_FLOOR);
+        assert_eq!(snapshot.ceiling, DEFAULT_CONCURRENCY_CEILING);
+        assert_eq!(snapshot.limit, DEFAULT_CONCURRENCY_FLOOR as f64);
+    }
+
+    #[test]
+    fn builder_rejects_invalid_batch_concurrency_bounds() {
+        let conn = "endpoint=https://example.com;accesskey=c2VjcmV0";
+        for (floor, ceiling) in [(0, 4), (5, 4)] {
+            let result = ACSClientBuilder::new()
+                .connection_string(conn)
+                .batch_concurrency(floor, ceiling)
+                .build();
+            assert!(result.is_err(), "{floor}..={ceiling} should be rejected");
+        }
+    }
+
+    #[test]
+    fn builder_rejects_zero_rate_limit_quotas() {
+        let conn = "endpoint=https://example.com;accesskey=c2VjcmV0";
+        for (per_minute, per_hour) in [(0, 100), (10, 0)] {
+            let requests = ACSClientBuilder::new()
+                .connection_string(conn)
+                .rate_limit(per_minute, per_hour)
+                .build();
+            assert!(
+                requests.is_err(),
+                "{per_minute}/{per_hour} should be rejected"
+            );
+            let recipients = ACSClientBuilder::new()
+                .connection_string(conn)
+                .recipient_rate_limit(per_minute, per_hour)
+                .build();
+            assert!(
+                recipients.is_err(),
+                "{per_minute}/{per_hour} should be rejected"
+            );
+        }
+    }
+
     // ── Phase 2: max_retries ─────────────────────────────────────────────────
 
     #[test]
@@ -1633,7 +3263,10 @@ mod tests {
 
     #[test]
     fn rate_limit_exceeded_carries_retry_count() {
-        let err = ACSError::RateLimitExceeded { retries: 7 };
+        let err = ACSError::RateLimitExceeded {
+            retries: 7,
+            retry_after: None,
+        };
         assert!(err.to_string().contains("7"));
     }
 
@@ -1641,6 +3274,7 @@ mod tests {
     fn rate_limit_exceeded_display_mentions_retries() {
         let err = ACSError::RateLimitExceeded {
             retries: DEFAULT_MAX_RETRIES,
+            retry_after: None,
         };
         let s = err.to_string();
         assert!(s.contains("rate limit"));
@@ -1776,7 +3410,7 @@ mod tests {
             .connection_string(conn)
             .build()
             .unwrap();
-        assert_eq!(client.poll_interval, DEFAULT_POLL_INTERVAL);
+        assert_eq!(client.poll_strategy.delay(0), DEFAULT_POLL_INTERVAL);
     }
 
     #[test]
@@ -1787,7 +3421,7 @@ mod tests {
             .poll_interval(Duration::from_millis(500))
             .build()
             .unwrap();
-        assert_eq!(client.poll_interval, Duration::from_millis(500));
+        assert_eq!(client.poll_strategy.delay(0), Duration::from_millis(500));
     }
 
     #[test]
@@ -1798,7 +3432,7 @@ mod tests {
             .poll_interval(Duration::from_secs(10))
             .build()
             .unwrap();
-        assert_eq!(client.poll_interval, Duration::from_secs(10));
+        assert_eq!(client.poll_strategy.delay(0), Duration::from_secs(10));
     }
 
     #[test]
@@ -1809,7 +3443,7 @@ mod tests {
             .poll_interval(Duration::from_millis(250))
             .build()
             .unwrap();
-        assert_eq!(client.poll_interval, Duration::from_millis(250));
+        assert_eq!(client.poll_strategy.delay(0), Duration::from_millis(250));
     }
 
     #[test]
@@ -1821,7 +3455,76 @@ mod tests {
             .build()
             .unwrap();
         let cloned = client.clone();
-        assert_eq!(cloned.poll_interval, Duration::from_secs(7));
+        assert_eq!(cloned.poll_strategy.delay(0), Duration::from_secs(7));
+    }
+
+    // ── retry_policy ──────────────────────────────────────────────────────────
+
+    #[test]
+    fn builder_retry_policy_overrides_max_retries() {
+        use crate::adapters::gateways::retry::FixedDelay;
+
+        let conn = "endpoint=https://example.com;accesskey=c2VjcmV0";
+        let client = ACSClientBuilder::new()
+            .connection_string(conn)
+            .max_retries(1)
+            .retry_policy(FixedDelay::new(Duration::from_millis(10)).max_retries(6))
+            .build()
+            .unwrap();
+        assert_eq!(client.max_retries, 6);
+        assert_eq!(client.retry_policy.max_retries(), 6);
+    }
+
+    // ── SendOptions ───────────────────────────────────────────────────────────
+
+    #[test]
+    fn send_options_default_waits_for_acceptance_only() {
+        let options = SendOptions::new();
+        assert_eq!(options.wait, WaitMode::Accepted);
+        assert!(options.idempotency_key.is_none());
+        assert!(options.timeout.is_none());
+        assert!(options.max_retries.is_none());
+        assert!(options.cancellation_token.is_none());
+    }
+
+    #[test]
+    fn send_options_setters_store_values() {
+        let options = SendOptions::new()
+            .idempotency_key("key-1")
+            .timeout(Duration::from_secs(3))
+            .max_retries(7)
+            .wait(WaitMode::Terminal(Duration::from_secs(30)));
+        assert_eq!(options.idempotency_key.as_deref(), Some("key-1"));
+        assert_eq!(options.timeout, Some(Duration::from_secs(3)));
+        assert_eq!(options.max_retries, Some(7));
+        assert_eq!(options.wait, WaitMode::Terminal(Duration::from_secs(30)));
+    }
+
+    #[test]
+    fn build_extra_headers_none_without_key_or_headers() {
+        assert!(build_extra_headers(&SendOptions::new()).is_none());
+    }
+
+    #[test]
+    fn build_extra_headers_merges_repeatability_and_custom_headers() {
+        let options = SendOptions::new().idempotency_key("key-2").header(
+            HeaderName::from_static("x-correlation-id"),
+            HeaderValue::from_static("corr-1"),
+        );
+        let headers = build_extra_headers(&options).unwrap();
+        assert_eq!(headers["repeatability-request-id"], "key-2");
+        assert!(headers.contains_key("repeatability-first-sent"));
+        assert_eq!(headers["x-correlation-id"], "corr-1");
+    }
+
+    #[test]
+    fn build_extra_headers_custom_header_overrides_repeatability() {
+        let options = SendOptions::new().idempotency_key("key-3").header(
+            HeaderName::from_static("repeatability-request-id"),
+            HeaderValue::from_static("override"),
+        );
+        let headers = build_extra_headers(&options).unwrap();
+        assert_eq!(headers["repeatability-request-id"], "override");
     }
 }
 
@@ -2023,7 +3726,7 @@ mod integration_tests {
         let result = client.send_email(&minimal_email()).await;
         assert!(matches!(
             result,
-            Err(ACSError::RateLimitExceeded { retries: 2 })
+            Err(ACSError::RateLimitExceeded { retries: 2, .. })
         ));
     }
 
@@ -2082,6 +3785,7 @@ mod integration_tests {
     // ── send_email_with_callback ──────────────────────────────────────────────
 
     #[tokio::test]
+    #[allow(deprecated)]
     async fn send_email_with_callback_invokes_callback_on_succeeded() {
         let server = MockServer::start().await;
         Mock::given(method("POST"))
@@ -2133,6 +3837,7 @@ mod integration_tests {
     }
 
     #[tokio::test]
+    #[allow(deprecated)]
     async fn send_email_with_callback_returns_error_when_send_fails() {
         let server = MockServer::start().await;
         Mock::given(method("POST"))
@@ -2426,6 +4131,7 @@ mod integration_tests {
     // ── #12 send_email_with_callback_cancellable ──────────────────────────────
 
     #[tokio::test]
+    #[allow(deprecated)]
     async fn send_email_with_callback_cancellable_stops_when_already_cancelled() {
         let server = MockServer::start().await;
         Mock::given(method("POST"))
@@ -2448,804 +4154,3447 @@ mod integration_tests {
             })
             .await
             .unwrap();
-        assert_eq!(message_id, "cc-msg");
-        let _ = done_rx.await;
-        assert_eq!(callback_count.load(std::sync::atomic::Ordering::SeqCst), 0);
+        assert_eq!(message_id, "cc-msg");
+        let _ = done_rx.await;
+        assert_eq!(callback_count.load(std::sync::atomic::Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    #[allow(deprecated)]
+    async fn send_email_with_callback_cancellable_invokes_callback_on_success() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/emails:send"))
+            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "cbs-msg" })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/emails/operations/cbs-msg"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({ "id": "cbs-msg", "status": "Succeeded" })),
+            )
+            .mount(&server)
+            .await;
+
+        let token = CancellationToken::new(); // never cancelled
+        let statuses: std::sync::Arc<std::sync::Mutex<Vec<String>>> =
+            std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let s = statuses.clone();
+
+        let client = client_for(&server);
+        let (_, done_rx) = client
+            .send_email_with_callback_cancellable(&minimal_email(), token, move |_, status, _| {
+                s.lock().unwrap().push(status.to_string());
+            })
+            .await
+            .unwrap();
+        let _ = done_rx.await;
+        let calls = statuses.lock().unwrap();
+        assert!(calls.iter().any(|s| s == "Succeeded"));
+    }
+
+    #[tokio::test]
+    #[allow(deprecated)]
+    async fn send_email_with_callback_cancellable_returns_error_when_send_fails() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/emails:send"))
+            .respond_with(ResponseTemplate::new(500).set_body_json(json!({
+                "error": { "code": "InternalError", "message": "fail" }
+            })))
+            .mount(&server)
+            .await;
+
+        let token = CancellationToken::new();
+        let client = client_for(&server);
+        let result = client
+            .send_email_with_callback_cancellable(&minimal_email(), token, |_, _, _| {})
+            .await;
+        assert!(matches!(result, Err(ACSError::Api { .. })));
+    }
+
+    // ── #11 batch: mixed success / failure ───────────────────────────────────
+
+    #[tokio::test]
+    async fn send_emails_batch_mixed_success_and_failure() {
+        let server = MockServer::start().await;
+        // First request to arrive → 500 (exhausted after 1 hit)
+        Mock::given(method("POST"))
+            .and(path("/emails:send"))
+            .respond_with(ResponseTemplate::new(500).set_body_json(json!({
+                "error": { "code": "InternalError", "message": "boom" }
+            })))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        // Every subsequent request → 202
+        Mock::given(method("POST"))
+            .and(path("/emails:send"))
+            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "ok-id" })))
+            .mount(&server)
+            .await;
+
+        let client = client_for(&server);
+        let results = client
+            .send_emails_batch(&[minimal_email(), minimal_email()])
+            .await;
+
+        assert_eq!(results.len(), 2);
+        let ok_count = results.iter().filter(|r| r.is_ok()).count();
+        let err_count = results.iter().filter(|r| r.is_err()).count();
+        assert_eq!(ok_count, 1, "exactly one send should succeed");
+        assert_eq!(err_count, 1, "exactly one send should fail");
+    }
+
+    #[tokio::test]
+    async fn send_emails_batch_propagates_max_retries_config() {
+        let server = MockServer::start().await;
+        // Always 429 — exhausts retries
+        Mock::given(method("POST"))
+            .and(path("/emails:send"))
+            .respond_with(ResponseTemplate::new(429).set_body_json(json!({
+                "error": { "code": "TooManyRequests", "message": "slow down" }
+            })))
+            .mount(&server)
+            .await;
+
+        let client = ACSClientBuilder::new()
+            .connection_string(FAKE_CONN)
+            .max_retries(2)
+            .base_url_override(&server.uri())
+            .build()
+            .unwrap();
+
+        let results = client.send_emails_batch(&[minimal_email()]).await;
+        assert_eq!(results.len(), 1);
+        assert!(matches!(
+            results[0],
+            Err(ACSError::RateLimitExceeded { retries: 2, .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn send_emails_batch_returns_error_on_malformed_response() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/emails:send"))
+            .respond_with(ResponseTemplate::new(202).set_body_string("not json"))
+            .mount(&server)
+            .await;
+
+        let client = client_for(&server);
+        let results = client.send_emails_batch(&[minimal_email()]).await;
+        assert_eq!(results.len(), 1);
+        assert!(matches!(results[0], Err(ACSError::Deserialization(_))));
+    }
+
+    // ── #12 stream cancellable: missing paths ─────────────────────────────────
+
+    #[tokio::test]
+    async fn send_email_stream_cancellable_stops_on_status_error() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/emails:send"))
+            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "cse-op" })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/emails/operations/cse-op"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+                "error": { "code": "NotFound", "message": "operation not found" }
+            })))
+            .mount(&server)
+            .await;
+
+        let token = CancellationToken::new(); // never cancelled
+        let client = client_for(&server);
+        let (_, stream) = client
+            .send_email_stream_cancellable(&minimal_email(), token)
+            .await
+            .unwrap();
+
+        tokio::pin!(stream);
+        use futures::StreamExt;
+        let first = stream
+            .next()
+            .await
+            .expect("stream must yield an error item");
+        assert!(matches!(first, Err(ACSError::Api { .. })));
+        // stream must terminate after an error — next call returns None
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn send_email_stream_cancellable_running_then_succeeded() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/emails:send"))
+            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "rs-op" })))
+            .mount(&server)
+            .await;
+        // First poll → Running
+        Mock::given(method("GET"))
+            .and(path("/emails/operations/rs-op"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "id": "rs-op", "status": "Running"
+            })))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        // Second poll → Succeeded
+        Mock::given(method("GET"))
+            .and(path("/emails/operations/rs-op"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "id": "rs-op", "status": "Succeeded"
+            })))
+            .mount(&server)
+            .await;
+
+        let token = CancellationToken::new();
+        let client = client_for(&server);
+        let (_, stream) = client
+            .send_email_stream_cancellable(&minimal_email(), token)
+            .await
+            .unwrap();
+
+        tokio::pin!(stream);
+        use futures::StreamExt;
+        let mut statuses = Vec::new();
+        while let Some(item) = stream.next().await {
+            statuses.push(item.unwrap());
+        }
+        assert_eq!(
+            statuses,
+            vec![EmailSendStatusType::Running, EmailSendStatusType::Succeeded]
+        );
+    }
+
+    // ── #12 callback cancellable: missing paths ───────────────────────────────
+
+    #[tokio::test]
+    #[allow(deprecated)]
+    async fn send_email_with_callback_cancellable_reports_error_on_status_failure() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/emails:send"))
+            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "cpe-msg" })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/emails/operations/cpe-msg"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+                "error": { "code": "NotFound", "message": "operation not found" }
+            })))
+            .mount(&server)
+            .await;
+
+        let token = CancellationToken::new();
+        let had_error: std::sync::Arc<std::sync::atomic::AtomicBool> =
+            std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let flag = had_error.clone();
+
+        let client = client_for(&server);
+        let (_, done_rx) = client
+            .send_email_with_callback_cancellable(&minimal_email(), token, move |_, _, err| {
+                if err.is_some() {
+                    flag.store(true, std::sync::atomic::Ordering::SeqCst);
+                }
+            })
+            .await
+            .unwrap();
+
+        let _ = done_rx.await;
+        assert!(
+            had_error.load(std::sync::atomic::Ordering::SeqCst),
+            "callback should have received a non-None error"
+        );
+    }
+
+    #[tokio::test]
+    #[allow(deprecated)]
+    async fn send_email_with_callback_cancellable_running_then_succeeded() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/emails:send"))
+            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "crts-msg" })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/emails/operations/crts-msg"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "id": "crts-msg", "status": "Running"
+            })))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/emails/operations/crts-msg"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "id": "crts-msg", "status": "Succeeded"
+            })))
+            .mount(&server)
+            .await;
+
+        let token = CancellationToken::new();
+        let statuses: std::sync::Arc<std::sync::Mutex<Vec<String>>> =
+            std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let s = statuses.clone();
+
+        let client = client_for(&server);
+        let (_, done_rx) = client
+            .send_email_with_callback_cancellable(&minimal_email(), token, move |_, status, _| {
+                s.lock().unwrap().push(status.to_string());
+            })
+            .await
+            .unwrap();
+
+        let _ = done_rx.await;
+        let calls = statuses.lock().unwrap();
+        assert!(calls.contains(&"Running".to_string()));
+        assert!(calls.contains(&"Succeeded".to_string()));
+        assert_eq!(*calls.last().unwrap(), "Succeeded");
+    }
+
+    // ── #13 clone: get_email_status and different auth path ───────────────────
+
+    #[tokio::test]
+    async fn cloned_client_get_email_status_works() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/emails/operations/clone-op"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "id": "clone-op", "status": "Succeeded"
+            })))
+            .mount(&server)
+            .await;
+
+        let original = client_for(&server);
+        let cloned = original.clone();
+        let result = cloned.get_email_status("clone-op").await;
+        assert!(matches!(result, Ok(EmailSendStatusType::Succeeded)));
+    }
+
+    #[tokio::test]
+    async fn clone_preserves_api_version_in_requests() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/emails:send"))
+            .and(query_param("api-version", "2025-09-01"))
+            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "ver-id" })))
+            .mount(&server)
+            .await;
+
+        let original = ACSClientBuilder::new()
+            .connection_string(FAKE_CONN)
+            .api_version(ACSApiVersion::V20250901)
+            .max_retries(0)
+            .base_url_override(&server.uri())
+            .build()
+            .unwrap();
+        let cloned = original.clone();
+
+        let result = cloned.send_email(&minimal_email()).await;
+        assert_eq!(result.unwrap(), "ver-id");
+    }
+
+    // ── send_request_with_retry: Retry-After header ───────────────────────────
+
+    #[tokio::test]
+    async fn send_email_respects_retry_after_header() {
+        let server = MockServer::start().await;
+        // First request → 429 with Retry-After: 1
+        Mock::given(method("POST"))
+            .and(path("/emails:send"))
+            .respond_with(
+                ResponseTemplate::new(429)
+                    .append_header("Retry-After", "1")
+                    .set_body_json(json!({
+                        "error": { "code": "TooManyRequests", "message": "slow down" }
+                    })),
+            )
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        // Second request → 202
+        Mock::given(method("POST"))
+            .and(path("/emails:send"))
+            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "after-retry" })))
+            .mount(&server)
+            .await;
+
+        let client = ACSClientBuilder::new()
+            .connection_string(FAKE_CONN)
+            .max_retries(3)
+            .base_url_override(&server.uri())
+            .build()
+            .unwrap();
+
+        let result = client.send_email(&minimal_email()).await;
+        assert_eq!(result.unwrap(), "after-retry");
+    }
+
+    // ── send_email_and_wait ───────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn send_email_and_wait_returns_terminal_status() {
+        let server = MockServer::start().await;
+        let email = minimal_email();
+        let op_id = "op-wait-1";
+
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": op_id })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(json!({ "status": "Succeeded" })),
+            )
+            .mount(&server)
+            .await;
+
+        let client = ACSClientBuilder::new()
+            .connection_string(FAKE_CONN)
+            .base_url_override(&server.uri())
+            .poll_interval(Duration::from_millis(10))
+            .build()
+            .unwrap();
+
+        let result = client
+            .send_email_and_wait(&email, Duration::from_secs(5))
+            .await;
+        assert!(matches!(result, Ok(EmailSendStatusType::Succeeded)));
+    }
+
+    #[tokio::test]
+    async fn send_email_and_wait_times_out() {
+        let server = MockServer::start().await;
+        let email = minimal_email();
+        let op_id = "op-wait-timeout";
+
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": op_id })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "status": "Running" })))
+            .mount(&server)
+            .await;
+
+        let client = ACSClientBuilder::new()
+            .connection_string(FAKE_CONN)
+            .base_url_override(&server.uri())
+            .poll_interval(Duration::from_secs(60)) // longer than timeout
+            .build()
+            .unwrap();
+
+        let result = client
+            .send_email_and_wait(&email, Duration::from_millis(50))
+            .await;
+        assert!(matches!(result, Err(ACSError::Timeout)));
+    }
+
+    // ── send_email_idempotent ─────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn send_email_idempotent_sends_repeatability_headers() {
+        use wiremock::matchers::header_exists;
+
+        let server = MockServer::start().await;
+        let email = minimal_email();
+        let key = uuid::Uuid::new_v4().to_string();
+
+        Mock::given(method("POST"))
+            .and(header_exists("repeatability-request-id"))
+            .and(header_exists("repeatability-first-sent"))
+            .respond_with(
+                ResponseTemplate::new(202).set_body_json(json!({ "id": "op-idempotent-1" })),
+            )
+            .mount(&server)
+            .await;
+
+        let client = ACSClientBuilder::new()
+            .connection_string(FAKE_CONN)
+            .base_url_override(&server.uri())
+            .build()
+            .unwrap();
+
+        let result = client.send_email_idempotent(&email, &key).await;
+        assert!(result.is_ok(), "expected Ok, got: {result:?}");
+    }
+
+    #[tokio::test]
+    async fn send_email_idempotent_returns_operation_id() {
+        let server = MockServer::start().await;
+        let email = minimal_email();
+        let key = uuid::Uuid::new_v4().to_string();
+
+        Mock::given(method("POST"))
+            .respond_with(
+                ResponseTemplate::new(202).set_body_json(json!({ "id": "idempotent-op-42" })),
+            )
+            .mount(&server)
+            .await;
+
+        let client = ACSClientBuilder::new()
+            .connection_string(FAKE_CONN)
+            .base_url_override(&server.uri())
+            .build()
+            .unwrap();
+
+        let result = client.send_email_idempotent(&email, &key).await;
+        assert_eq!(result.unwrap(), "idempotent-op-42");
+    }
+
+    #[tokio::test]
+    async fn send_email_idempotent_retries_on_429_preserves_key() {
+        use wiremock::matchers::header_exists;
+
+        let server = MockServer::start().await;
+        let email = minimal_email();
+        let key = uuid::Uuid::new_v4().to_string();
+
+        // First request: 429; second attempt: 202
+        Mock::given(method("POST"))
+            .and(header_exists("repeatability-request-id"))
+            .respond_with(ResponseTemplate::new(429))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(header_exists("repeatability-request-id"))
+            .respond_with(
+                ResponseTemplate::new(202).set_body_json(json!({ "id": "idempotent-retry-op" })),
+            )
+            .mount(&server)
+            .await;
+
+        let client = ACSClientBuilder::new()
+            .connection_string(FAKE_CONN)
+            .base_url_override(&server.uri())
+            .max_retries(1)
+            .build()
+            .unwrap();
+
+        let result = client.send_email_idempotent(&email, &key).await;
+        assert_eq!(result.unwrap(), "idempotent-retry-op");
+    }
+
+    #[tokio::test]
+    async fn send_email_and_wait_failed_status_returns_delivery_failed() {
+        let server = MockServer::start().await;
+        let email = minimal_email();
+
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "op-fail" })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "id": "op-fail",
+                "status": "Failed",
+                "error": { "code": "EmailDroppedAllRecipientsSuppressed", "message": "suppressed" }
+            })))
+            .mount(&server)
+            .await;
+
+        let client = ACSClientBuilder::new()
+            .connection_string(FAKE_CONN)
+            .base_url_override(&server.uri())
+            .poll_interval(Duration::from_millis(10))
+            .build()
+            .unwrap();
+
+        let result = client
+            .send_email_and_wait(&email, Duration::from_secs(5))
+            .await;
+        match result {
+            Err(ACSError::DeliveryFailed {
+                operation_id,
+                error: Some(detail),
+                ..
+            }) => {
+                assert_eq!(operation_id, "op-fail");
+                assert_eq!(
+                    detail.code.as_deref(),
+                    Some("EmailDroppedAllRecipientsSuppressed")
+                );
+            }
+            other => panic!("expected DeliveryFailed, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn send_email_and_wait_send_fails_returns_send_error() {
+        let server = MockServer::start().await;
+        let email = minimal_email();
+
+        Mock::given(method("POST"))
+            .respond_with(
+                ResponseTemplate::new(500).set_body_json(
+                    json!({ "error": { "code": "InternalError", "message": "boom" } }),
+                ),
+            )
+            .mount(&server)
+            .await;
+
+        let client = ACSClientBuilder::new()
+            .connection_string(FAKE_CONN)
+            .base_url_override(&server.uri())
+            .max_retries(0)
+            .build()
+            .unwrap();
+
+        let result = client
+            .send_email_and_wait(&email, Duration::from_secs(5))
+            .await;
+        // Must be a send-level Api error, not Timeout
+        assert!(matches!(result, Err(ACSError::Api { .. })));
+    }
+
+    #[tokio::test]
+    async fn send_email_and_wait_poll_error_propagates() {
+        let server = MockServer::start().await;
+        let email = minimal_email();
+
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "op-poll-err" })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(
+                json!({ "error": { "code": "OperationNotFound", "message": "not found" } }),
+            ))
+            .mount(&server)
+            .await;
+
+        let client = ACSClientBuilder::new()
+            .connection_string(FAKE_CONN)
+            .base_url_override(&server.uri())
+            .poll_interval(Duration::from_millis(10))
+            .build()
+            .unwrap();
+
+        let result = client
+            .send_email_and_wait(&email, Duration::from_secs(5))
+            .await;
+        // Must be a poll-level Api error, not Timeout
+        assert!(matches!(result, Err(ACSError::Api { .. })));
+    }
+
+    #[tokio::test]
+    async fn send_email_and_wait_running_then_succeeded() {
+        let server = MockServer::start().await;
+        let email = minimal_email();
+
+        Mock::given(method("POST"))
+            .respond_with(
+                ResponseTemplate::new(202).set_body_json(json!({ "id": "op-running-ok" })),
+            )
+            .mount(&server)
+            .await;
+        // First GET: Running; subsequent GETs: Succeeded
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "status": "Running" })))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(json!({ "status": "Succeeded" })),
+            )
+            .mount(&server)
+            .await;
+
+        let client = ACSClientBuilder::new()
+            .connection_string(FAKE_CONN)
+            .base_url_override(&server.uri())
+            .poll_interval(Duration::from_millis(10))
+            .build()
+            .unwrap();
+
+        let result = client
+            .send_email_and_wait(&email, Duration::from_secs(5))
+            .await;
+        assert!(matches!(result, Ok(EmailSendStatusType::Succeeded)));
+    }
+
+    // ── send_email_and_wait_cancellable ───────────────────────────────────────
+
+    #[tokio::test]
+    async fn send_email_and_wait_cancellable_returns_terminal_status() {
+        let server = MockServer::start().await;
+        let email = minimal_email();
+
+        Mock::given(method("POST"))
+            .respond_with(
+                ResponseTemplate::new(202).set_body_json(json!({ "id": "op-cancellable-ok" })),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(json!({ "status": "Succeeded" })),
+            )
+            .mount(&server)
+            .await;
+
+        let client = ACSClientBuilder::new()
+            .connection_string(FAKE_CONN)
+            .base_url_override(&server.uri())
+            .poll_interval(Duration::from_millis(10))
+            .build()
+            .unwrap();
+
+        let token = CancellationToken::new();
+        let result = client
+            .send_email_and_wait_cancellable(&email, Duration::from_secs(5), token)
+            .await;
+        assert!(matches!(result, Ok(EmailSendStatusType::Succeeded)));
+    }
+
+    #[tokio::test]
+    async fn send_email_and_wait_cancellable_returns_canceled_when_token_fires() {
+        let server = MockServer::start().await;
+        let email = minimal_email();
+
+        Mock::given(method("POST"))
+            .respond_with(
+                ResponseTemplate::new(202).set_body_json(json!({ "id": "op-cancellable-tok" })),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "status": "Running" })))
+            .mount(&server)
+            .await;
+
+        let client = ACSClientBuilder::new()
+            .connection_string(FAKE_CONN)
+            .base_url_override(&server.uri())
+            .poll_interval(Duration::from_secs(60)) // longer than cancel fires
+            .build()
+            .unwrap();
+
+        let token = CancellationToken::new();
+        let token_clone = token.clone();
+        // Cancel after a short delay
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            token_clone.cancel();
+        });
+
+        let result = client
+            .send_email_and_wait_cancellable(&email, Duration::from_secs(5), token)
+            .await;
+        assert!(matches!(result, Err(ACSError::Canceled)));
+    }
+
+    #[tokio::test]
+    async fn send_email_and_wait_cancellable_already_cancelled_returns_canceled() {
+        let server = MockServer::start().await;
+        let email = minimal_email();
+
+        Mock::given(method("POST"))
+            .respond_with(
+                ResponseTemplate::new(202).set_body_json(json!({ "id": "op-precancelled" })),
+            )
+            .mount(&server)
+            .await;
+
+        let client = ACSClientBuilder::new()
+            .connection_string(FAKE_CONN)
+            .base_url_override(&server.uri())
+            .poll_interval(Duration::from_millis(10))
+            .build()
+            .unwrap();
+
+        let token = CancellationToken::new();
+        token.cancel(); // pre-cancelled before the call
+
+        let result = client
+            .send_email_and_wait_cancellable(&email, Duration::from_secs(5), token)
+            .await;
+        assert!(matches!(result, Err(ACSError::Canceled)));
+    }
+
+    #[tokio::test]
+    async fn send_email_and_wait_cancellable_times_out_before_cancel() {
+        let server = MockServer::start().await;
+        let email = minimal_email();
+
+        Mock::given(method("POST"))
+            .respond_with(
+                ResponseTemplate::new(202).set_body_json(json!({ "id": "op-timeout-wins" })),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "status": "Running" })))
+            .mount(&server)
+            .await;
+
+        let client = ACSClientBuilder::new()
+            .connection_string(FAKE_CONN)
+            .base_url_override(&server.uri())
+            .poll_interval(Duration::from_secs(60))
+            .build()
+            .unwrap();
+
+        let token = CancellationToken::new(); // never cancelled
+        let result = client
+            .send_email_and_wait_cancellable(&email, Duration::from_millis(50), token)
+            .await;
+        assert!(matches!(result, Err(ACSError::Timeout)));
+    }
+
+    #[tokio::test]
+    async fn send_email_and_wait_cancellable_send_fails_returns_send_error() {
+        let server = MockServer::start().await;
+        let email = minimal_email();
+
+        Mock::given(method("POST"))
+            .respond_with(
+                ResponseTemplate::new(500).set_body_json(
+                    json!({ "error": { "code": "InternalError", "message": "boom" } }),
+                ),
+            )
+            .mount(&server)
+            .await;
+
+        let client = ACSClientBuilder::new()
+            .connection_string(FAKE_CONN)
+            .base_url_override(&server.uri())
+            .max_retries(0)
+            .build()
+            .unwrap();
+
+        let token = CancellationToken::new();
+        let result = client
+            .send_email_and_wait_cancellable(&email, Duration::from_secs(5), token)
+            .await;
+        assert!(matches!(result, Err(ACSError::Api { .. })));
+    }
+
+    // ── send_email_with ───────────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn send_email_with_default_options_returns_operation_id_only() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/emails:send"))
+            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "with-1" })))
+            .mount(&server)
+            .await;
+
+        let client = client_for(&server);
+        let outcome = client
+            .send_email_with(&minimal_email(), &SendOptions::new())
+            .await
+            .unwrap();
+        assert_eq!(outcome.operation_id, "with-1");
+        assert!(outcome.status.is_none());
+    }
+
+    #[tokio::test]
+    async fn send_email_with_sends_extra_headers() {
+        use wiremock::matchers::header;
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(header("x-correlation-id", "corr-42"))
+            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "with-hdr" })))
+            .mount(&server)
+            .await;
+
+        let client = client_for(&server);
+        let options = SendOptions::new().header(
+            HeaderName::from_static("x-correlation-id"),
+            HeaderValue::from_static("corr-42"),
+        );
+        let outcome = client
+            .send_email_with(&minimal_email(), &options)
+            .await
+            .unwrap();
+        assert_eq!(outcome.operation_id, "with-hdr");
+    }
+
+    #[tokio::test]
+    async fn send_email_with_max_retries_overrides_client_setting() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(429).append_header("Retry-After", "0"))
+            .expect(3)
+            .mount(&server)
+            .await;
+
+        // client_for() disables retries; the per-call override re-enables two.
+        let client = client_for(&server);
+        let options = SendOptions::new().max_retries(2);
+        let result = client.send_email_with(&minimal_email(), &options).await;
+        assert!(matches!(
+            result,
+            Err(ACSError::RateLimitExceeded { retries: 2, .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn send_email_with_wait_terminal_returns_status() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "with-wait" })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/emails/operations/with-wait"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(json!({ "status": "Succeeded" })),
+            )
+            .mount(&server)
+            .await;
+
+        let client = ACSClientBuilder::new()
+            .connection_string(FAKE_CONN)
+            .base_url_override(&server.uri())
+            .poll_interval(Duration::from_millis(10))
+            .build()
+            .unwrap();
+        let options = SendOptions::new().wait(WaitMode::Terminal(Duration::from_secs(5)));
+        let outcome = client
+            .send_email_with(&minimal_email(), &options)
+            .await
+            .unwrap();
+        assert_eq!(outcome.operation_id, "with-wait");
+        assert_eq!(outcome.status, Some(EmailSendStatusType::Succeeded));
+    }
+
+    #[tokio::test]
+    async fn send_email_with_cancellation_interrupts_retry_backoff() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(429).append_header("Retry-After", "60"))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = client_for(&server);
+        let token = CancellationToken::new();
+        let options = SendOptions::new()
+            .max_retries(3)
+            .cancellation_token(token.clone());
+        let canceller = tokio::spawn(async move {
+            sleep(Duration::from_millis(50)).await;
+            token.cancel();
+        });
+        let result = client.send_email_with(&minimal_email(), &options).await;
+        canceller.await.unwrap();
+        assert!(matches!(result, Err(ACSError::Canceled)));
+    }
+
+    #[tokio::test]
+    async fn send_email_with_per_request_timeout_fails_slow_response() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(
+                ResponseTemplate::new(202)
+                    .set_body_json(json!({ "id": "too-slow" }))
+                    .set_delay(Duration::from_secs(2)),
+            )
+            .mount(&server)
+            .await;
+
+        let client = client_for(&server);
+        let options = SendOptions::new().timeout(Duration::from_millis(50));
+        let result = client.send_email_with(&minimal_email(), &options).await;
+        assert!(matches!(result, Err(ACSError::Network(_))));
+    }
+
+    // ── retry_policy ──────────────────────────────────────────────────────────
+
+    fn fast_retry_client(server: &MockServer, retries: u32) -> ACSClient {
+        use crate::adapters::gateways::retry::FixedDelay;
+
+        ACSClientBuilder::new()
+            .connection_string(FAKE_CONN)
+            .base_url_override(&server.uri())
+            .retry_policy(FixedDelay::new(Duration::from_millis(10)).max_retries(retries))
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn send_email_retries_on_502_then_succeeds() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(502))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "after-502" })))
+            .mount(&server)
+            .await;
+
+        let client = fast_retry_client(&server, 2);
+        let result = client.send_email(&minimal_email()).await;
+        assert_eq!(result.unwrap(), "after-502");
+    }
+
+    #[tokio::test]
+    async fn send_email_retry_after_500_reuses_generated_repeatability_id() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(500))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "after-500" })))
+            .mount(&server)
+            .await;
+
+        let client = fast_retry_client(&server, 2);
+        let result = client.send_email(&minimal_email()).await;
+        assert_eq!(result.unwrap(), "after-500");
+
+        let requests = server.received_requests().await.unwrap();
+        assert_eq!(requests.len(), 2);
+        let ids: Vec<_> = requests
+            .iter()
+            .map(|r| r.headers.get("repeatability-request-id").unwrap())
+            .collect();
+        assert_eq!(ids[0], ids[1]);
+        assert_eq!(
+            requests[0].headers.get("repeatability-first-sent"),
+            requests[1].headers.get("repeatability-first-sent")
+        );
+
+        // The next logical send gets a key of its own.
+        client.send_email(&minimal_email()).await.unwrap();
+        let requests = server.received_requests().await.unwrap();
+        assert_ne!(
+            requests[2].headers.get("repeatability-request-id"),
+            requests[1].headers.get("repeatability-request-id")
+        );
+    }
+
+    #[tokio::test]
+    async fn send_email_exhausted_500_returns_api_error() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(500).set_body_json(json!({
+                "error": { "code": "InternalError", "message": "still broken" }
+            })))
+            .expect(3)
+            .mount(&server)
+            .await;
+
+        let client = fast_retry_client(&server, 2);
+        let result = client.send_email(&minimal_email()).await;
+        assert!(matches!(result, Err(ACSError::Api { .. })));
+    }
+
+    #[tokio::test]
+    async fn send_email_retries_network_errors() {
+        use crate::adapters::gateways::retry::FixedDelay;
+
+        // Bind then drop a listener so the port refuses connections.
+        let addr = std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap();
+        let client = ACSClientBuilder::new()
+            .connection_string(FAKE_CONN)
+            .base_url_override(&format!("http://{addr}"))
+            .retry_policy(FixedDelay::new(Duration::from_millis(20)).max_retries(2))
+            .build()
+            .unwrap();
+
+        let started = std::time::Instant::now();
+        let result = client.send_email(&minimal_email()).await;
+        assert!(matches!(result, Err(ACSError::Network(_))));
+        assert!(started.elapsed() >= Duration::from_millis(40));
+    }
+
+    #[tokio::test]
+    async fn send_email_stops_retrying_when_max_elapsed_is_exceeded() {
+        use crate::adapters::gateways::retry::FixedDelay;
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = ACSClientBuilder::new()
+            .connection_string(FAKE_CONN)
+            .base_url_override(&server.uri())
+            .retry_policy(
+                FixedDelay::new(Duration::from_secs(5))
+                    .max_retries(10)
+                    .max_elapsed(Duration::from_secs(1)),
+            )
+            .build()
+            .unwrap();
+
+        let result = client.send_email(&minimal_email()).await;
+        assert!(matches!(
+            result,
+            Err(ACSError::RateLimitExceeded { retries: 0, .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn send_email_custom_retryable_statuses_are_respected() {
+        use crate::adapters::gateways::retry::FixedDelay;
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(502).set_body_json(json!({
+                "error": { "code": "BadGateway", "message": "not retried" }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = ACSClientBuilder::new()
+            .connection_string(FAKE_CONN)
+            .base_url_override(&server.uri())
+            .retry_policy(
+                FixedDelay::new(Duration::from_millis(10))
+                    .retryable_statuses(&[StatusCode::TOO_MANY_REQUESTS]),
+            )
+            .build()
+            .unwrap();
+
+        let result = client.send_email(&minimal_email()).await;
+        assert!(matches!(result, Err(ACSError::Api { .. })));
+    }
+
+    #[tokio::test]
+    async fn get_email_status_retries_transient_failure() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/emails/operations/poll-retry"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/emails/operations/poll-retry"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(json!({ "status": "Succeeded" })),
+            )
+            .mount(&server)
+            .await;
+
+        let client = fast_retry_client(&server, 1);
+        let status = client.get_email_status("poll-retry").await.unwrap();
+        assert_eq!(status, EmailSendStatusType::Succeeded);
+    }
+
+    // ── Retry-After variants ──────────────────────────────────────────────────
+
+    async fn mount_throttle_then_accept(server: &MockServer, throttle: ResponseTemplate) {
+        Mock::given(method("POST"))
+            .respond_with(throttle)
+            .up_to_n_times(1)
+            .mount(server)
+            .await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "throttled-ok" })))
+            .mount(server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn send_email_honours_retry_after_http_date() {
+        let server = MockServer::start().await;
+        let date = httpdate::fmt_http_date(SystemTime::now() + Duration::from_secs(1));
+        mount_throttle_then_accept(
+            &server,
+            ResponseTemplate::new(429).append_header("Retry-After", date.as_str()),
+        )
+        .await;
+
+        let client = ACSClientBuilder::new()
+            .connection_string(FAKE_CONN)
+            .base_url_override(&server.uri())
+            .max_retries(1)
+            .build()
+            .unwrap();
+        let result = client.send_email(&minimal_email()).await;
+        assert_eq!(result.unwrap(), "throttled-ok");
+    }
+
+    #[tokio::test]
+    async fn send_email_honours_retry_after_ms() {
+        let server = MockServer::start().await;
+        mount_throttle_then_accept(
+            &server,
+            ResponseTemplate::new(429).append_header("x-ms-retry-after-ms", "20"),
+        )
+        .await;
+
+        let client = ACSClientBuilder::new()
+            .connection_string(FAKE_CONN)
+            .base_url_override(&server.uri())
+            .max_retries(1)
+            .build()
+            .unwrap();
+        let started = std::time::Instant::now();
+        let result = client.send_email(&minimal_email()).await;
+        assert_eq!(result.unwrap(), "throttled-ok");
+        assert!(started.elapsed() < Duration::from_secs(1));
+    }
+
+    #[tokio::test]
+    async fn send_email_clamps_retry_after_to_policy_maximum() {
+        use crate::adapters::gateways::retry::FixedDelay;
+
+        let server = MockServer::start().await;
+        mount_throttle_then_accept(
+            &server,
+            ResponseTemplate::new(503).append_header("Retry-After", "3600"),
+        )
+        .await;
+
+        let client = ACSClientBuilder::new()
+            .connection_string(FAKE_CONN)
+            .base_url_override(&server.uri())
+            .retry_policy(
+                FixedDelay::new(Duration::from_secs(10))
+                    .max_retries(1)
+                    .max_retry_after(Duration::from_millis(10)),
+            )
+            .build()
+            .unwrap();
+        let result = client.send_email(&minimal_email()).await;
+        assert_eq!(result.unwrap(), "throttled-ok");
+    }
+
+    #[tokio::test]
+    async fn rate_limit_exceeded_reports_requested_retry_after() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(429).append_header("retry-after-ms", "1500"))
+            .mount(&server)
+            .await;
+
+        let client = client_for(&server);
+        let result = client.send_email(&minimal_email()).await;
+        match result {
+            Err(ACSError::RateLimitExceeded {
+                retries,
+                retry_after,
+            }) => {
+                assert_eq!(retries, 0);
+                assert_eq!(retry_after, Some(Duration::from_millis(1500)));
+            }
+            other => panic!("expected RateLimitExceeded, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn fail_fast_rate_limit_returns_quota_exhausted_without_calling_api() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "limited" })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = ACSClientBuilder::new()
+            .connection_string(FAKE_CONN)
+            .max_retries(0)
+            .base_url_override(&server.uri())
+            .rate_limit(1, 100)
+            .rate_limit_mode(RateLimitMode::FailFast)
+            .build()
+            .unwrap();
+        let clone = client.clone();
+
+        assert_eq!(
+            client.send_email(&minimal_email()).await.unwrap(),
+            "limited"
+        );
+        match clone.send_email(&minimal_email()).await {
+            Err(err @ ACSError::QuotaExhausted { .. }) => {
+                assert_eq!(
+                    err.kind(),
+                    crate::domain::entities::models::ACSErrorKind::QuotaExhausted
+                );
+            }
+            other => panic!("expected QuotaExhausted, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn client_without_quotas_has_no_rate_limiter() {
+        let server = MockServer::start().await;
+        assert!(client_for(&server).rate_limiter().is_none());
+
+        let limited = ACSClientBuilder::new()
+            .connection_string(FAKE_CONN)
+            .base_url_override(&server.uri())
+            .recipient_rate_limit(10, 100)
+            .build()
+            .unwrap();
+        assert_eq!(
+            limited.rate_limiter().map(|l| l.mode()),
+            Some(RateLimitMode::Wait)
+        );
+    }
+
+    #[tokio::test]
+    async fn send_emails_batch_grows_concurrency_while_accepted() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "ok" })))
+            .mount(&server)
+            .await;
+
+        let client = ACSClientBuilder::new()
+            .connection_string(FAKE_CONN)
+            .max_retries(0)
+            .base_url_override(&server.uri())
+            .batch_concurrency(1, 4)
+            .build()
+            .unwrap();
+        let emails: Vec<_> = (0..10).map(|_| minimal_email()).collect();
+        let results = client.send_emails_batch(&emails).await;
+        assert!(results.iter().all(|r| r.is_ok()));
+
+        let snapshot = client.clone().batch_concurrency();
+        assert_eq!(snapshot.limit, 4.0);
+        assert_eq!(snapshot.in_flight, 0);
+        assert_eq!(snapshot.decreases, 0);
+    }
+
+    #[tokio::test]
+    async fn send_emails_batch_shrinks_concurrency_on_throttling() {
+        use crate::adapters::gateways::retry::FixedDelay;
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "ok" })))
+            .up_to_n_times(7)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(429))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "ok" })))
+            .mount(&server)
+            .await;
+
+        let client = ACSClientBuilder::new()
+            .connection_string(FAKE_CONN)
+            .base_url_override(&server.uri())
+            .retry_policy(FixedDelay::new(Duration::from_millis(1)).max_retries(1))
+            .batch_concurrency(2, 64)
+            .build()
+            .unwrap();
+
+        let first = client
+            .send_emails_batch(&(0..7).map(|_| minimal_email()).collect::<Vec<_>>())
+            .await;
+        assert!(first.iter().all(|r| r.is_ok()));
+        assert_eq!(client.batch_concurrency().limit, 9.0);
+
+        // The retried 429 still counts as a throttling signal.
+        let second = client.send_emails_batch(&[minimal_email()]).await;
+        assert_eq!(second[0].as_deref().unwrap(), "ok");
+        let snapshot = client.batch_concurrency();
+        assert_eq!(snapshot.decreases, 1);
+        assert!(snapshot.limit < 9.0);
+        assert!(snapshot.limit >= 4.0);
+    }
+
+    // ── send_emails_batch_report ──────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn batch_report_groups_failures_and_resends_only_failed_slots() {
+        use crate::domain::entities::models::ACSErrorKind;
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(500).set_body_json(json!({
+                "error": { "code": "InternalError", "message": "boom" }
+            })))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "op" })))
+            .mount(&server)
+            .await;
+
+        let client = client_for(&server);
+        let mut report = client
+            .send_emails_batch_report((0..3).map(|_| minimal_email()))
+            .await;
+        assert_eq!(report.len(), 3);
+        assert_eq!(report.passes(), 1);
+        assert_eq!(report.accepted_count(), 2);
+        assert!(!report.is_complete());
+        let by_kind = report.failures_by_kind();
+        assert_eq!(by_kind.get(&ACSErrorKind::Api), Some(&vec![0]));
+
+        assert_eq!(report.retry_failed(&client).await, 1);
+        assert!(report.is_complete());
+        assert_eq!(report.passes(), 2);
+        assert_eq!(report.result(0).unwrap().as_deref().unwrap(), "op");
+
+        let requests = server.received_requests().await.unwrap();
+        assert_eq!(requests.len(), 4);
+        let key = report.idempotency_key(0).unwrap();
+        let slot0: Vec<_> = requests
+            .iter()
+            .filter(|r| r.headers.get("repeatability-request-id").unwrap() == key)
+            .collect();
+        assert_eq!(slot0.len(), 2);
+        assert_eq!(
+            slot0[0].headers.get("repeatability-first-sent"),
+            slot0[1].headers.get("repeatability-first-sent")
+        );
+    }
+
+    #[tokio::test]
+    async fn batch_report_retry_failed_is_noop_when_complete() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "op" })))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let client = client_for(&server);
+        let mut report = client
+            .send_emails_batch_report([minimal_email(), minimal_email()])
+            .await;
+        assert!(report.is_complete());
+        assert_eq!(report.failed_count(), 0);
+        assert_ne!(report.idempotency_key(0), report.idempotency_key(1));
+
+        assert_eq!(report.retry_failed(&client).await, 0);
+        assert_eq!(report.passes(), 1);
+    }
+
+    // ── send_stream ──────────────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn send_stream_yields_every_index() {
+        use futures::StreamExt;
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "streamed" })))
+            .expect(5)
+            .mount(&server)
+            .await;
+
+        let client = client_for(&server);
+        let emails = futures::stream::iter((0..5).map(|_| minimal_email()));
+        let mut results: Vec<_> = client.send_stream(emails).collect().await;
+        results.sort_by_key(|(index, _)| *index);
+
+        let indices: Vec<usize> = results.iter().map(|(index, _)| *index).collect();
+        assert_eq!(indices, vec![0, 1, 2, 3, 4]);
+        assert!(results
+            .iter()
+            .all(|(_, r)| r.as_deref().ok() == Some("streamed")));
+    }
+
+    #[tokio::test]
+    async fn send_stream_pulls_input_lazily() {
+        use futures::StreamExt;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "lazy" })))
+            .mount(&server)
+            .await;
+
+        let client = ACSClientBuilder::new()
+            .connection_string(FAKE_CONN)
+            .max_retries(0)
+            .base_url_override(&server.uri())
+            .batch_concurrency(1, 2)
+            .build()
+            .unwrap();
+        let pulled = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&pulled);
+        let emails = futures::stream::iter(0..1_000).map(move |_| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            minimal_email()
+        });
+
+        let results = client.send_stream(emails);
+        tokio::pin!(results);
+        assert!(results.next().await.unwrap().1.is_ok());
+        assert!(pulled.load(Ordering::SeqCst) <= 3);
+    }
+
+    #[tokio::test]
+    async fn send_stream_and_wait_yields_terminal_statuses() {
+        use futures::StreamExt;
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "op-final" })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(json!({ "status": "Succeeded" })),
+            )
+            .mount(&server)
+            .await;
+
+        let client = ACSClientBuilder::new()
+            .connection_string(FAKE_CONN)
+            .max_retries(0)
+            .base_url_override(&server.uri())
+            .poll_interval(Duration::from_millis(10))
+            .build()
+            .unwrap();
+        let emails = futures::stream::iter((0..3).map(|_| minimal_email()));
+        let results: Vec<_> = client
+            .send_stream_and_wait(emails, Duration::from_secs(5), 2)
+            .collect()
+            .await;
+
+        assert_eq!(results.len(), 3);
+        for (_, result) in results {
+            let outcome = result.unwrap();
+            assert_eq!(outcome.operation_id, "op-final");
+            assert_eq!(outcome.status, Some(EmailSendStatusType::Succeeded));
+        }
+    }
+
+    // ── BulkJob ──────────────────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn bulk_job_skips_completed_items_on_restart() {
+        use crate::adapters::gateways::bulk::BulkJob;
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "bulk-op" })))
+            .expect(3)
+            .mount(&server)
+            .await;
+
+        let client = client_for(&server);
+        let dir = tempfile::tempdir().unwrap();
+        let job = BulkJob::new("job-a", dir.path());
+
+        let first = job
+            .run(
+                &client,
+                futures::stream::iter((0..3).map(|_| minimal_email())),
+            )
+            .await
+            .unwrap();
+        assert_eq!((first.accepted, first.skipped, first.resent), (3, 0, 0));
+        assert!(first.failures.is_empty());
+
+        let second = job
+            .run(
+                &client,
+                futures::stream::iter((0..3).map(|_| minimal_email())),
+            )
+            .await
+            .unwrap();
+        assert_eq!((second.accepted, second.skipped), (0, 3));
+    }
+
+    #[tokio::test]
+    async fn bulk_job_resends_in_flight_items_with_original_key() {
+        use crate::adapters::gateways::bulk::BulkJob;
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "bulk-op" })))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let dir = tempfile::tempdir().unwrap();
+        let job = BulkJob::new("job-b", dir.path());
+        let checkpoint = [
+            r#"{"index":0,"idempotency_key":"key-0","first_sent":1700000000}"#,
+            r#"{"index":1,"idempotency_key":"key-1","first_sent":1700000000}"#,
+            r#"{"index":1,"idempotency_key":"key-1","first_sent":1700000000,"operation_id":"done"}"#,
+        ];
+        std::fs::write(job.checkpoint_path(), checkpoint.join("\n") + "\n").unwrap();
+
+        let client = client_for(&server);
+        let summary = job
+            .run(
+                &client,
+                futures::stream::iter((0..3).map(|_| minimal_email())),
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            (summary.accepted, summary.skipped, summary.resent),
+            (2, 1, 1)
+        );
+
+        let requests = server.received_requests().await.unwrap();
+        let resend = requests
+            .iter()
+            .find(|r| r.headers.get("repeatability-request-id").unwrap() == "key-0")
+            .expect("in-flight item resent with its original key");
+        let first_sent =
+            httpdate::fmt_http_date(std::time::UNIX_EPOCH + Duration::from_secs(1_700_000_000));
+        assert_eq!(
+            resend.headers.get("repeatability-first-sent").unwrap(),
+            first_sent.as_str()
+        );
+
+        let third = job
+            .run(
+                &client,
+                futures::stream::iter((0..3).map(|_| minimal_email())),
+            )
+            .await
+            .unwrap();
+        assert_eq!(third.skipped, 3);
+    }
+
+    #[tokio::test]
+    async fn bulk_job_resumes_after_torn_checkpoint_line() {
+        use crate::adapters::gateways::bulk::BulkJob;
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(wiremock::matchers::header(
+                "repeatability-request-id",
+                "key-0",
+            ))
+            .respond_with(ResponseTemplate::new(500))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "bulk-op" })))
+            .mount(&server)
+            .await;
+
+        let dir = tempfile::tempdir().unwrap();
+        let job = BulkJob::new("job-torn", dir.path());
+        // The crash cut the last line short, before its newline.
+        let checkpoint = concat!(
+            r#"{"index":0,"idempotency_key":"key-0","first_sent":1700000000}"#,
+            "\n",
+            r#"{"index":0,"idempotency_key":"key-0","first_sent":17"#,
+        );
+        std::fs::write(job.checkpoint_path(), checkpoint).unwrap();
+
+        let client = ACSClientBuilder::new()
+            .connection_string(FAKE_CONN)
+            .max_retries(0)
+            .base_url_override(&server.uri())
+            .build()
+            .unwrap();
+        let emails = || futures::stream::iter((0..2).map(|_| minimal_email()));
+        let first = job.run(&client, emails()).await.unwrap();
+        assert_eq!((first.accepted, first.resent), (1, 1));
+
+        // Every line written after the torn one is readable.
+        let content = std::fs::read_to_string(job.checkpoint_path()).unwrap();
+        assert!(content.ends_with('\n'));
+        let records: Vec<serde_json::Value> = content
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(records.len(), 5);
+
+        let second = job.run(&client, emails()).await.unwrap();
+        assert_eq!((second.skipped, second.resent), (1, 1));
+        let requests = server.received_requests().await.unwrap();
+        let item0_sends = requests
+            .iter()
+            .filter(|r| r.headers.get("repeatability-request-id").unwrap() == "key-0")
+            .count();
+        assert_eq!(item0_sends, 2);
+    }
+
+    #[tokio::test]
+    async fn bulk_job_rejects_job_id_with_path_separator() {
+        use crate::adapters::gateways::bulk::BulkJob;
+
+        let server = MockServer::start().await;
+        let client = client_for(&server);
+        let dir = tempfile::tempdir().unwrap();
+        for job_id in ["../escape", "nul\0byte"] {
+            let job = BulkJob::new(job_id, dir.path());
+            let result = job
+                .run(&client, futures::stream::iter(Vec::<SentEmail>::new()))
+                .await;
+            assert!(matches!(result, Err(ACSError::Checkpoint(_))));
+        }
+    }
+
+    #[tokio::test]
+    async fn bulk_job_reruns_clean_checkpoint_without_rewriting_it() {
+        use crate::adapters::gateways::bulk::BulkJob;
+
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "bulk-op" })))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let client = client_for(&server);
+        let dir = tempfile::tempdir().unwrap();
+        let job = BulkJob::new("job-clean", dir.path());
+        let emails = || futures::stream::iter((0..2).map(|_| minimal_email()));
+
+        let first = job.run(&client, emails()).await.unwrap();
+        assert_eq!(first.accepted, 2);
+        let checkpoint = std::fs::read(job.checkpoint_path()).unwrap();
+
+        // Every line is complete, so the second run neither cuts nor appends.
+        let second = job.run(&client, emails()).await.unwrap();
+        assert_eq!((second.accepted, second.skipped), (0, 2));
+        assert_eq!(std::fs::read(job.checkpoint_path()).unwrap(), checkpoint);
+    }
+
+    // ── get_email_operation ──────────────────────────────────────────────────
+
+    async fn mount_failed_operation(server: &MockServer, op_id: &str) {
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": op_id })))
+            .mount(server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .append_header("x-ms-request-id", "req-42")
+                    .set_body_json(json!({
+                        "id": op_id,
+                        "status": "Failed",
+                        "error": {
+                            "code": "InvalidRecipient",
+                            "message": "Recipient address rejected",
+                            "target": "recipients.to[0]",
+                            "details": [
+                                { "code": "MailboxNotFound", "message": "550 5.1.1" }
+                            ],
+                            "additionalInfo": [
+                                { "type": "SmtpResponse", "info": { "code": 550 } }
+                            ]
+                        }
+                    })),
+            )
+            .mount(server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn get_email_operation_returns_error_tree_and_headers() {
+        let server = MockServer::start().await;
+        mount_failed_operation(&server, "op-tree").await;
+
+        let client = client_for(&server);
+        let operation = client.get_email_operation("op-tree").await.unwrap();
+        assert_eq!(operation.id, "op-tree");
+        assert_eq!(operation.status, EmailSendStatusType::Failed);
+        assert!(operation.is_terminal());
+        assert_eq!(operation.headers.get("x-ms-request-id").unwrap(), "req-42");
+
+        let error = operation.error.as_ref().unwrap();
+        assert_eq!(error.code.as_deref(), Some("InvalidRecipient"));
+        assert_eq!(error.target.as_deref(), Some("recipients.to[0]"));
+        let details = error.details.as_ref().unwrap();
+        assert_eq!(details[0].code.as_deref(), Some("MailboxNotFound"));
+        let info = &error.additional_info.as_ref().unwrap()[0];
+        assert_eq!(info.info_type.as_deref(), Some("SmtpResponse"));
+        assert_eq!(info.info.as_ref().unwrap()["code"], 550);
+
+        match operation.into_status() {
+            Err(err @ ACSError::DeliveryFailed { .. }) => {
+                assert!(err.to_string().contains("InvalidRecipient"));
+            }
+            other => panic!("expected DeliveryFailed, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn send_email_stream_yields_delivery_failure() {
+        use futures::StreamExt;
+
+        let server = MockServer::start().await;
+        mount_failed_operation(&server, "op-stream-fail").await;
+
+        let client = ACSClientBuilder::new()
+            .connection_string(FAKE_CONN)
+            .max_retries(0)
+            .base_url_override(&server.uri())
+            .poll_interval(Duration::from_millis(10))
+            .build()
+            .unwrap();
+        let (_, stream) = client.send_email_stream(&minimal_email()).await.unwrap();
+        let items: Vec<_> = stream.collect().await;
+        assert_eq!(items.len(), 1);
+        match &items[0] {
+            Err(ACSError::DeliveryFailed { error: Some(e), .. }) => {
+                assert_eq!(e.code.as_deref(), Some("InvalidRecipient"));
+            }
+            other => panic!("expected DeliveryFailed, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    #[allow(deprecated)]
+    async fn send_email_with_callback_reports_delivery_failure() {
+        let server = MockServer::start().await;
+        mount_failed_operation(&server, "op-cb-fail").await;
+
+        let client = ACSClientBuilder::new()
+            .connection_string(FAKE_CONN)
+            .max_retries(0)
+            .base_url_override(&server.uri())
+            .poll_interval(Duration::from_millis(10))
+            .build()
+            .unwrap();
+        let seen = Arc::new(std::sync::Mutex::new(None));
+        let sink = Arc::clone(&seen);
+        let (_, done) = client
+            .send_email_with_callback(&minimal_email(), move |_, status, error| {
+                *sink.lock().unwrap() = Some((*status, error.map(|e| e.kind())));
+            })
+            .await
+            .unwrap();
+        done.await.unwrap();
+
+        assert_eq!(
+            *seen.lock().unwrap(),
+            Some((
+                EmailSendStatusType::Failed,
+                Some(crate::domain::entities::models::ACSErrorKind::DeliveryFailed)
+            ))
+        );
+    }
+
+    // ── wait_for_operation / operation_stream ─────────────────────────────────
+
+    fn polling_client(server: &MockServer) -> ACSClient {
+        ACSClientBuilder::new()
+            .connection_string(FAKE_CONN)
+            .max_retries(0)
+            .base_url_override(&server.uri())
+            .poll_interval(Duration::from_millis(10))
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn wait_for_operation_polls_existing_id_without_sending() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(202))
+            .expect(0)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/emails/operations/existing-op"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(json!({ "status": "Succeeded" })),
+            )
+            .mount(&server)
+            .await;
+
+        let client = polling_client(&server);
+        let status = client
+            .wait_for_operation("existing-op", Duration::from_secs(5))
+            .await;
+        assert!(matches!(status, Ok(EmailSendStatusType::Succeeded)));
+    }
+
+    #[tokio::test]
+    async fn wait_for_operation_times_out_and_can_be_cancelled() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "status": "Running" })))
+            .mount(&server)
+            .await;
+
+        let client = polling_client(&server);
+        let timed_out = client
+            .wait_for_operation("slow-op", Duration::from_millis(50))
+            .await;
+        assert!(matches!(timed_out, Err(ACSError::Timeout)));
+
+        let token = CancellationToken::new();
+        token.cancel();
+        let cancelled = client
+            .wait_for_operation_cancellable("slow-op", Duration::from_secs(5), token)
+            .await;
+        assert!(matches!(cancelled, Err(ACSError::Canceled)));
+    }
+
+    #[tokio::test]
+    async fn operation_stream_yields_until_terminal() {
+        use futures::StreamExt;
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "status": "Running" })))
+            .up_to_n_times(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(json!({ "status": "Succeeded" })),
+            )
+            .mount(&server)
+            .await;
+
+        let client = polling_client(&server);
+        let statuses: Vec<_> = client
+            .operation_stream("tracked-op")
+            .map(|item| item.unwrap())
+            .collect()
+            .await;
+        assert_eq!(
+            statuses,
+            vec![
+                EmailSendStatusType::Running,
+                EmailSendStatusType::Running,
+                EmailSendStatusType::Succeeded
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn operation_stream_cancellable_stops_on_cancel() {
+        use futures::StreamExt;
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "status": "Running" })))
+            .mount(&server)
+            .await;
+
+        let client = polling_client(&server);
+        let token = CancellationToken::new();
+        let stream = client.operation_stream_cancellable("tracked-op", token.clone());
+        tokio::pin!(stream);
+        assert!(matches!(
+            stream.next().await,
+            Some(Ok(EmailSendStatusType::Running))
+        ));
+        token.cancel();
+        assert!(stream.next().await.is_none());
+    }
+
+    // ── OperationTracker ──────────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn tracker_shares_one_get_between_subscribers_and_evicts_terminal() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(json!({ "status": "Succeeded" })),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = polling_client(&server);
+        let (first, second) = tokio::join!(
+            client.wait_for_operation("shared-op", Duration::from_secs(5)),
+            client.wait_for_operation("shared-op", Duration::from_secs(5)),
+        );
+        assert_eq!(first.unwrap(), EmailSendStatusType::Succeeded);
+        assert_eq!(second.unwrap(), EmailSendStatusType::Succeeded);
+        assert_eq!(client.operation_tracker().pending(), 0);
+    }
+
+    #[tokio::test]
+    async fn tracker_evicts_operation_when_subscription_is_dropped() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "status": "Running" })))
+            .mount(&server)
+            .await;
+
+        let client = polling_client(&server);
+        let subscription = client.track_operation("abandoned-op");
+        assert_eq!(subscription.operation_id(), "abandoned-op");
+        assert!(client.operation_tracker().is_tracking("abandoned-op"));
+
+        drop(subscription);
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert!(!client.operation_tracker().is_tracking("abandoned-op"));
+        assert_eq!(client.operation_tracker().pending(), 0);
+    }
+
+    #[tokio::test]
+    async fn tracker_delivers_poll_errors_and_evicts() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+                "error": { "code": "NotFound", "message": "no such operation" }
+            })))
+            .mount(&server)
+            .await;
+
+        let client = polling_client(&server);
+        let mut subscription = client.track_operation("missing-op");
+        assert!(matches!(
+            subscription.recv().await,
+            Some(Err(ACSError::Api { .. }))
+        ));
+        assert!(subscription.recv().await.is_none());
+        assert_eq!(client.operation_tracker().pending(), 0);
+    }
+
+    #[tokio::test]
+    async fn tracker_polls_other_operations_while_one_get_is_retrying() {
+        use crate::adapters::gateways::retry::FixedDelay;
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/emails/operations/stalled-op"))
+            .respond_with(ResponseTemplate::new(503).insert_header("Retry-After", "30"))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/emails/operations/quick-op"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "status": "Running" })))
+            .up_to_n_times(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/emails/operations/quick-op"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(json!({ "status": "Succeeded" })),
+            )
+            .mount(&server)
+            .await;
+
+        let client = ACSClientBuilder::new()
+            .connection_string(FAKE_CONN)
+            .base_url_override(&server.uri())
+            .retry_policy(FixedDelay::new(Duration::from_millis(10)).max_retries(3))
+            .poll_interval(Duration::from_millis(10))
+            .build()
+            .unwrap();
+        let _stalled = client.track_operation("stalled-op");
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        let quick = client
+            .wait_for_operation("quick-op", Duration::from_secs(2))
+            .await;
+        assert_eq!(quick.unwrap(), EmailSendStatusType::Succeeded);
+        assert!(client.operation_tracker().is_tracking("stalled-op"));
+        let stalled_gets = server
+            .received_requests()
+            .await
+            .unwrap()
+            .iter()
+            .filter(|r| r.url.path() == "/emails/operations/stalled-op")
+            .count();
+        assert_eq!(
+            stalled_gets, 1,
+            "the stalled operation must not be polled twice"
+        );
+    }
+
+    #[tokio::test]
+    async fn status_poll_rate_spaces_out_status_gets() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(json!({ "status": "Succeeded" })),
+            )
+            .expect(3)
+            .mount(&server)
+            .await;
+
+        let client = ACSClientBuilder::new()
+            .connection_string(FAKE_CONN)
+            .max_retries(0)
+            .base_url_override(&server.uri())
+            .poll_interval(Duration::from_millis(10))
+            .status_poll_rate(20)
+            .build()
+            .unwrap();
+        let started = std::time::Instant::now();
+        let (a, b, c) = tokio::join!(
+            client.wait_for_operation("op-a", Duration::from_secs(5)),
+            client.wait_for_operation("op-b", Duration::from_secs(5)),
+            client.wait_for_operation("op-c", Duration::from_secs(5)),
+        );
+        assert!(a.is_ok() && b.is_ok() && c.is_ok());
+        // 20 GETs per second: the third GET waits two 50 ms ticks.
+        assert!(started.elapsed() >= Duration::from_millis(100));
+    }
+
+    // ── PollStrategy ──────────────────────────────────────────────────────────
+
+    #[tokio::test]
+    async fn poll_strategy_spaces_out_polls() {
+        use crate::adapters::gateways::poll::ExponentialPoll;
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "status": "Running" })))
+            .up_to_n_times(3)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(json!({ "status": "Succeeded" })),
+            )
+            .mount(&server)
+            .await;
+
+        let client = ACSClientBuilder::new()
+            .connection_string(FAKE_CONN)
+            .max_retries(0)
+            .base_url_override(&server.uri())
+            .poll_strategy(ExponentialPoll::new(
+                Duration::from_millis(20),
+                Duration::from_secs(1),
+            ))
+            .build()
+            .unwrap();
+        let started = std::time::Instant::now();
+        let status = client
+            .wait_for_operation("backoff-op", Duration::from_secs(5))
+            .await
+            .unwrap();
+        assert_eq!(status, EmailSendStatusType::Succeeded);
+        // 20 + 40 + 80 + 160 ms before the four polls.
+        assert!(started.elapsed() >= Duration::from_millis(300));
+    }
+
+    #[tokio::test]
+    async fn status_retry_after_overrides_poll_strategy() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("retry-after-ms", "250")
+                    .set_body_json(json!({ "status": "Running" })),
+            )
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(json!({ "status": "Succeeded" })),
+            )
+            .mount(&server)
+            .await;
+
+        let client = polling_client(&server);
+        let started = std::time::Instant::now();
+        let status = client
+            .wait_for_operation("hinted-op", Duration::from_secs(5))
+            .await
+            .unwrap();
+        assert_eq!(status, EmailSendStatusType::Succeeded);
+        assert!(started.elapsed() >= Duration::from_millis(250));
+    }
+
+    #[test]
+    fn builder_poll_strategy_replaces_poll_interval() {
+        use crate::adapters::gateways::poll::FibonacciPoll;
+
+        let client = ACSClientBuilder::new()
+            .connection_string(FAKE_CONN)
+            .poll_interval(Duration::from_secs(1))
+            .poll_strategy(FibonacciPoll::new(
+                Duration::from_millis(100),
+                Duration::from_secs(2),
+            ))
+            .build()
+            .unwrap();
+        assert_eq!(client.poll_strategy().delay(0), Duration::from_millis(100));
+        assert_eq!(client.poll_strategy().delay(4), Duration::from_millis(500));
     }
 
+    // ── EmailPoller ───────────────────────────────────────────────────────────
+
     #[tokio::test]
-    async fn send_email_with_callback_cancellable_invokes_callback_on_success() {
+    async fn resumed_poller_continues_with_token_state() {
         let server = MockServer::start().await;
-        Mock::given(method("POST"))
-            .and(path("/emails:send"))
-            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "cbs-msg" })))
+        Mock::given(method("GET"))
+            .and(path("/emails/operations/lro-op"))
+            .and(query_param("api-version", "2025-09-01"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "status": "Running" })))
+            .up_to_n_times(1)
             .mount(&server)
             .await;
         Mock::given(method("GET"))
-            .and(path("/emails/operations/cbs-msg"))
+            .and(path("/emails/operations/lro-op"))
+            .and(query_param("api-version", "2025-09-01"))
             .respond_with(
-                ResponseTemplate::new(200)
-                    .set_body_json(json!({ "id": "cbs-msg", "status": "Succeeded" })),
+                ResponseTemplate::new(200).set_body_json(json!({ "status": "Succeeded" })),
             )
             .mount(&server)
             .await;
 
-        let token = CancellationToken::new(); // never cancelled
-        let statuses: std::sync::Arc<std::sync::Mutex<Vec<String>>> =
-            std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
-        let s = statuses.clone();
-
-        let client = client_for(&server);
-        let (_, done_rx) = client
-            .send_email_with_callback_cancellable(&minimal_email(), token, move |_, status, _| {
-                s.lock().unwrap().push(status.to_string());
-            })
-            .await
+        let sender = ACSClientBuilder::new()
+            .connection_string(FAKE_CONN)
+            .max_retries(0)
+            .base_url_override(&server.uri())
+            .api_version(ACSApiVersion::V20250901)
+            .build()
             .unwrap();
-        let _ = done_rx.await;
-        let calls = statuses.lock().unwrap();
-        assert!(calls.iter().any(|s| s == "Succeeded"));
+        let mut poller = sender.poller("lro-op");
+        assert_eq!(poller.poll().await.unwrap(), EmailSendStatusType::Running);
+        let token = poller.to_resume_token();
+
+        // A client on the default API version resumes with the token's version.
+        let mut resumed = polling_client(&server).resume_poller(&token).unwrap();
+        assert_eq!(resumed.attempts(), 1);
+        assert_eq!(resumed.first_poll(), poller.first_poll());
+        assert_eq!(resumed.last_status(), Some(EmailSendStatusType::Running));
+        let status = resumed.wait(Duration::from_secs(5)).await.unwrap();
+        assert_eq!(status, EmailSendStatusType::Succeeded);
+        assert_eq!(resumed.attempts(), 2);
+        assert!(resumed.is_done());
     }
 
     #[tokio::test]
-    async fn send_email_with_callback_cancellable_returns_error_when_send_fails() {
+    async fn finished_poller_wait_returns_without_polling() {
         let server = MockServer::start().await;
-        Mock::given(method("POST"))
-            .and(path("/emails:send"))
-            .respond_with(ResponseTemplate::new(500).set_body_json(json!({
-                "error": { "code": "InternalError", "message": "fail" }
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "status": "Failed",
+                "error": { "code": "RecipientBlocked", "message": "blocked" }
             })))
+            .expect(1)
             .mount(&server)
             .await;
 
-        let token = CancellationToken::new();
-        let client = client_for(&server);
-        let result = client
-            .send_email_with_callback_cancellable(&minimal_email(), token, |_, _, _| {})
-            .await;
-        assert!(matches!(result, Err(ACSError::Api { .. })));
+        let client = polling_client(&server);
+        let mut poller = client.poller("failed-op");
+        assert_eq!(poller.poll().await.unwrap(), EmailSendStatusType::Failed);
+
+        let mut resumed = client.resume_poller(&poller.to_resume_token()).unwrap();
+        match resumed.wait(Duration::from_secs(1)).await {
+            Err(ACSError::DeliveryFailed {
+                operation_id,
+                error: Some(error),
+                ..
+            }) => {
+                assert_eq!(operation_id, "failed-op");
+                assert_eq!(error.code.as_deref(), Some("RecipientBlocked"));
+            }
+            other => panic!("expected DeliveryFailed with details, got {other:?}"),
+        }
     }
 
-    // ── #11 batch: mixed success / failure ───────────────────────────────────
-
     #[tokio::test]
-    async fn send_emails_batch_mixed_success_and_failure() {
+    async fn poller_publishes_events_and_counts_only_successful_polls() {
+        use crate::adapters::gateways::events::EmailEventKind;
+
         let server = MockServer::start().await;
-        // First request to arrive → 500 (exhausted after 1 hit)
-        Mock::given(method("POST"))
-            .and(path("/emails:send"))
-            .respond_with(ResponseTemplate::new(500).set_body_json(json!({
-                "error": { "code": "InternalError", "message": "boom" }
-            })))
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(503))
             .up_to_n_times(1)
             .mount(&server)
             .await;
-        // Every subsequent request → 202
-        Mock::given(method("POST"))
-            .and(path("/emails:send"))
-            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "ok-id" })))
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "status": "Running" })))
+            .up_to_n_times(1)
             .mount(&server)
             .await;
-
-        let client = client_for(&server);
-        let results = client
-            .send_emails_batch(&[minimal_email(), minimal_email()])
+        Mock::given(method("GET"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(json!({ "status": "Succeeded" })),
+            )
+            .mount(&server)
             .await;
 
-        assert_eq!(results.len(), 2);
-        let ok_count = results.iter().filter(|r| r.is_ok()).count();
-        let err_count = results.iter().filter(|r| r.is_err()).count();
-        assert_eq!(ok_count, 1, "exactly one send should succeed");
-        assert_eq!(err_count, 1, "exactly one send should fail");
+        let client = polling_client(&server);
+        let mut events = client.subscribe();
+        let mut poller = client.poller("evt-poller-op");
+        assert!(poller.poll().await.is_err());
+        assert_eq!(poller.attempts(), 0);
+        assert_eq!(poller.first_poll(), None);
+
+        let status = poller.wait(Duration::from_secs(5)).await.unwrap();
+        assert_eq!(status, EmailSendStatusType::Succeeded);
+        assert_eq!(poller.attempts(), 2);
+
+        let received: Vec<_> = std::iter::from_fn(|| events.try_recv().ok())
+            .map(|event| event.kind)
+            .collect();
+        assert_eq!(received.len(), 3, "{received:?}");
+        assert!(matches!(received[0], EmailEventKind::Error { .. }));
+        assert!(matches!(
+            received[1],
+            EmailEventKind::StatusChanged {
+                status: EmailSendStatusType::Running
+            }
+        ));
+        assert!(matches!(
+            received[2],
+            EmailEventKind::Terminal {
+                status: EmailSendStatusType::Succeeded,
+                error: None
+            }
+        ));
     }
 
     #[tokio::test]
-    async fn send_emails_batch_propagates_max_retries_config() {
+    async fn poller_wait_timeout_keeps_state() {
         let server = MockServer::start().await;
-        // Always 429 — exhausts retries
-        Mock::given(method("POST"))
-            .and(path("/emails:send"))
-            .respond_with(ResponseTemplate::new(429).set_body_json(json!({
-                "error": { "code": "TooManyRequests", "message": "slow down" }
-            })))
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "status": "Running" })))
             .mount(&server)
             .await;
 
-        let client = ACSClientBuilder::new()
-            .connection_string(FAKE_CONN)
-            .max_retries(2)
-            .base_url_override(&server.uri())
-            .build()
-            .unwrap();
-
-        let results = client.send_emails_batch(&[minimal_email()]).await;
-        assert_eq!(results.len(), 1);
+        let client = polling_client(&server);
+        let mut poller = client.poller("slow-op");
         assert!(matches!(
-            results[0],
-            Err(ACSError::RateLimitExceeded { retries: 2 })
+            poller.wait(Duration::from_millis(100)).await,
+            Err(ACSError::Timeout)
         ));
+        assert!(poller.attempts() >= 1);
+        assert!(poller.first_poll().is_some());
+        assert_eq!(poller.last_status(), Some(EmailSendStatusType::Running));
     }
 
+    // ── async callbacks ───────────────────────────────────────────────────────
+
     #[tokio::test]
-    async fn send_emails_batch_returns_error_on_malformed_response() {
+    async fn async_callback_is_awaited_per_update_and_handle_returns_final_status() {
         let server = MockServer::start().await;
         Mock::given(method("POST"))
-            .and(path("/emails:send"))
-            .respond_with(ResponseTemplate::new(202).set_body_string("not json"))
+            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "async-op" })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "status": "Running" })))
+            .up_to_n_times(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(json!({ "status": "Succeeded" })),
+            )
             .mount(&server)
             .await;
 
-        let client = client_for(&server);
-        let results = client.send_emails_batch(&[minimal_email()]).await;
-        assert_eq!(results.len(), 1);
-        assert!(matches!(results[0], Err(ACSError::Deserialization(_))));
+        let client = polling_client(&server);
+        let seen = Arc::new(tokio::sync::Mutex::new(Vec::new()));
+        let sink = Arc::clone(&seen);
+        let handle = client
+            .send_email_with_async_callback(&minimal_email(), move |id, status, error| {
+                let sink = Arc::clone(&sink);
+                async move {
+                    tokio::task::yield_now().await;
+                    sink.lock().await.push((id, status, error.is_some()));
+                }
+            })
+            .await
+            .unwrap();
+        assert_eq!(handle.operation_id(), "async-op");
+        assert_eq!(handle.await.unwrap(), EmailSendStatusType::Succeeded);
+        assert_eq!(
+            *seen.lock().await,
+            vec![
+                ("async-op".to_string(), EmailSendStatusType::Running, false),
+                ("async-op".to_string(), EmailSendStatusType::Running, false),
+                (
+                    "async-op".to_string(),
+                    EmailSendStatusType::Succeeded,
+                    false
+                ),
+            ]
+        );
     }
 
-    // ── #12 stream cancellable: missing paths ─────────────────────────────────
-
     #[tokio::test]
-    async fn send_email_stream_cancellable_stops_on_status_error() {
+    async fn fnmut_callback_and_failed_delivery() {
         let server = MockServer::start().await;
-        Mock::given(method("POST"))
-            .and(path("/emails:send"))
-            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "cse-op" })))
-            .mount(&server)
-            .await;
         Mock::given(method("GET"))
-            .and(path("/emails/operations/cse-op"))
-            .respond_with(ResponseTemplate::new(404).set_body_json(json!({
-                "error": { "code": "NotFound", "message": "operation not found" }
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "status": "Failed",
+                "error": { "code": "Bounced", "message": "mailbox full" }
             })))
             .mount(&server)
             .await;
 
-        let token = CancellationToken::new(); // never cancelled
-        let client = client_for(&server);
-        let (_, stream) = client
-            .send_email_stream_cancellable(&minimal_email(), token)
-            .await
-            .unwrap();
+        let client = polling_client(&server);
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        let mut calls = 0;
+        let handle = client.operation_callback("failed-op", move |_, status, error| {
+            calls += 1;
+            let _ = tx.send((calls, status, error));
+            std::future::ready(())
+        });
+        assert!(matches!(
+            handle.join().await,
+            Err(ACSError::DeliveryFailed { ref operation_id, .. }) if operation_id == "failed-op"
+        ));
+        let (calls, status, error) = rx.recv().await.unwrap();
+        assert_eq!((calls, status), (1, EmailSendStatusType::Failed));
+        assert!(matches!(error, Some(ACSError::DeliveryFailed { .. })));
+    }
 
-        tokio::pin!(stream);
-        use futures::StreamExt;
-        let first = stream
-            .next()
-            .await
-            .expect("stream must yield an error item");
-        assert!(matches!(first, Err(ACSError::Api { .. })));
-        // stream must terminate after an error — next call returns None
-        assert!(stream.next().await.is_none());
+    #[tokio::test]
+    async fn aborted_callback_handle_resolves_to_canceled() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "status": "Running" })))
+            .mount(&server)
+            .await;
+
+        let client = polling_client(&server);
+        let handle = client.operation_callback("endless-op", |_, _, _| std::future::ready(()));
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        handle.abort();
+        assert!(matches!(handle.await, Err(ACSError::Canceled)));
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(!client.operation_tracker().is_tracking("endless-op"));
     }
 
+    // ── event bus ─────────────────────────────────────────────────────────────
+
     #[tokio::test]
-    async fn send_email_stream_cancellable_running_then_succeeded() {
+    async fn event_bus_reports_submission_changes_and_terminal_status() {
+        use crate::adapters::gateways::events::EmailEventKind;
+
         let server = MockServer::start().await;
         Mock::given(method("POST"))
-            .and(path("/emails:send"))
-            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "rs-op" })))
+            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "evt-op" })))
             .mount(&server)
             .await;
-        // First poll → Running
         Mock::given(method("GET"))
-            .and(path("/emails/operations/rs-op"))
-            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
-                "id": "rs-op", "status": "Running"
-            })))
-            .up_to_n_times(1)
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "status": "Running" })))
+            .up_to_n_times(2)
             .mount(&server)
             .await;
-        // Second poll → Succeeded
         Mock::given(method("GET"))
-            .and(path("/emails/operations/rs-op"))
-            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
-                "id": "rs-op", "status": "Succeeded"
-            })))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(json!({ "status": "Succeeded" })),
+            )
             .mount(&server)
             .await;
 
-        let token = CancellationToken::new();
-        let client = client_for(&server);
-        let (_, stream) = client
-            .send_email_stream_cancellable(&minimal_email(), token)
+        let client = polling_client(&server);
+        let mut events = client.clone().subscribe();
+        let options = SendOptions::new()
+            .correlation_id("order-7")
+            .wait(WaitMode::Terminal(Duration::from_secs(5)));
+        client
+            .send_email_with(&minimal_email(), &options)
             .await
             .unwrap();
 
-        tokio::pin!(stream);
-        use futures::StreamExt;
-        let mut statuses = Vec::new();
-        while let Some(item) = stream.next().await {
-            statuses.push(item.unwrap());
+        let mut received = Vec::new();
+        while let Ok(event) = events.try_recv() {
+            assert_eq!(event.operation_id, "evt-op");
+            assert_eq!(event.correlation_id.as_deref(), Some("order-7"));
+            assert!(event.submitted_at.is_some());
+            received.push(event.kind);
         }
-        assert_eq!(
-            statuses,
-            vec![EmailSendStatusType::Running, EmailSendStatusType::Succeeded]
-        );
+        assert_eq!(received.len(), 3, "{received:?}");
+        assert!(matches!(received[0], EmailEventKind::Submitted));
+        assert!(matches!(
+            received[1],
+            EmailEventKind::StatusChanged {
+                status: EmailSendStatusType::Running
+            }
+        ));
+        assert!(matches!(
+            received[2],
+            EmailEventKind::Terminal {
+                status: EmailSendStatusType::Succeeded,
+                error: None
+            }
+        ));
     }
 
-    // ── #12 callback cancellable: missing paths ───────────────────────────────
-
     #[tokio::test]
-    async fn send_email_with_callback_cancellable_reports_error_on_status_failure() {
+    async fn event_bus_reports_poll_errors_for_tracked_operations() {
+        use crate::adapters::gateways::events::EmailEventKind;
+
         let server = MockServer::start().await;
-        Mock::given(method("POST"))
-            .and(path("/emails:send"))
-            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "cpe-msg" })))
-            .mount(&server)
-            .await;
         Mock::given(method("GET"))
-            .and(path("/emails/operations/cpe-msg"))
             .respond_with(ResponseTemplate::new(404).set_body_json(json!({
-                "error": { "code": "NotFound", "message": "operation not found" }
+                "error": { "code": "NotFound", "message": "no such operation" }
             })))
             .mount(&server)
             .await;
 
-        let token = CancellationToken::new();
-        let had_error: std::sync::Arc<std::sync::atomic::AtomicBool> =
-            std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
-        let flag = had_error.clone();
-
-        let client = client_for(&server);
-        let (_, done_rx) = client
-            .send_email_with_callback_cancellable(&minimal_email(), token, move |_, _, err| {
-                if err.is_some() {
-                    flag.store(true, std::sync::atomic::Ordering::SeqCst);
-                }
-            })
-            .await
-            .unwrap();
-
-        let _ = done_rx.await;
-        assert!(
-            had_error.load(std::sync::atomic::Ordering::SeqCst),
-            "callback should have received a non-None error"
-        );
+        let client = polling_client(&server);
+        let mut events = client.subscribe();
+        let _ = client
+            .wait_for_operation("gone-op", Duration::from_secs(5))
+            .await;
+        let event = events.try_recv().unwrap();
+        assert_eq!(event.operation_id, "gone-op");
+        assert_eq!(event.submitted_at, None);
+        assert!(matches!(
+            event.kind,
+            EmailEventKind::Error {
+                error: ACSError::Api { .. }
+            }
+        ));
     }
 
+    // ── graceful shutdown ─────────────────────────────────────────────────────
+
     #[tokio::test]
-    async fn send_email_with_callback_cancellable_running_then_succeeded() {
+    async fn shutdown_waits_for_in_flight_operations_within_grace() {
         let server = MockServer::start().await;
-        Mock::given(method("POST"))
-            .and(path("/emails:send"))
-            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "crts-msg" })))
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "status": "Running" })))
+            .up_to_n_times(2)
             .mount(&server)
             .await;
         Mock::given(method("GET"))
-            .and(path("/emails/operations/crts-msg"))
-            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
-                "id": "crts-msg", "status": "Running"
-            })))
-            .up_to_n_times(1)
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(json!({ "status": "Succeeded" })),
+            )
             .mount(&server)
             .await;
+
+        let client = polling_client(&server);
+        let handle = client.operation_callback("draining-op", |_, _, _| async {});
+        assert!(client.operation_tracker().background_tasks() >= 1);
+
+        let unresolved = client.shutdown(Duration::from_secs(5)).await;
+        assert!(unresolved.is_empty(), "{unresolved:?}");
+        assert_eq!(handle.await.unwrap(), EmailSendStatusType::Succeeded);
+        assert_eq!(client.operation_tracker().background_tasks(), 0);
+    }
+
+    #[tokio::test]
+    async fn shutdown_cancels_operations_past_grace_and_returns_their_ids() {
+        use crate::adapters::gateways::events::EmailEventKind;
+
+        let server = MockServer::start().await;
         Mock::given(method("GET"))
-            .and(path("/emails/operations/crts-msg"))
-            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
-                "id": "crts-msg", "status": "Succeeded"
-            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "status": "Running" })))
             .mount(&server)
             .await;
 
-        let token = CancellationToken::new();
-        let statuses: std::sync::Arc<std::sync::Mutex<Vec<String>>> =
-            std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
-        let s = statuses.clone();
-
-        let client = client_for(&server);
-        let (_, done_rx) = client
-            .send_email_with_callback_cancellable(&minimal_email(), token, move |_, status, _| {
-                s.lock().unwrap().push(status.to_string());
+        let client = polling_client(&server);
+        let mut events = client.subscribe();
+        let handle = client.operation_callback("stuck-b", |_, _, _| async {});
+        let waiter = {
+            let client = client.clone();
+            tokio::spawn(async move {
+                client
+                    .wait_for_operation("stuck-a", Duration::from_secs(30))
+                    .await
             })
-            .await
-            .unwrap();
+        };
+        tokio::time::sleep(Duration::from_millis(50)).await;
 
-        let _ = done_rx.await;
-        let calls = statuses.lock().unwrap();
-        assert!(calls.contains(&"Running".to_string()));
-        assert!(calls.contains(&"Succeeded".to_string()));
-        assert_eq!(*calls.last().unwrap(), "Succeeded");
+        let unresolved = client.shutdown(Duration::from_millis(50)).await;
+        assert_eq!(
+            unresolved,
+            vec!["stuck-a".to_string(), "stuck-b".to_string()]
+        );
+        assert!(matches!(handle.await, Err(ACSError::Canceled)));
+        assert!(matches!(waiter.await.unwrap(), Err(ACSError::Canceled)));
+        assert_eq!(client.operation_tracker().pending(), 0);
+
+        let mut canceled = 0;
+        while let Ok(event) = events.try_recv() {
+            if matches!(
+                event.kind,
+                EmailEventKind::Error {
+                    error: ACSError::Canceled
+                }
+            ) {
+                canceled += 1;
+            }
+        }
+        assert_eq!(canceled, 2);
     }
 
-    // ── #13 clone: get_email_status and different auth path ───────────────────
-
     #[tokio::test]
-    async fn cloned_client_get_email_status_works() {
+    async fn shutdown_rejects_new_operations_on_every_clone() {
         let server = MockServer::start().await;
         Mock::given(method("GET"))
-            .and(path("/emails/operations/clone-op"))
-            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
-                "id": "clone-op", "status": "Succeeded"
-            })))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(json!({ "status": "Succeeded" })),
+            )
+            .expect(0)
             .mount(&server)
             .await;
 
-        let original = client_for(&server);
-        let cloned = original.clone();
-        let result = cloned.get_email_status("clone-op").await;
-        assert!(matches!(result, Ok(EmailSendStatusType::Succeeded)));
+        let client = polling_client(&server);
+        let clone = client.clone();
+        assert!(client.shutdown(Duration::from_secs(1)).await.is_empty());
+        assert!(clone.operation_tracker().is_closed());
+        assert!(matches!(
+            clone
+                .wait_for_operation("late-op", Duration::from_secs(1))
+                .await,
+            Err(ACSError::Canceled)
+        ));
+        assert!(!clone.operation_tracker().is_tracking("late-op"));
     }
 
+    // ── delivery SLA watchdog ─────────────────────────────────────────────────
+
     #[tokio::test]
-    async fn clone_preserves_api_version_in_requests() {
+    async fn delivery_sla_reports_overdue_operations_once_per_class() {
+        use crate::adapters::gateways::events::EmailEventKind;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
         let server = MockServer::start().await;
         Mock::given(method("POST"))
-            .and(path("/emails:send"))
-            .and(query_param("api-version", "2025-09-01"))
-            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "ver-id" })))
+            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "slow-op" })))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "bulk-op" })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "status": "Running" })))
             .mount(&server)
             .await;
 
-        let original = ACSClientBuilder::new()
+        let fired = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&fired);
+        let client = ACSClientBuilder::new()
             .connection_string(FAKE_CONN)
-            .api_version(ACSApiVersion::V20250901)
             .max_retries(0)
             .base_url_override(&server.uri())
+            .poll_interval(Duration::from_millis(10))
+            .delivery_sla(
+                DeliverySla::new(Duration::from_millis(50))
+                    .class("newsletter", Duration::from_secs(600))
+                    .on_overdue(move |operation| {
+                        assert_eq!(operation.operation_id, "slow-op");
+                        counter.fetch_add(1, Ordering::SeqCst);
+                    }),
+            )
             .build()
             .unwrap();
-        let cloned = original.clone();
+        let mut events = client.subscribe();
 
-        let result = cloned.send_email(&minimal_email()).await;
-        assert_eq!(result.unwrap(), "ver-id");
+        let options = SendOptions::new()
+            .correlation_id("order-9")
+            .email_class("receipt");
+        let receipt = client
+            .send_email_with(&minimal_email(), &options)
+            .await
+            .unwrap()
+            .operation_id;
+        let newsletter = client
+            .send_email_with(
+                &minimal_email(),
+                &SendOptions::new().email_class("newsletter"),
+            )
+            .await
+            .unwrap()
+            .operation_id;
+        assert!(client.operation_tracker().is_tracking(&receipt));
+        assert!(client.operation_tracker().is_tracking(&newsletter));
+        tokio::time::sleep(Duration::from_millis(200)).await;
+
+        assert_eq!(fired.load(Ordering::SeqCst), 1);
+        let summary = client.sla_summary();
+        assert_eq!(summary.watched, 2);
+        assert!(!summary.is_healthy());
+        assert_eq!(summary.overdue.len(), 1);
+        let overdue = &summary.overdue[0];
+        assert_eq!(overdue.operation_id, "slow-op");
+        assert_eq!(overdue.status, EmailSendStatusType::Running);
+        assert_eq!(overdue.email_class.as_deref(), Some("receipt"));
+        assert_eq!(overdue.correlation_id.as_deref(), Some("order-9"));
+        assert!(overdue.age >= overdue.threshold);
+
+        let mut reported = Vec::new();
+        while let Ok(event) = events.try_recv() {
+            if let EmailEventKind::Overdue { threshold, .. } = event.kind {
+                assert_eq!(threshold, Duration::from_millis(50));
+                reported.push(event.operation_id);
+            }
+        }
+        assert_eq!(reported, vec!["slow-op".to_string()]);
     }
 
-    // ── handle_response_and_retry_if_needed: Retry-After header ──────────────
-
     #[tokio::test]
-    async fn send_email_respects_retry_after_header() {
+    async fn delivery_sla_reports_unwatched_send_while_its_status_get_hangs() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
         let server = MockServer::start().await;
-        // First request → 429 with Retry-After: 1
         Mock::given(method("POST"))
-            .and(path("/emails:send"))
-            .respond_with(
-                ResponseTemplate::new(429)
-                    .append_header("Retry-After", "1")
-                    .set_body_json(json!({
-                        "error": { "code": "TooManyRequests", "message": "slow down" }
-                    })),
-            )
-            .up_to_n_times(1)
+            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "fire-op" })))
             .mount(&server)
             .await;
-        // Second request → 202
-        Mock::given(method("POST"))
-            .and(path("/emails:send"))
-            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "after-retry" })))
+        Mock::given(method("GET"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!({ "status": "Succeeded" }))
+                    .set_delay(Duration::from_millis(500)),
+            )
             .mount(&server)
             .await;
 
+        let fired = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&fired);
         let client = ACSClientBuilder::new()
             .connection_string(FAKE_CONN)
-            .max_retries(3)
+            .max_retries(0)
             .base_url_override(&server.uri())
+            .poll_interval(Duration::from_millis(10))
+            .delivery_sla(
+                DeliverySla::new(Duration::from_millis(50)).on_overdue(move |_| {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                }),
+            )
             .build()
             .unwrap();
 
-        let result = client.send_email(&minimal_email()).await;
-        assert_eq!(result.unwrap(), "after-retry");
+        // Nothing waits on the operation, and its only GET takes longer than
+        // the threshold.
+        let operation_id = client.send_email(&minimal_email()).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(250)).await;
+        assert_eq!(fired.load(Ordering::SeqCst), 1);
+        assert_eq!(client.sla_summary().overdue.len(), 1);
+
+        tokio::time::sleep(Duration::from_millis(500)).await;
+        assert!(!client.operation_tracker().is_tracking(&operation_id));
+        assert_eq!(client.sla_summary().watched, 0);
+        assert_eq!(fired.load(Ordering::SeqCst), 1);
     }
 
-    // ── send_email_and_wait ───────────────────────────────────────────────────
+    // ── resend policy ─────────────────────────────────────────────────────────
 
     #[tokio::test]
-    async fn send_email_and_wait_returns_terminal_status() {
-        let server = MockServer::start().await;
-        let email = minimal_email();
-        let op_id = "op-wait-1";
+    async fn resend_policy_resends_failed_email_with_fresh_key() {
+        use crate::adapters::gateways::events::EmailEventKind;
+        use crate::adapters::gateways::resend::ResendPolicy;
 
+        let server = MockServer::start().await;
         Mock::given(method("POST"))
-            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": op_id })))
+            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "first-op" })))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "second-op" })))
             .mount(&server)
             .await;
         Mock::given(method("GET"))
+            .and(path("/emails/operations/first-op"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "id": "first-op",
+                "status": "Failed",
+                "error": { "code": "ServiceUnavailable", "message": "try again" }
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/emails/operations/second-op"))
             .respond_with(
                 ResponseTemplate::new(200).set_body_json(json!({ "status": "Succeeded" })),
             )
             .mount(&server)
             .await;
 
-        let client = ACSClientBuilder::new()
-            .connection_string(FAKE_CONN)
-            .base_url_override(&server.uri())
-            .poll_interval(Duration::from_millis(10))
-            .build()
+        let client = polling_client(&server);
+        let mut events = client.subscribe();
+        let options = SendOptions::new()
+            .idempotency_key("caller-key")
+            .correlation_id("order-3")
+            .resend(ResendPolicy::new(3).rule("ServiceUnavailable", Duration::ZERO))
+            .wait(WaitMode::Terminal(Duration::from_secs(5)));
+        let outcome = client
+            .send_email_with(&minimal_email(), &options)
+            .await
             .unwrap();
+        assert_eq!(outcome.operation_id, "second-op");
+        assert_eq!(outcome.status, Some(EmailSendStatusType::Succeeded));
+        assert_eq!(outcome.attempts, vec!["first-op", "second-op"]);
 
-        let result = client
-            .send_email_and_wait(&email, Duration::from_secs(5))
-            .await;
-        assert!(matches!(result, Ok(EmailSendStatusType::Succeeded)));
+        let keys: Vec<String> = server
+            .received_requests()
+            .await
+            .unwrap()
+            .iter()
+            .filter(|request| request.method.as_str() == "POST")
+            .map(|request| {
+                request.headers["repeatability-request-id"]
+                    .to_str()
+                    .unwrap()
+                    .to_string()
+            })
+            .collect();
+        assert_eq!(keys.len(), 2);
+        assert_eq!(keys[0], "caller-key");
+        assert_ne!(keys[1], "caller-key");
+
+        let resent = std::iter::from_fn(|| events.try_recv().ok())
+            .find(|event| matches!(event.kind, EmailEventKind::Resent { .. }))
+            .unwrap();
+        assert_eq!(resent.operation_id, "second-op");
+        assert_eq!(resent.correlation_id.as_deref(), Some("order-3"));
+        assert!(matches!(
+            resent.kind,
+            EmailEventKind::Resent { ref previous_operation_id, attempt: 2 }
+                if previous_operation_id == "first-op"
+        ));
     }
 
     #[tokio::test]
-    async fn send_email_and_wait_times_out() {
-        let server = MockServer::start().await;
-        let email = minimal_email();
-        let op_id = "op-wait-timeout";
+    async fn client_resend_policy_stops_at_attempt_cap() {
+        use crate::adapters::gateways::resend::ResendPolicy;
 
+        let server = MockServer::start().await;
         Mock::given(method("POST"))
-            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": op_id })))
+            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "doomed-op" })))
+            .expect(2)
             .mount(&server)
             .await;
         Mock::given(method("GET"))
-            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "status": "Running" })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "status": "Failed",
+                "error": { "code": "ServiceUnavailable", "message": "try again" }
+            })))
             .mount(&server)
             .await;
 
         let client = ACSClientBuilder::new()
             .connection_string(FAKE_CONN)
+            .max_retries(0)
             .base_url_override(&server.uri())
-            .poll_interval(Duration::from_secs(60)) // longer than timeout
+            .poll_interval(Duration::from_millis(10))
+            .resend_policy(ResendPolicy::new(2).rule("ServiceUnavailable", Duration::ZERO))
             .build()
             .unwrap();
-
         let result = client
-            .send_email_and_wait(&email, Duration::from_millis(50))
+            .send_email_and_wait(&minimal_email(), Duration::from_secs(5))
             .await;
-        assert!(matches!(result, Err(ACSError::Timeout)));
+        assert!(matches!(result, Err(ACSError::DeliveryFailed { .. })));
     }
 
-    // ── send_email_idempotent ─────────────────────────────────────────────────
-
     #[tokio::test]
-    async fn send_email_idempotent_sends_repeatability_headers() {
-        use wiremock::matchers::header_exists;
+    async fn exhausted_resend_policy_reports_every_attempt() {
+        use crate::adapters::gateways::resend::ResendPolicy;
 
         let server = MockServer::start().await;
-        let email = minimal_email();
-        let key = uuid::Uuid::new_v4().to_string();
-
-        Mock::given(method("POST"))
-            .and(header_exists("repeatability-request-id"))
-            .and(header_exists("repeatability-first-sent"))
-            .respond_with(
-                ResponseTemplate::new(202).set_body_json(json!({ "id": "op-idempotent-1" })),
-            )
+        for id in ["first-op", "second-op", "third-op"] {
+            Mock::given(method("POST"))
+                .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": id })))
+                .up_to_n_times(1)
+                .mount(&server)
+                .await;
+        }
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "status": "Failed",
+                "error": { "code": "ServiceUnavailable", "message": "try again" }
+            })))
             .mount(&server)
             .await;
 
-        let client = ACSClientBuilder::new()
-            .connection_string(FAKE_CONN)
+        let client = polling_client(&server);
+        let options = SendOptions::new()
+            .resend(ResendPolicy::new(3).rule("ServiceUnavailable", Duration::ZERO))
+            .wait(WaitMode::Terminal(Duration::from_secs(5)));
+        match client.send_email_with(&minimal_email(), &options).await {
+            Err(ACSError::DeliveryFailed {
+                operation_id,
+                attempts,
+                ..
+            }) => {
+                assert_eq!(operation_id, "third-op");
+                assert_eq!(attempts, vec!["first-op", "second-op", "third-op"]);
+            }
+            other => panic!("expected DeliveryFailed, got {other:?}"),
+        }
+    }
+
+    // ── token cache ───────────────────────────────────────────────────────────
+
+    fn service_principal_client(server: &MockServer) -> ACSClient {
+        ACSClientBuilder::new()
+            .host("my-resource.communication.azure.com")
+            .service_principal("tenant-1", "client-1", "secret-1")
+            .max_retries(0)
             .base_url_override(&server.uri())
+            .authority_host(&server.uri())
             .build()
-            .unwrap();
+            .unwrap()
+    }
 
-        let result = client.send_email_idempotent(&email, &key).await;
-        assert!(result.is_ok(), "expected Ok, got: {result:?}");
+    fn token_response(token: &str) -> ResponseTemplate {
+        ResponseTemplate::new(200).set_body_json(json!({
+            "token_type": "Bearer",
+            "expires_in": 3600,
+            "access_token": token
+        }))
     }
 
     #[tokio::test]
-    async fn send_email_idempotent_returns_operation_id() {
+    async fn service_principal_token_is_fetched_once_for_concurrent_sends() {
         let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/tenant-1/oauth2/v2.0/token"))
+            .respond_with(token_response("cached-token").set_delay(Duration::from_millis(50)))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/emails:send"))
+            .and(wiremock::matchers::header(
+                "authorization",
+                "Bearer cached-token",
+            ))
+            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "op" })))
+            .expect(6)
+            .mount(&server)
+            .await;
+
+        let client = service_principal_client(&server);
         let email = minimal_email();
-        let key = uuid::Uuid::new_v4().to_string();
+        let sends = (0..5).map(|_| {
+            let client = client.clone();
+            async move { client.send_email(&minimal_email()).await }
+        });
+        for result in futures::future::join_all(sends).await {
+            assert_eq!(result.unwrap(), "op");
+        }
+        client.send_email(&email).await.unwrap();
+        assert_eq!(client.token_cache().unwrap().refreshes(), 1);
+    }
 
+    #[tokio::test]
+    async fn unauthorized_response_forces_token_refresh_and_resend() {
+        let server = MockServer::start().await;
         Mock::given(method("POST"))
-            .respond_with(
-                ResponseTemplate::new(202).set_body_json(json!({ "id": "idempotent-op-42" })),
-            )
+            .and(path("/tenant-1/oauth2/v2.0/token"))
+            .respond_with(token_response("revoked-token"))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/tenant-1/oauth2/v2.0/token"))
+            .respond_with(token_response("fresh-token"))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/emails:send"))
+            .and(wiremock::matchers::header(
+                "authorization",
+                "Bearer revoked-token",
+            ))
+            .respond_with(ResponseTemplate::new(401).set_body_json(json!({
+                "error": { "code": "InvalidAuthenticationToken", "message": "expired" }
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/emails:send"))
+            .and(wiremock::matchers::header(
+                "authorization",
+                "Bearer fresh-token",
+            ))
+            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "op" })))
+            .expect(1)
             .mount(&server)
             .await;
 
-        let client = ACSClientBuilder::new()
-            .connection_string(FAKE_CONN)
-            .base_url_override(&server.uri())
-            .build()
-            .unwrap();
-
-        let result = client.send_email_idempotent(&email, &key).await;
-        assert_eq!(result.unwrap(), "idempotent-op-42");
+        let client = service_principal_client(&server);
+        assert_eq!(client.send_email(&minimal_email()).await.unwrap(), "op");
+        assert_eq!(client.token_cache().unwrap().refreshes(), 2);
     }
 
     #[tokio::test]
-    async fn send_email_idempotent_retries_on_429_preserves_key() {
-        use wiremock::matchers::header_exists;
-
+    async fn concurrent_unauthorized_responses_refresh_the_token_once() {
         let server = MockServer::start().await;
-        let email = minimal_email();
-        let key = uuid::Uuid::new_v4().to_string();
-
-        // First request: 429; second attempt: 202
         Mock::given(method("POST"))
-            .and(header_exists("repeatability-request-id"))
-            .respond_with(ResponseTemplate::new(429))
+            .and(path("/tenant-1/oauth2/v2.0/token"))
+            .respond_with(token_response("revoked-token"))
             .up_to_n_times(1)
             .mount(&server)
             .await;
         Mock::given(method("POST"))
-            .and(header_exists("repeatability-request-id"))
-            .respond_with(
-                ResponseTemplate::new(202).set_body_json(json!({ "id": "idempotent-retry-op" })),
-            )
+            .and(path("/tenant-1/oauth2/v2.0/token"))
+            .respond_with(token_response("fresh-token"))
+            .mount(&server)
+            .await;
+        // Half of the rejections arrive only after the refresh has completed.
+        let rejected = || {
+            ResponseTemplate::new(401).set_body_json(json!({
+                "error": { "code": "InvalidAuthenticationToken", "message": "revoked" }
+            }))
+        };
+        for (delay, count) in [(50, 4), (300, 4)] {
+            Mock::given(method("POST"))
+                .and(path("/emails:send"))
+                .and(wiremock::matchers::header(
+                    "authorization",
+                    "Bearer revoked-token",
+                ))
+                .respond_with(rejected().set_delay(Duration::from_millis(delay)))
+                .up_to_n_times(count)
+                .mount(&server)
+                .await;
+        }
+        Mock::given(method("POST"))
+            .and(path("/emails:send"))
+            .and(wiremock::matchers::header(
+                "authorization",
+                "Bearer fresh-token",
+            ))
+            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "op" })))
+            .expect(8)
             .mount(&server)
             .await;
 
+        let client = service_principal_client(&server);
+        let sends = (0..8).map(|_| {
+            let client = client.clone();
+            async move { client.send_email(&minimal_email()).await }
+        });
+        for result in futures::future::join_all(sends).await {
+            assert_eq!(result.unwrap(), "op");
+        }
+        let token_fetches = server
+            .received_requests()
+            .await
+            .unwrap()
+            .iter()
+            .filter(|r| r.url.path() == "/tenant-1/oauth2/v2.0/token")
+            .count();
+        assert_eq!(token_fetches, 2);
+        assert_eq!(client.token_cache().unwrap().refreshes(), 2);
+    }
+
+    #[test]
+    fn shared_key_client_has_no_token_cache() {
         let client = ACSClientBuilder::new()
             .connection_string(FAKE_CONN)
-            .base_url_override(&server.uri())
-            .max_retries(1)
             .build()
             .unwrap();
-
-        let result = client.send_email_idempotent(&email, &key).await;
-        assert_eq!(result.unwrap(), "idempotent-retry-op");
+        assert!(client.token_cache().is_none());
     }
 
+    // ── caller-supplied credentials ───────────────────────────────────────────
+
     #[tokio::test]
-    async fn send_email_and_wait_returns_failed_status() {
+    async fn token_provider_tokens_are_sent_as_bearer_and_cached() {
         let server = MockServer::start().await;
-        let email = minimal_email();
-
         Mock::given(method("POST"))
-            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "op-fail" })))
-            .mount(&server)
-            .await;
-        Mock::given(method("GET"))
-            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "status": "Failed" })))
+            .and(path("/emails:send"))
+            .and(wiremock::matchers::header(
+                "authorization",
+                "Bearer sidecar-token",
+            ))
+            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "op" })))
+            .expect(2)
             .mount(&server)
             .await;
 
+        let calls = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let counter = calls.clone();
         let client = ACSClientBuilder::new()
-            .connection_string(FAKE_CONN)
+            .host("my-resource.communication.azure.com")
+            .token_provider(move || {
+                counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                async {
+                    Ok::<_, String>(AccessToken::new(
+                        azure_core::auth::Secret::new("sidecar-token".to_string()),
+                        time::OffsetDateTime::now_utc() + time::Duration::hours(1),
+                    ))
+                }
+            })
+            .max_retries(0)
             .base_url_override(&server.uri())
-            .poll_interval(Duration::from_millis(10))
             .build()
             .unwrap();
+        client.send_email(&minimal_email()).await.unwrap();
+        client.send_email(&minimal_email()).await.unwrap();
+        assert_eq!(calls.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
 
-        let result = client
-            .send_email_and_wait(&email, Duration::from_secs(5))
-            .await;
-        assert!(matches!(result, Ok(EmailSendStatusType::Failed)));
+    #[tokio::test]
+    async fn token_provider_error_is_auth_error() {
+        let client = ACSClientBuilder::new()
+            .host("my-resource.communication.azure.com")
+            .token_provider(|| async { Err::<AccessToken, _>("sidecar down") })
+            .max_retries(0)
+            .build()
+            .unwrap();
+        let result = client.send_email(&minimal_email()).await;
+        assert!(matches!(result, Err(ACSError::Auth(message)) if message.contains("sidecar down")));
     }
 
     #[tokio::test]
-    async fn send_email_and_wait_send_fails_returns_send_error() {
+    async fn static_token_and_custom_credential_are_sent_as_bearer() {
         let server = MockServer::start().await;
-        let email = minimal_email();
-
         Mock::given(method("POST"))
-            .respond_with(
-                ResponseTemplate::new(500).set_body_json(
-                    json!({ "error": { "code": "InternalError", "message": "boom" } }),
-                ),
-            )
+            .and(path("/emails:send"))
+            .and(wiremock::matchers::header("authorization", "Bearer fixed"))
+            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "op" })))
+            .expect(2)
             .mount(&server)
             .await;
 
-        let client = ACSClientBuilder::new()
-            .connection_string(FAKE_CONN)
+        let static_client = ACSClientBuilder::new()
+            .host("my-resource.communication.azure.com")
+            .static_token("fixed")
             .base_url_override(&server.uri())
-            .max_retries(0)
             .build()
             .unwrap();
-
-        let result = client
-            .send_email_and_wait(&email, Duration::from_secs(5))
-            .await;
-        // Must be a send-level Api error, not Timeout
-        assert!(matches!(result, Err(ACSError::Api { .. })));
+        let credential_client = ACSClientBuilder::new()
+            .host("my-resource.communication.azure.com")
+            .token_credential(Arc::new(StaticTokenCredential::new("fixed")))
+            .base_url_override(&server.uri())
+            .build()
+            .unwrap();
+        static_client.send_email(&minimal_email()).await.unwrap();
+        credential_client
+            .send_email(&minimal_email())
+            .await
+            .unwrap();
     }
 
     #[tokio::test]
-    async fn send_email_and_wait_poll_error_propagates() {
+    async fn user_assigned_managed_identity_authenticates_sends() {
         let server = MockServer::start().await;
-        let email = minimal_email();
-
-        Mock::given(method("POST"))
-            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "op-poll-err" })))
+        Mock::given(method("GET"))
+            .and(path("/msi/token"))
+            .and(wiremock::matchers::query_param("client_id", "uami-1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "access_token": "uami-token",
+                "expires_on": "4102444800"
+            })))
+            .expect(1)
             .mount(&server)
             .await;
-        Mock::given(method("GET"))
-            .respond_with(ResponseTemplate::new(404).set_body_json(
-                json!({ "error": { "code": "OperationNotFound", "message": "not found" } }),
+        Mock::given(method("POST"))
+            .and(path("/emails:send"))
+            .and(wiremock::matchers::header(
+                "authorization",
+                "Bearer uami-token",
             ))
+            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "op" })))
+            .expect(1)
             .mount(&server)
             .await;
 
         let client = ACSClientBuilder::new()
-            .connection_string(FAKE_CONN)
+            .host("my-resource.communication.azure.com")
+            .managed_identity_client_id("uami-1")
+            .managed_identity_endpoint(&format!("{}/msi/token", server.uri()))
             .base_url_override(&server.uri())
-            .poll_interval(Duration::from_millis(10))
             .build()
             .unwrap();
-
-        let result = client
-            .send_email_and_wait(&email, Duration::from_secs(5))
-            .await;
-        // Must be a poll-level Api error, not Timeout
-        assert!(matches!(result, Err(ACSError::Api { .. })));
+        assert_eq!(client.send_email(&minimal_email()).await.unwrap(), "op");
     }
 
     #[tokio::test]
-    async fn send_email_and_wait_running_then_succeeded() {
+    async fn workload_identity_exchanges_federated_token_for_sends() {
         let server = MockServer::start().await;
-        let email = minimal_email();
-
         Mock::given(method("POST"))
-            .respond_with(
-                ResponseTemplate::new(202).set_body_json(json!({ "id": "op-running-ok" })),
-            )
-            .mount(&server)
-            .await;
-        // First GET: Running; subsequent GETs: Succeeded
-        Mock::given(method("GET"))
-            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "status": "Running" })))
-            .up_to_n_times(1)
+            .and(path("/tenant-1/oauth2/v2.0/token"))
+            .and(wiremock::matchers::body_string_contains(
+                "client_assertion=federated-jwt",
+            ))
+            .respond_with(token_response("workload-token"))
+            .expect(1)
             .mount(&server)
             .await;
-        Mock::given(method("GET"))
-            .respond_with(
-                ResponseTemplate::new(200).set_body_json(json!({ "status": "Succeeded" })),
-            )
+        Mock::given(method("POST"))
+            .and(path("/emails:send"))
+            .and(wiremock::matchers::header(
+                "authorization",
+                "Bearer workload-token",
+            ))
+            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "op" })))
+            .expect(1)
             .mount(&server)
             .await;
 
+        let dir = tempfile::tempdir().unwrap();
+        let token_file = dir.path().join("azure-identity-token");
+        std::fs::write(&token_file, "federated-jwt").unwrap();
         let client = ACSClientBuilder::new()
-            .connection_string(FAKE_CONN)
+            .host("my-resource.communication.azure.com")
+            .workload_identity("tenant-1", "app-1", &token_file)
+            .authority_host(&server.uri())
             .base_url_override(&server.uri())
-            .poll_interval(Duration::from_millis(10))
             .build()
             .unwrap();
-
-        let result = client
-            .send_email_and_wait(&email, Duration::from_secs(5))
-            .await;
-        assert!(matches!(result, Ok(EmailSendStatusType::Succeeded)));
+        assert_eq!(client.send_email(&minimal_email()).await.unwrap(), "op");
     }
 
-    // ── send_email_and_wait_cancellable ───────────────────────────────────────
+    #[test]
+    fn invalid_authority_host_fails_build() {
+        let result = ACSClientBuilder::new()
+            .host("my-resource.communication.azure.com")
+            .workload_identity("tenant-1", "app-1", "/var/run/token")
+            .authority_host("not a url")
+            .build();
+        assert!(matches!(result, Err(message) if message.contains("Invalid authority host")));
+    }
 
+    #[cfg(feature = "certificate")]
     #[tokio::test]
-    async fn send_email_and_wait_cancellable_returns_terminal_status() {
-        let server = MockServer::start().await;
-        let email = minimal_email();
+    async fn certificate_service_principal_authenticates_sends() {
+        use crate::adapters::gateways::credentials::tests::{self_signed, write_pem};
 
+        let server = MockServer::start().await;
         Mock::given(method("POST"))
-            .respond_with(
-                ResponseTemplate::new(202).set_body_json(json!({ "id": "op-cancellable-ok" })),
-            )
+            .and(path("/tenant-1/oauth2/v2.0/token"))
+            .and(wiremock::matchers::body_string_contains(
+                "client_assertion_type=urn%3Aietf%3Aparams%3Aoauth%3Aclient-assertion-type%3Ajwt-bearer",
+            ))
+            .and(wiremock::matchers::body_string_contains("client_id=app-1"))
+            .respond_with(token_response("certificate-token"))
+            .expect(1)
             .mount(&server)
             .await;
-        Mock::given(method("GET"))
-            .respond_with(
-                ResponseTemplate::new(200).set_body_json(json!({ "status": "Succeeded" })),
-            )
+        Mock::given(method("POST"))
+            .and(path("/emails:send"))
+            .and(wiremock::matchers::header(
+                "authorization",
+                "Bearer certificate-token",
+            ))
+            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "op" })))
+            .expect(1)
             .mount(&server)
             .await;
 
+        let dir = tempfile::tempdir().unwrap();
+        let certificate_path = dir.path().join("sp.pem");
+        let (certificate, key) = self_signed("app-1");
+        write_pem(&certificate_path, &certificate, &key);
         let client = ACSClientBuilder::new()
-            .connection_string(FAKE_CONN)
+            .host("my-resource.communication.azure.com")
+            .service_principal_certificate("tenant-1", "app-1", &certificate_path)
+            .authority_host(&server.uri())
             .base_url_override(&server.uri())
-            .poll_interval(Duration::from_millis(10))
             .build()
             .unwrap();
+        assert_eq!(client.send_email(&minimal_email()).await.unwrap(), "op");
+    }
 
-        let token = CancellationToken::new();
-        let result = client
-            .send_email_and_wait_cancellable(&email, Duration::from_secs(5), token)
-            .await;
-        assert!(matches!(result, Ok(EmailSendStatusType::Succeeded)));
+    #[cfg(feature = "certificate")]
+    #[tokio::test]
+    async fn missing_certificate_is_auth_error() {
+        let client = ACSClientBuilder::new()
+            .host("my-resource.communication.azure.com")
+            .service_principal_certificate("tenant-1", "app-1", "/nonexistent/sp.pem")
+            .build()
+            .unwrap();
+        let result = client.send_email(&minimal_email()).await;
+        assert!(
+            matches!(result, Err(ACSError::Auth(message)) if message.contains("failed to read certificate"))
+        );
     }
 
+    // ── sovereign clouds ──────────────────────────────────────────────────────
+
     #[tokio::test]
-    async fn send_email_and_wait_cancellable_returns_canceled_when_token_fires() {
+    async fn custom_cloud_sets_authority_and_token_scope() {
         let server = MockServer::start().await;
-        let email = minimal_email();
-
         Mock::given(method("POST"))
-            .respond_with(
-                ResponseTemplate::new(202).set_body_json(json!({ "id": "op-cancellable-tok" })),
-            )
+            .and(path("/tenant-1/oauth2/v2.0/token"))
+            .and(wiremock::matchers::body_string_contains(
+                "scope=https%3A%2F%2Fcommunication.example.cloud%2F.default",
+            ))
+            .respond_with(token_response("private-cloud-token"))
+            .expect(1)
             .mount(&server)
             .await;
-        Mock::given(method("GET"))
-            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "status": "Running" })))
+        Mock::given(method("POST"))
+            .and(path("/emails:send"))
+            .and(wiremock::matchers::header(
+                "authorization",
+                "Bearer private-cloud-token",
+            ))
+            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "op" })))
+            .expect(1)
             .mount(&server)
             .await;
 
         let client = ACSClientBuilder::new()
-            .connection_string(FAKE_CONN)
+            .host("my-resource.communication.example.cloud")
+            .cloud(AzureCloud::Custom {
+                authority_host: format!("{}/", server.uri()),
+                token_scope: "https://communication.example.cloud/.default".to_string(),
+                endpoint_suffix: ".communication.example.cloud".to_string(),
+            })
+            .service_principal("tenant-1", "client-1", "secret-1")
             .base_url_override(&server.uri())
-            .poll_interval(Duration::from_secs(60)) // longer than cancel fires
             .build()
             .unwrap();
-
-        let token = CancellationToken::new();
-        let token_clone = token.clone();
-        // Cancel after a short delay
-        tokio::spawn(async move {
-            tokio::time::sleep(Duration::from_millis(20)).await;
-            token_clone.cancel();
-        });
-
-        let result = client
-            .send_email_and_wait_cancellable(&email, Duration::from_secs(5), token)
-            .await;
-        assert!(matches!(result, Err(ACSError::Canceled)));
+        assert_eq!(client.send_email(&minimal_email()).await.unwrap(), "op");
     }
 
     #[tokio::test]
-    async fn send_email_and_wait_cancellable_already_cancelled_returns_canceled() {
+    async fn explicit_authority_host_overrides_cloud() {
         let server = MockServer::start().await;
-        let email = minimal_email();
-
         Mock::given(method("POST"))
-            .respond_with(
-                ResponseTemplate::new(202).set_body_json(json!({ "id": "op-precancelled" })),
-            )
+            .and(path("/tenant-1/oauth2/v2.0/token"))
+            .and(wiremock::matchers::body_string_contains(
+                "scope=https%3A%2F%2Fcommunication.azure.us%2F.default",
+            ))
+            .respond_with(token_response("gov-token"))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/emails:send"))
+            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "op" })))
             .mount(&server)
             .await;
 
         let client = ACSClientBuilder::new()
-            .connection_string(FAKE_CONN)
+            .host("my-resource.communication.azure.us")
+            .cloud(AzureCloud::UsGov)
+            .service_principal("tenant-1", "client-1", "secret-1")
+            .authority_host(&server.uri())
             .base_url_override(&server.uri())
-            .poll_interval(Duration::from_millis(10))
             .build()
             .unwrap();
+        assert_eq!(client.send_email(&minimal_email()).await.unwrap(), "op");
+    }
+
+    // ── shared-key rotation ───────────────────────────────────────────────────
+
+    const PRIMARY_KEY: &str = "cHJpbWFyeQ==";
+    const SECONDARY_KEY: &str = "c2Vjb25kYXJ5";
+
+    /// Matches requests whose HMAC signature was computed with `key`.
+    fn signed_with(key: &str) -> impl wiremock::Match {
+        let key = key.to_string();
+        move |request: &wiremock::Request| {
+            let header = |name: &str| {
+                request
+                    .headers
+                    .get(name)
+                    .and_then(|value| value.to_str().ok())
+                    .unwrap_or_default()
+                    .to_string()
+            };
+            let string_to_sign = format!(
+                "POST\n{}?{}\n{};{};{}",
+                request.url.path(),
+                request.url.query().unwrap_or_default(),
+                header("x-ms-date"),
+                header("host").split(':').next().unwrap_or_default(),
+                header("x-ms-content-sha256")
+            );
+            let signature =
+                crate::adapters::gateways::acs_shared_key::compute_signature(&string_to_sign, &key)
+                    .unwrap();
+            header("authorization").ends_with(&format!("Signature={}", signature))
+        }
+    }
 
-        let token = CancellationToken::new();
-        token.cancel(); // pre-cancelled before the call
-
-        let result = client
-            .send_email_and_wait_cancellable(&email, Duration::from_secs(5), token)
-            .await;
-        assert!(matches!(result, Err(ACSError::Canceled)));
+    fn shared_key_client(server: &MockServer, secondary: Option<&str>) -> ACSClient {
+        let mut builder = ACSClientBuilder::new()
+            .connection_string(&format!(
+                "endpoint=https://my-resource.communication.azure.com;accesskey={PRIMARY_KEY}"
+            ))
+            .max_retries(0)
+            .base_url_override(&server.uri());
+        if let Some(secondary) = secondary {
+            builder = builder.secondary_key(secondary);
+        }
+        builder.build().unwrap()
     }
 
     #[tokio::test]
-    async fn send_email_and_wait_cancellable_times_out_before_cancel() {
+    async fn unauthorized_shared_key_falls_back_to_secondary() {
         let server = MockServer::start().await;
-        let email = minimal_email();
-
         Mock::given(method("POST"))
-            .respond_with(
-                ResponseTemplate::new(202).set_body_json(json!({ "id": "op-timeout-wins" })),
-            )
+            .and(path("/emails:send"))
+            .and(signed_with(SECONDARY_KEY))
+            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "op" })))
+            .expect(2)
             .mount(&server)
             .await;
-        Mock::given(method("GET"))
-            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "status": "Running" })))
+        Mock::given(method("POST"))
+            .and(path("/emails:send"))
+            .respond_with(ResponseTemplate::new(401).set_body_json(json!({
+                "error": { "code": "Denied", "message": "invalid signature" }
+            })))
+            .expect(1)
             .mount(&server)
             .await;
 
-        let client = ACSClientBuilder::new()
-            .connection_string(FAKE_CONN)
-            .base_url_override(&server.uri())
-            .poll_interval(Duration::from_secs(60))
-            .build()
-            .unwrap();
+        let client = shared_key_client(&server, Some(SECONDARY_KEY));
+        assert_eq!(client.send_email(&minimal_email()).await.unwrap(), "op");
+        // The fallback stays active for later sends and for clones.
+        assert_eq!(
+            client.clone().send_email(&minimal_email()).await.unwrap(),
+            "op"
+        );
+        assert_eq!(client.access_keys().unwrap().generation(), 1);
+    }
 
-        let token = CancellationToken::new(); // never cancelled
-        let result = client
-            .send_email_and_wait_cancellable(&email, Duration::from_millis(50), token)
+    #[tokio::test]
+    async fn rotated_key_applies_to_all_clones() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/emails:send"))
+            .and(signed_with(SECONDARY_KEY))
+            .respond_with(ResponseTemplate::new(202).set_body_json(json!({ "id": "op" })))
+            .expect(1)
+            .mount(&server)
             .await;
-        assert!(matches!(result, Err(ACSError::Timeout)));
+
+        let client = shared_key_client(&server, None);
+        let clone = client.clone();
+        client.rotate_key(SECONDARY_KEY).unwrap();
+        assert_eq!(clone.send_email(&minimal_email()).await.unwrap(), "op");
+        assert!(client.rotate_key("not base64!").is_err());
     }
 
     #[tokio::test]
-    async fn send_email_and_wait_cancellable_send_fails_returns_send_error() {
+    async fn unauthorized_without_fallback_key_is_returned() {
         let server = MockServer::start().await;
-        let email = minimal_email();
-
         Mock::given(method("POST"))
-            .respond_with(
-                ResponseTemplate::new(500).set_body_json(
-                    json!({ "error": { "code": "InternalError", "message": "boom" } }),
-                ),
-            )
+            .and(path("/emails:send"))
+            .respond_with(ResponseTemplate::new(401).set_body_json(json!({
+                "error": { "code": "Denied", "message": "invalid signature" }
+            })))
+            .expect(1)
             .mount(&server)
             .await;
 
+        let client = shared_key_client(&server, None);
+        let result = client.send_email(&minimal_email()).await;
+        assert!(matches!(result, Err(ACSError::Api { code: Some(code), .. }) if code == "Denied"));
+    }
+
+    #[test]
+    fn token_client_cannot_rotate_keys() {
         let client = ACSClientBuilder::new()
-            .connection_string(FAKE_CONN)
-            .base_url_override(&server.uri())
-            .max_retries(0)
+            .host("my-resource.communication.azure.com")
+            .static_token("fixed")
             .build()
             .unwrap();
-
-        let token = CancellationToken::new();
-        let result = client
-            .send_email_and_wait_cancellable(&email, Duration::from_secs(5), token)
-            .await;
-        assert!(matches!(result, Err(ACSError::Api { .. })));
+        assert!(matches!(
+            client.rotate_key(PRIMARY_KEY),
+            Err(ACSError::Auth(_))
+        ));
+        assert!(client.access_keys().is_none());
     }
 }
@@ -0,0 +1,305 @@
+//! Batch send reports with safe resending of failed slots.
+//!
+//! [`ACSClient::send_emails_batch_report`] sends a batch like
+//! [`send_emails_batch`] but returns a [`BatchReport`] instead of a bare
+//! `Vec` of results.  The report keeps each email together with a stable
+//! idempotency key and the time of its first attempt, so
+//! [`BatchReport::retry_failed`] resends only the failed slots with the same
+//! `repeatability-request-id` / `repeatability-first-sent` headers as the
+//! first pass.  ACS deduplicates on those headers, so a slot that actually
+//! went through despite a client-side error is never sent twice.
+//!
+//! ```rust,ignore
+//! let mut report = client.send_emails_batch_report(emails).await;
+//! for (kind, slots) in report.failures_by_kind() {
+//!     eprintln!("{kind:?}: {} failed", slots.len());
+//! }
+//! if !report.is_complete() {
+//!     report.retry_failed(&client).await;
+//! }
+//! ```
+//!
+//! [`ACSClient::send_emails_batch_report`]: crate::adapters::gateways::acs_email::ACSClient::send_emails_batch_report
+//! [`send_emails_batch`]: crate::adapters::gateways::acs_email::ACSClient::send_emails_batch
+
+use crate::adapters::gateways::acs_email::{ACSClient, SendOptions};
+use crate::domain::entities::models::{ACSError, ACSErrorKind, SentEmail};
+use std::collections::HashMap;
+use std::time::{Duration, SystemTime};
+use tokio::time::Instant;
+use tracing::debug;
+use uuid::Uuid;
+
+/// One email of a batch and the outcome of its latest attempt.
+#[derive(Debug)]
+struct BatchSlot {
+    email: SentEmail,
+    idempotency_key: String,
+    first_sent: Option<SystemTime>,
+    result: Option<Result<String, ACSError>>,
+}
+
+/// Outcome of a batch send, returned by
+/// [`ACSClient::send_emails_batch_report`].
+///
+/// Slots are indexed in input order.  Accepted slots carry the ACS operation
+/// ID; failed slots keep their error until [`retry_failed`] resends them.
+///
+/// [`retry_failed`]: BatchReport::retry_failed
+#[derive(Debug)]
+pub struct BatchReport {
+    slots: Vec<BatchSlot>,
+    started_at: SystemTime,
+    elapsed: Duration,
+    passes: u32,
+}
+
+impl BatchReport {
+    /// A report with one unsent slot per email, each with a fresh idempotency key.
+    pub(crate) fn new(emails: impl IntoIterator<Item = SentEmail>) -> Self {
+        let slots = emails
+            .into_iter()
+            .map(|email| BatchSlot {
+                email,
+                idempotency_key: Uuid::new_v4().to_string(),
+                first_sent: None,
+                result: None,
+            })
+            .collect();
+        BatchReport {
+            slots,
+            started_at: SystemTime::now(),
+            elapsed: Duration::ZERO,
+            passes: 0,
+        }
+    }
+
+    /// Resend every slot that has not been accepted yet, reusing its
+    /// idempotency key and first-sent time, and return how many were resent.
+    ///
+    /// Accepted slots are left untouched.  The sends run under `client`'s
+    /// adaptive batch concurrency limit.
+    pub async fn retry_failed(&mut self, client: &ACSClient) -> usize {
+        let now = SystemTime::now();
+        let pending: Vec<usize> = self
+            .slots
+            .iter()
+            .enumerate()
+            .filter(|(_, slot)| !matches!(slot.result, Some(Ok(_))))
+            .map(|(index, _)| index)
+            .collect();
+        if pending.is_empty() {
+            return 0;
+        }
+        debug!(
+            pass = self.passes + 1,
+            slots = pending.len(),
+            "Sending batch slots"
+        );
+
+        let started = Instant::now();
+        for &index in &pending {
+            self.slots[index].first_sent.get_or_insert(now);
+        }
+        let jobs = pending.iter().map(|&index| {
+            let slot = &self.slots[index];
+            let mut options = SendOptions::new().idempotency_key(&slot.idempotency_key);
+            if let Some(first_sent) = slot.first_sent {
+                options = options.first_sent(first_sent);
+            }
+            (&slot.email, options)
+        });
+        let results = client.submit_batch(jobs).await;
+        for (index, result) in pending.iter().zip(results) {
+            self.slots[*index].result = Some(result);
+        }
+        self.elapsed += started.elapsed();
+        self.passes += 1;
+        pending.len()
+    }
+
+    /// Number of emails in the batch.
+    pub fn len(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// `true` when the batch contains no emails.
+    pub fn is_empty(&self) -> bool {
+        self.slots.is_empty()
+    }
+
+    /// `true` when every slot has been accepted by ACS.
+    pub fn is_complete(&self) -> bool {
+        self.accepted_count() == self.slots.len()
+    }
+
+    /// Number of accepted slots.
+    pub fn accepted_count(&self) -> usize {
+        self.accepted().count()
+    }
+
+    /// Number of failed slots.
+    pub fn failed_count(&self) -> usize {
+        self.failures().count()
+    }
+
+    /// `(slot index, operation ID)` for every accepted slot, in input order.
+    pub fn accepted(&self) -> impl Iterator<Item = (usize, &str)> {
+        self.slots
+            .iter()
+            .enumerate()
+            .filter_map(|(index, slot)| match &slot.result {
+                Some(Ok(id)) => Some((index, id.as_str())),
+                _ => None,
+            })
+    }
+
+    /// `(slot index, error)` for every failed slot, in input order.
+    pub fn failures(&self) -> impl Iterator<Item = (usize, &ACSError)> {
+        self.slots
+            .iter()
+            .enumerate()
+            .filter_map(|(index, slot)| match &slot.result {
+                Some(Err(e)) => Some((index, e)),
+                _ => None,
+            })
+    }
+
+    /// Failed slot indices grouped by [`ACSErrorKind`].
+    pub fn failures_by_kind(&self) -> HashMap<ACSErrorKind, Vec<usize>> {
+        let mut grouped: HashMap<ACSErrorKind, Vec<usize>> = HashMap::new();
+        for (index, error) in self.failures() {
+            grouped.entry(error.kind()).or_default().push(index);
+        }
+        grouped
+    }
+
+    /// Result of the latest attempt for slot `index`.
+    pub fn result(&self, index: usize) -> Option<&Result<String, ACSError>> {
+        self.slots.get(index)?.result.as_ref()
+    }
+
+    /// The email in slot `index`.
+    pub fn email(&self, index: usize) -> Option<&SentEmail> {
+        self.slots.get(index).map(|slot| &slot.email)
+    }
+
+    /// The idempotency key sent with every attempt of slot `index`.
+    pub fn idempotency_key(&self, index: usize) -> Option<&str> {
+        self.slots
+            .get(index)
+            .map(|slot| slot.idempotency_key.as_str())
+    }
+
+    /// Wall-clock time at which the report was created.
+    pub fn started_at(&self) -> SystemTime {
+        self.started_at
+    }
+
+    /// Total time spent sending, summed over all passes.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Number of send passes: `1` after the initial send, plus one per
+    /// [`retry_failed`] call that had slots to resend.
+    ///
+    /// [`retry_failed`]: BatchReport::retry_failed
+    pub fn passes(&self) -> u32 {
+        self.passes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::gateways::acs_email::ACSClientBuilder;
+    use crate::domain::entities::models::{
+        EmailAddress, EmailContent, Recipients, SentEmailBuilder,
+    };
+
+    fn email() -> SentEmail {
+        SentEmailBuilder::new()
+            .sender("noreply@example.com".to_string())
+            .content(EmailContent {
+                subject: Some("Test".to_string()),
+                plain_text: Some("body".to_string()),
+                html: None,
+            })
+            .recipients(Recipients {
+                to: Some(vec![EmailAddress {
+                    email: Some("to@example.com".to_string()),
+                    display_name: None,
+                }]),
+                cc: None,
+                b_cc: None,
+            })
+            .build()
+            .unwrap()
+    }
+
+    /// A client whose sends fail immediately with a network error.
+    fn unreachable_client() -> ACSClient {
+        // Bind then drop a listener so the port refuses connections.
+        let addr = std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap();
+        ACSClientBuilder::new()
+            .connection_string("endpoint=https://example.com;accesskey=c2VjcmV0")
+            .base_url_override(&format!("http://{addr}"))
+            .max_retries(0)
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn empty_report_is_complete_without_passes() {
+        let mut report = BatchReport::new(Vec::new());
+        assert!(report.is_empty());
+        assert!(report.is_complete());
+        assert!(report.failures_by_kind().is_empty());
+        assert_eq!(report.retry_failed(&unreachable_client()).await, 0);
+        assert_eq!(report.passes(), 0);
+        assert_eq!(report.elapsed(), Duration::ZERO);
+    }
+
+    #[test]
+    fn failures_are_grouped_by_kind_in_input_order() {
+        let mut report = BatchReport::new((0..5).map(|_| email()));
+        report.slots[0].result = Some(Ok("op-0".to_string()));
+        report.slots[1].result = Some(Err(ACSError::Network("reset".to_string())));
+        report.slots[2].result = Some(Err(ACSError::Timeout));
+        report.slots[3].result = Some(Err(ACSError::Network("refused".to_string())));
+
+        let grouped = report.failures_by_kind();
+        assert_eq!(grouped.len(), 2);
+        assert_eq!(grouped[&ACSErrorKind::Network], vec![1, 3]);
+        assert_eq!(grouped[&ACSErrorKind::Timeout], vec![2]);
+        assert_eq!(report.accepted().collect::<Vec<_>>(), vec![(0, "op-0")]);
+        // Slot 4 was never sent: neither accepted nor failed.
+        assert_eq!(report.failed_count(), 3);
+        assert!(report.result(4).is_none());
+        assert!(!report.is_complete());
+    }
+
+    #[tokio::test]
+    async fn retry_failed_keeps_key_and_first_sent_across_passes() {
+        let client = unreachable_client();
+        let mut report = BatchReport::new([email(), email()]);
+        report.slots[1].result = Some(Ok("op-1".to_string()));
+
+        assert_eq!(report.retry_failed(&client).await, 1);
+        let key = report.idempotency_key(0).unwrap().to_string();
+        let first_sent = report.slots[0].first_sent.unwrap();
+        assert!(report.slots[1].first_sent.is_none());
+        assert!(matches!(report.result(0), Some(Err(ACSError::Network(_)))));
+
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        assert_eq!(report.retry_failed(&client).await, 1);
+        assert_eq!(report.passes(), 2);
+        assert_eq!(report.idempotency_key(0), Some(key.as_str()));
+        assert_eq!(report.slots[0].first_sent, Some(first_sent));
+        assert_eq!(report.accepted_count(), 1);
+    }
+}
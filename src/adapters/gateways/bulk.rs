@@ -0,0 +1,368 @@
+//! Resumable bulk sends backed by an on-disk checkpoint.
+//!
+//! A [`BulkJob`] sends a stream of emails like [`send_stream`], but records
+//! every item in a JSON-lines checkpoint file named after the job ID:
+//!
+//! 1. Before an item is sent, an *in-flight* record with its index,
+//!    idempotency key and first-sent time is appended and synced to disk.
+//! 2. Once ACS answers, a record with the operation ID (or the error) is
+//!    appended.
+//!
+//! Running a job again with the same ID and checkpoint directory replays the
+//! file: items with an operation ID are skipped, and in-flight or failed items
+//! are resent with their original `repeatability-request-id` and
+//! `repeatability-first-sent` headers (the same headers
+//! [`send_email_idempotent`] sends), so ACS deduplicates anything that was
+//! accepted before the process died.  A last line left incomplete by a crash
+//! is cut off before new records are appended.  Items must be supplied in
+//! the same order on every run, since they are matched by index.
+//!
+//! ```rust,ignore
+//! let job = BulkJob::new("newsletter-2026-10", "/var/lib/mailer/jobs");
+//! let summary = job.run(&client, cursor_stream).await?;
+//! println!("{} sent, {} already done", summary.accepted, summary.skipped);
+//! ```
+//!
+//! [`send_stream`]: crate::adapters::gateways::acs_email::ACSClient::send_stream
+//! [`send_email_idempotent`]: crate::adapters::gateways::acs_email::ACSClient::send_email_idempotent
+
+use crate::adapters::gateways::acs_email::{ACSClient, SendOptions};
+use crate::domain::entities::models::{ACSError, SentEmail};
+use futures::stream::{Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::fs::{File, OpenOptions};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::sync::Mutex;
+use tracing::{debug, instrument, warn};
+use uuid::Uuid;
+
+/// One line of the checkpoint file.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+struct CheckpointRecord {
+    index: usize,
+    idempotency_key: String,
+    /// `repeatability-first-sent`, as seconds since the Unix epoch.
+    first_sent: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    operation_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+impl CheckpointRecord {
+    fn first_sent_time(&self) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(self.first_sent)
+    }
+}
+
+/// A bulk send that can be resumed after a crash.  See the [module docs](self).
+#[derive(Clone, Debug)]
+pub struct BulkJob {
+    job_id: String,
+    checkpoint_path: PathBuf,
+}
+
+/// Totals for one [`BulkJob::run`].
+#[derive(Debug, Default)]
+pub struct BulkJobSummary {
+    /// Items accepted by ACS during this run.
+    pub accepted: usize,
+    /// Items skipped because the checkpoint already recorded an operation ID.
+    pub skipped: usize,
+    /// Items resent with the idempotency key from an earlier run.
+    pub resent: usize,
+    /// `(index, error)` for every item that failed during this run.
+    pub failures: Vec<(usize, ACSError)>,
+}
+
+impl BulkJob {
+    /// A job whose checkpoint is `<checkpoint_dir>/<job_id>.jsonl`.
+    pub fn new(job_id: &str, checkpoint_dir: impl AsRef<Path>) -> Self {
+        BulkJob {
+            job_id: job_id.to_string(),
+            checkpoint_path: checkpoint_dir.as_ref().join(format!("{job_id}.jsonl")),
+        }
+    }
+
+    /// The job ID passed to [`new`](BulkJob::new).
+    pub fn job_id(&self) -> &str {
+        &self.job_id
+    }
+
+    /// Path of the checkpoint file.
+    ///
+    /// The job ID is not validated until [`run`](BulkJob::run), so for an ID
+    /// that `run` rejects this path may lie outside the checkpoint directory.
+    pub fn checkpoint_path(&self) -> &Path {
+        &self.checkpoint_path
+    }
+
+    /// Send `emails`, skipping items the checkpoint records as accepted and
+    /// resending the rest with their original idempotency keys.
+    ///
+    /// Sends run under `client`'s adaptive batch concurrency limit.  A failed
+    /// send does not stop the job; it is reported in
+    /// [`BulkJobSummary::failures`] and retried on the next run.
+    ///
+    /// # Errors
+    ///
+    /// [`ACSError::Checkpoint`] when the job ID is not a plain file name or
+    /// the checkpoint file cannot be read or written.  Items sent before the
+    /// failure are recorded, so the job can be resumed.
+    #[instrument(skip(self, client, emails), fields(job_id = %self.job_id))]
+    pub async fn run<S>(&self, client: &ACSClient, emails: S) -> Result<BulkJobSummary, ACSError>
+    where
+        S: Stream<Item = SentEmail>,
+    {
+        if self.job_id.is_empty()
+            || self.job_id.contains(['/', '\\', '\0'])
+            || (cfg!(windows) && self.job_id.contains(':'))
+        {
+            return Err(ACSError::Checkpoint(format!(
+                "invalid job ID {:?}",
+                self.job_id
+            )));
+        }
+        let (previous, torn_at) = load_checkpoint(&self.checkpoint_path).await?;
+        debug!(records = previous.len(), "Loaded checkpoint");
+        if let Some(complete_len) = torn_at {
+            // Drop a line cut short by a crash, so the next record starts on
+            // a line of its own instead of being merged into the fragment.
+            truncate(&self.checkpoint_path, complete_len)
+                .await
+                .map_err(|e| checkpoint_err(&self.checkpoint_path, e))?;
+        }
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.checkpoint_path)
+            .await
+            .map_err(|e| checkpoint_err(&self.checkpoint_path, e))?;
+        let writer = Mutex::new(file);
+
+        let mut summary = BulkJobSummary::default();
+        let mut skipped = 0;
+        let mut resent = 0;
+        {
+            let writer = &writer;
+            let results = emails
+                .enumerate()
+                .filter_map(|(index, email)| {
+                    let record = match previous.get(&index) {
+                        Some(record) if record.operation_id.is_some() => {
+                            skipped += 1;
+                            None
+                        }
+                        Some(record) => {
+                            resent += 1;
+                            Some(CheckpointRecord {
+                                operation_id: None,
+                                error: None,
+                                ..record.clone()
+                            })
+                        }
+                        None => Some(CheckpointRecord {
+                            index,
+                            idempotency_key: Uuid::new_v4().to_string(),
+                            first_sent: unix_secs(SystemTime::now()),
+                            operation_id: None,
+                            error: None,
+                        }),
+                    };
+                    futures::future::ready(record.map(|record| (record, email)))
+                })
+                .map(|(mut record, email)| async move {
+                    // Durable before the send: it carries the key a resend
+                    // after a crash must reuse.
+                    append_record(writer, &record, true).await?;
+                    let options = SendOptions::new()
+                        .idempotency_key(&record.idempotency_key)
+                        .first_sent(record.first_sent_time());
+                    let result = client.submit_limited(&email, &options).await;
+                    match &result {
+                        Ok(operation_id) => record.operation_id = Some(operation_id.clone()),
+                        Err(e) => record.error = Some(e.to_string()),
+                    }
+                    // Written here rather than by the consumer below, which
+                    // would wait on `writer` while no one polls its holder.
+                    append_record(writer, &record, false).await?;
+                    Ok::<_, ACSError>((record.index, result))
+                })
+                .buffer_unordered(client.batch_concurrency().ceiling);
+            tokio::pin!(results);
+
+            while let Some(item) = results.next().await {
+                let (index, result) = item?;
+                match result {
+                    Ok(_) => summary.accepted += 1,
+                    Err(e) => summary.failures.push((index, e)),
+                }
+            }
+        }
+        summary.skipped = skipped;
+        summary.resent = resent;
+        summary.failures.sort_by_key(|(index, _)| *index);
+        Ok(summary)
+    }
+}
+
+/// Replay the checkpoint at `path`; later records for an index win.
+///
+/// When the file ends in a line cut short by a crash, also returns the
+/// length of the file up to its last complete line.  A missing file is an
+/// empty checkpoint.  Lines that do not parse are skipped with a warning.
+async fn load_checkpoint(
+    path: &Path,
+) -> Result<(HashMap<usize, CheckpointRecord>, Option<u64>), ACSError> {
+    let file = match File::open(path).await {
+        Ok(file) => file,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok((HashMap::new(), None)),
+        Err(e) => return Err(checkpoint_err(path, e)),
+    };
+    let mut records = HashMap::new();
+    let mut complete_len = 0;
+    let mut reader = BufReader::new(file);
+    let mut line = Vec::new();
+    loop {
+        line.clear();
+        let read = reader
+            .read_until(b'\n', &mut line)
+            .await
+            .map_err(|e| checkpoint_err(path, e))?;
+        if read == 0 {
+            break;
+        }
+        if line.last() != Some(&b'\n') {
+            warn!("Discarding torn checkpoint line");
+            return Ok((records, Some(complete_len)));
+        }
+        complete_len += read as u64;
+        if line.trim_ascii().is_empty() {
+            continue;
+        }
+        match serde_json::from_slice::<CheckpointRecord>(&line) {
+            Ok(record) => {
+                records.insert(record.index, record);
+            }
+            Err(e) => warn!(error = %e, "Skipping unreadable checkpoint line"),
+        }
+    }
+    Ok((records, None))
+}
+
+/// Cut the file at `path` to `len` bytes.
+///
+/// Uses a plain write handle: on Windows an append-only handle lacks the
+/// right to change the file length.
+async fn truncate(path: &Path, len: u64) -> std::io::Result<()> {
+    OpenOptions::new()
+        .write(true)
+        .open(path)
+        .await?
+        .set_len(len)
+        .await
+}
+
+/// Append `record` as one line; `sync` waits until it is on disk.
+async fn append_record(
+    writer: &Mutex<File>,
+    record: &CheckpointRecord,
+    sync: bool,
+) -> Result<(), ACSError> {
+    let mut line =
+        serde_json::to_vec(record).map_err(|e| ACSError::Serialization(e.to_string()))?;
+    line.push(b'\n');
+    let mut file = writer.lock().await;
+    file.write_all(&line)
+        .await
+        .map_err(|e| ACSError::Checkpoint(e.to_string()))?;
+    file.flush()
+        .await
+        .map_err(|e| ACSError::Checkpoint(e.to_string()))?;
+    if sync {
+        file.sync_data()
+            .await
+            .map_err(|e| ACSError::Checkpoint(e.to_string()))?;
+    }
+    Ok(())
+}
+
+fn checkpoint_err(path: &Path, e: std::io::Error) -> ACSError {
+    ACSError::Checkpoint(format!("{}: {}", path.display(), e))
+}
+
+fn unix_secs(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checkpoint_path_is_named_after_job() {
+        let job = BulkJob::new("job-1", "/tmp/jobs");
+        assert_eq!(job.checkpoint_path(), Path::new("/tmp/jobs/job-1.jsonl"));
+        assert_eq!(job.job_id(), "job-1");
+    }
+
+    #[tokio::test]
+    async fn load_checkpoint_keeps_latest_record_and_skips_torn_lines() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("job.jsonl");
+        let lines = [
+            r#"{"index":0,"idempotency_key":"k0","first_sent":100}"#,
+            r#"{"index":0,"idempotency_key":"k0","first_sent":100,"operation_id":"op-0"}"#,
+            r#"{"index":1,"idempotency_key":"k1","first_sent":101}"#,
+            r#"{"index":2,"idempotency_ke"#,
+        ];
+        tokio::fs::write(&path, lines.join("\n")).await.unwrap();
+
+        let (records, torn_at) = load_checkpoint(&path).await.unwrap();
+        assert_eq!(records.len(), 2);
+        let torn = lines[3].len() as u64;
+        assert_eq!(
+            torn_at,
+            Some(tokio::fs::metadata(&path).await.unwrap().len() - torn)
+        );
+        assert_eq!(records[&0].operation_id.as_deref(), Some("op-0"));
+        assert_eq!(records[&1].operation_id, None);
+        assert_eq!(
+            records[&1].first_sent_time(),
+            UNIX_EPOCH + Duration::from_secs(101)
+        );
+    }
+
+    #[tokio::test]
+    async fn load_checkpoint_treats_missing_file_as_empty() {
+        let dir = tempfile::tempdir().unwrap();
+        let (records, torn_at) = load_checkpoint(&dir.path().join("absent.jsonl"))
+            .await
+            .unwrap();
+        assert!(records.is_empty());
+        assert_eq!(torn_at, None);
+    }
+
+    #[tokio::test]
+    async fn load_checkpoint_reports_no_tear_for_complete_lines() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("job.jsonl");
+        tokio::fs::write(
+            &path,
+            concat!(
+                r#"{"index":0,"idempotency_key":"k0","first_sent":100}"#,
+                "\n"
+            ),
+        )
+        .await
+        .unwrap();
+        let (records, torn_at) = load_checkpoint(&path).await.unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(torn_at, None);
+    }
+}
@@ -0,0 +1,135 @@
+//! Azure cloud environments: Entra ID authority, ACS token scope and
+//! endpoint suffix.
+//!
+//! ACS resources in Azure Government and Azure China use a different Entra ID
+//! login host, token audience and endpoint domain than the public cloud.
+//! [`ACSClientBuilder::cloud`] selects all three at once:
+//!
+//! | Cloud | Authority host | Token scope | Endpoint suffix |
+//! |---|---|---|---|
+//! | [`Public`] (default) | `https://login.microsoftonline.com/` | `https://communication.azure.com/.default` | `.communication.azure.com` |
+//! | [`UsGov`] | `https://login.microsoftonline.us/` | `https://communication.azure.us/.default` | `.communication.azure.us` |
+//! | [`China`] | `https://login.chinacloudapi.cn/` | `https://communication.azure.cn/.default` | `.communication.azure.cn` |
+//!
+//! [`AzureCloud::Custom`] takes explicit values for private clouds.  A host
+//! that does not end with the cloud's endpoint suffix is logged as a warning
+//! at build time, since tokens for one cloud are rejected by another.
+//!
+//! ```rust,ignore
+//! let client = ACSClientBuilder::new()
+//!     .host("my-resource.communication.azure.us")
+//!     .cloud(AzureCloud::UsGov)
+//!     .managed_identity()
+//!     .build()?;
+//! ```
+//!
+//! [`ACSClientBuilder::cloud`]: crate::adapters::gateways::acs_email::ACSClientBuilder::cloud
+//! [`Public`]: AzureCloud::Public
+//! [`UsGov`]: AzureCloud::UsGov
+//! [`China`]: AzureCloud::China
+
+/// The Azure cloud an ACS resource lives in.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum AzureCloud {
+    /// Azure public cloud.
+    #[default]
+    Public,
+    /// Azure Government (US).
+    UsGov,
+    /// Azure China, operated by 21Vianet.
+    China,
+    /// A private or otherwise unlisted cloud.
+    Custom {
+        /// Entra ID login endpoint, e.g. `https://login.example.cloud/`.
+        authority_host: String,
+        /// ACS data-plane token scope, e.g. `https://communication.example.cloud/.default`.
+        token_scope: String,
+        /// Domain ACS endpoints end with, e.g. `.communication.example.cloud`.
+        endpoint_suffix: String,
+    },
+}
+
+impl AzureCloud {
+    /// Entra ID authority host for service principal and workload identity
+    /// tokens.
+    pub fn authority_host(&self) -> &str {
+        match self {
+            AzureCloud::Public => "https://login.microsoftonline.com/",
+            AzureCloud::UsGov => "https://login.microsoftonline.us/",
+            AzureCloud::China => "https://login.chinacloudapi.cn/",
+            AzureCloud::Custom { authority_host, .. } => authority_host,
+        }
+    }
+
+    /// Scope requested for ACS data-plane tokens.
+    pub fn token_scope(&self) -> &str {
+        match self {
+            AzureCloud::Public => "https://communication.azure.com/.default",
+            AzureCloud::UsGov => "https://communication.azure.us/.default",
+            AzureCloud::China => "https://communication.azure.cn/.default",
+            AzureCloud::Custom { token_scope, .. } => token_scope,
+        }
+    }
+
+    /// Domain suffix of ACS endpoints, including the leading dot.
+    pub fn endpoint_suffix(&self) -> &str {
+        match self {
+            AzureCloud::Public => ".communication.azure.com",
+            AzureCloud::UsGov => ".communication.azure.us",
+            AzureCloud::China => ".communication.azure.cn",
+            AzureCloud::Custom {
+                endpoint_suffix, ..
+            } => endpoint_suffix,
+        }
+    }
+
+    /// `true` when `host` (a bare host name) is an endpoint of this cloud.
+    ///
+    /// The suffix only matches at a label boundary, whether or not a custom
+    /// suffix was given with its leading dot.
+    pub fn is_endpoint(&self, host: &str) -> bool {
+        let suffix = self.endpoint_suffix().to_ascii_lowercase();
+        let domain = suffix.trim_start_matches('.');
+        let host = host.trim_end_matches('/').to_ascii_lowercase();
+        host == domain || host.ends_with(&format!(".{}", domain))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sovereign_clouds_use_their_own_hosts() {
+        assert_eq!(
+            AzureCloud::default().token_scope(),
+            "https://communication.azure.com/.default"
+        );
+        assert_eq!(
+            AzureCloud::UsGov.authority_host(),
+            "https://login.microsoftonline.us/"
+        );
+        assert_eq!(
+            AzureCloud::China.token_scope(),
+            "https://communication.azure.cn/.default"
+        );
+        let custom = AzureCloud::Custom {
+            authority_host: "https://login.example.cloud/".to_string(),
+            token_scope: "https://communication.example.cloud/.default".to_string(),
+            endpoint_suffix: "communication.example.cloud".to_string(),
+        };
+        assert_eq!(custom.authority_host(), "https://login.example.cloud/");
+        assert!(custom.is_endpoint("acs.communication.example.cloud"));
+        assert!(custom.is_endpoint("communication.example.cloud"));
+        assert!(!custom.is_endpoint("evilcommunication.example.cloud"));
+    }
+
+    #[test]
+    fn endpoint_suffix_is_matched_case_insensitively() {
+        assert!(AzureCloud::Public.is_endpoint("My-Resource.Communication.Azure.com"));
+        assert!(AzureCloud::UsGov.is_endpoint("my-resource.communication.azure.us"));
+        assert!(!AzureCloud::UsGov.is_endpoint("my-resource.communication.azure.com"));
+        assert!(!AzureCloud::China.is_endpoint("localhost:8080"));
+        assert!(!AzureCloud::Public.is_endpoint("evil-communication.azure.com"));
+    }
+}
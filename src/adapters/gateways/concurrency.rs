@@ -0,0 +1,261 @@
+//! Adaptive (AIMD) concurrency control for batch sends.
+//!
+//! [`send_emails_batch`] no longer fires every email at once.  Each send first
+//! takes a slot from an adaptive concurrency limit that reacts to how ACS
+//! answers:
+//!
+//! - **Slow start** — until the first throttling response, every accepted send
+//!   raises the limit by one, doubling it per round trip.
+//! - **Additive increase** — afterwards every accepted send raises the limit by
+//!   `1 / limit`, i.e. by one slot per round trip.
+//! - **Multiplicative decrease** — a `429` or `503` (including ones that are
+//!   retried internally) halves the limit.  Throttles from sends that started
+//!   before the last decrease are ignored, so one burst of rejections shrinks
+//!   the limit once instead of collapsing it to the floor.
+//!
+//! The limit always stays within the `[floor, ceiling]` range configured with
+//! [`ACSClientBuilder::batch_concurrency`].  It is shared by every clone of an
+//! [`ACSClient`], and [`ACSClient::batch_concurrency`] returns a
+//! [`ConcurrencySnapshot`] for capacity monitoring.
+//!
+//! [`send_emails_batch`]: crate::adapters::gateways::acs_email::ACSClient::send_emails_batch
+//! [`ACSClientBuilder::batch_concurrency`]: crate::adapters::gateways::acs_email::ACSClientBuilder::batch_concurrency
+//! [`ACSClient`]: crate::adapters::gateways::acs_email::ACSClient
+//! [`ACSClient::batch_concurrency`]: crate::adapters::gateways::acs_email::ACSClient::batch_concurrency
+
+use std::sync::{Arc, Mutex};
+use tokio::sync::Notify;
+use tracing::debug;
+
+/// Default lower bound of the batch concurrency limit.
+pub const DEFAULT_CONCURRENCY_FLOOR: usize = 1;
+/// Default upper bound of the batch concurrency limit.
+pub const DEFAULT_CONCURRENCY_CEILING: usize = 32;
+
+/// Point-in-time view of the adaptive batch concurrency limit.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ConcurrencySnapshot {
+    /// Current limit; sends beyond it wait for a slot.  Fractional while the
+    /// limit is growing additively — the usable limit is its integer part.
+    pub limit: f64,
+    /// Sends currently holding a slot.
+    pub in_flight: usize,
+    /// Configured lower bound of `limit`.
+    pub floor: usize,
+    /// Configured upper bound of `limit`.
+    pub ceiling: usize,
+    /// Number of times the limit has been decreased because of throttling.
+    pub decreases: u64,
+}
+
+#[derive(Debug)]
+struct State {
+    limit: f64,
+    in_flight: usize,
+    slow_start: bool,
+    decreases: u64,
+}
+
+#[derive(Debug)]
+struct Inner {
+    floor: usize,
+    ceiling: usize,
+    state: Mutex<State>,
+    released: Notify,
+}
+
+/// AIMD concurrency limit shared by the clones of an `ACSClient`.
+#[derive(Clone, Debug)]
+pub(crate) struct AdaptiveConcurrency {
+    inner: Arc<Inner>,
+}
+
+/// A slot taken from an [`AdaptiveConcurrency`] limit; released on drop.
+#[derive(Debug)]
+pub(crate) struct ConcurrencyPermit {
+    inner: Arc<Inner>,
+    /// Value of `decreases` when the slot was taken.
+    generation: u64,
+}
+
+impl AdaptiveConcurrency {
+    /// Create a limit that starts at `floor` and never leaves `[floor, ceiling]`.
+    ///
+    /// Callers validate `1 <= floor <= ceiling`.
+    pub(crate) fn new(floor: usize, ceiling: usize) -> Self {
+        AdaptiveConcurrency {
+            inner: Arc::new(Inner {
+                floor,
+                ceiling,
+                state: Mutex::new(State {
+                    limit: floor as f64,
+                    in_flight: 0,
+                    slow_start: true,
+                    decreases: 0,
+                }),
+                released: Notify::new(),
+            }),
+        }
+    }
+
+    pub(crate) fn snapshot(&self) -> ConcurrencySnapshot {
+        let state = self.inner.state.lock().unwrap();
+        ConcurrencySnapshot {
+            limit: state.limit,
+            in_flight: state.in_flight,
+            floor: self.inner.floor,
+            ceiling: self.inner.ceiling,
+            decreases: state.decreases,
+        }
+    }
+
+    /// Wait for a free slot under the current limit.
+    pub(crate) async fn acquire(&self) -> ConcurrencyPermit {
+        loop {
+            let released = self.inner.released.notified();
+            tokio::pin!(released);
+            released.as_mut().enable();
+            {
+                let mut state = self.inner.state.lock().unwrap();
+                if state.in_flight < state.limit as usize {
+                    state.in_flight += 1;
+                    return ConcurrencyPermit {
+                        inner: Arc::clone(&self.inner),
+                        generation: state.decreases,
+                    };
+                }
+            }
+            released.await;
+        }
+    }
+}
+
+impl ConcurrencyPermit {
+    /// Record an accepted send and grow the limit.
+    pub(crate) fn succeeded(&self) {
+        let mut state = self.inner.state.lock().unwrap();
+        let step = if state.slow_start {
+            1.0
+        } else {
+            1.0 / state.limit
+        };
+        let grown = (state.limit + step).min(self.inner.ceiling as f64);
+        let gained_slot = grown as usize > state.limit as usize;
+        state.limit = grown;
+        drop(state);
+        if gained_slot {
+            self.inner.released.notify_waiters();
+        }
+    }
+
+    /// Record a throttling response and halve the limit, unless the limit has
+    /// already been decreased since this slot was taken.
+    pub(crate) fn throttled(&self) {
+        let mut state = self.inner.state.lock().unwrap();
+        if state.decreases != self.generation {
+            return;
+        }
+        state.slow_start = false;
+        state.decreases += 1;
+        state.limit = (state.limit / 2.0).floor().max(self.inner.floor as f64);
+        debug!(
+            limit = state.limit,
+            "Throttled; decreasing batch concurrency"
+        );
+    }
+}
+
+impl Drop for ConcurrencyPermit {
+    fn drop(&mut self) {
+        self.inner.state.lock().unwrap().in_flight -= 1;
+        self.inner.released.notify_waiters();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn starts_at_floor() {
+        let snapshot = AdaptiveConcurrency::new(2, 10).snapshot();
+        assert_eq!(snapshot.limit, 2.0);
+        assert_eq!(snapshot.in_flight, 0);
+        assert_eq!((snapshot.floor, snapshot.ceiling), (2, 10));
+    }
+
+    #[tokio::test]
+    async fn slow_start_grows_by_one_per_success_up_to_ceiling() {
+        let limiter = AdaptiveConcurrency::new(1, 4);
+        for _ in 0..10 {
+            limiter.acquire().await.succeeded();
+        }
+        assert_eq!(limiter.snapshot().limit, 4.0);
+    }
+
+    #[tokio::test]
+    async fn throttle_halves_limit_and_ends_slow_start() {
+        let limiter = AdaptiveConcurrency::new(1, 64);
+        for _ in 0..15 {
+            limiter.acquire().await.succeeded();
+        }
+        assert_eq!(limiter.snapshot().limit, 16.0);
+
+        limiter.acquire().await.throttled();
+        assert_eq!(limiter.snapshot().limit, 8.0);
+        assert_eq!(limiter.snapshot().decreases, 1);
+
+        limiter.acquire().await.succeeded();
+        assert_eq!(limiter.snapshot().limit, 8.125);
+    }
+
+    #[tokio::test]
+    async fn limit_never_drops_below_floor() {
+        let limiter = AdaptiveConcurrency::new(3, 10);
+        for _ in 0..5 {
+            limiter.acquire().await.throttled();
+        }
+        assert_eq!(limiter.snapshot().limit, 3.0);
+    }
+
+    #[tokio::test]
+    async fn one_burst_of_throttles_decreases_once() {
+        let limiter = AdaptiveConcurrency::new(1, 64);
+        for _ in 0..15 {
+            limiter.acquire().await.succeeded();
+        }
+        let mut permits = Vec::new();
+        for _ in 0..4 {
+            permits.push(limiter.acquire().await);
+        }
+        for permit in &permits {
+            permit.throttled();
+        }
+        assert_eq!(limiter.snapshot().limit, 8.0);
+        assert_eq!(limiter.snapshot().decreases, 1);
+    }
+
+    #[tokio::test]
+    async fn acquire_waits_for_release_when_limit_reached() {
+        let limiter = AdaptiveConcurrency::new(1, 1);
+        let first = limiter.acquire().await;
+        assert_eq!(limiter.snapshot().in_flight, 1);
+
+        let waiter = {
+            let limiter = limiter.clone();
+            tokio::spawn(async move { limiter.acquire().await })
+        };
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert!(!waiter.is_finished());
+
+        drop(first);
+        let second = tokio::time::timeout(Duration::from_secs(1), waiter)
+            .await
+            .expect("waiter should be released")
+            .unwrap();
+        assert_eq!(limiter.snapshot().in_flight, 1);
+        drop(second);
+        assert_eq!(limiter.snapshot().in_flight, 0);
+    }
+}
@@ -0,0 +1,567 @@
+//! Ordered chain of credential sources, tried until one yields a token.
+//!
+//! The same binary often runs with different identities: a developer's
+//! `az login` locally, a workload identity on AKS, a managed identity on a VM.
+//! [`ACSClientBuilder::default_credential_chain`] tries, in order:
+//!
+//! 1. [`CredentialSource::Environment`] — a service principal from
+//!    `AZURE_TENANT_ID`, `AZURE_CLIENT_ID` and either `AZURE_CLIENT_SECRET` or
+//!    `AZURE_CLIENT_CERTIFICATE_PATH` (+ `AZURE_CLIENT_CERTIFICATE_PASSWORD`,
+//!    with the `certificate` Cargo feature);
+//! 2. [`CredentialSource::WorkloadIdentity`] — `AZURE_TENANT_ID`,
+//!    `AZURE_CLIENT_ID` and `AZURE_FEDERATED_TOKEN_FILE`;
+//! 3. [`CredentialSource::ManagedIdentity`] — the system-assigned identity;
+//! 4. [`CredentialSource::AzureCli`] — `az account get-access-token`.
+//!
+//! [`ACSClientBuilder::credential_chain`] takes a [`CredentialChain`] with any
+//! order or subset of sources.  Every attempt is logged at `debug` level and
+//! the selected source at `info` level.  The first source that returns a
+//! token is used for later refreshes too; if it fails, the next refresh walks
+//! the chain again.  When every source fails, the error lists why each one
+//! did.
+//!
+//! ```rust,ignore
+//! let client = ACSClientBuilder::new()
+//!     .host(&host)
+//!     .default_credential_chain()
+//!     .build()?;
+//!
+//! // Only the CLI locally and a user-assigned identity in production.
+//! let chain = CredentialChain::new(vec![
+//!     CredentialSource::ManagedIdentity(ManagedIdentityId::ClientId(uami)),
+//!     CredentialSource::AzureCli,
+//! ]);
+//! let client = ACSClientBuilder::new().host(&host).credential_chain(chain).build()?;
+//! ```
+//!
+//! [`ACSClientBuilder::default_credential_chain`]: crate::adapters::gateways::acs_email::ACSClientBuilder::default_credential_chain
+//! [`ACSClientBuilder::credential_chain`]: crate::adapters::gateways::acs_email::ACSClientBuilder::credential_chain
+
+#[cfg(feature = "certificate")]
+use crate::adapters::gateways::credentials::ClientCertificateCredential;
+use crate::adapters::gateways::credentials::{
+    ManagedIdentityCredential, ManagedIdentityId, WorkloadIdentityCredential,
+};
+use azure_core::auth::{AccessToken, Secret, TokenCredential};
+use azure_core::error::{Error, ErrorKind};
+use azure_core::HttpClient;
+use azure_identity::ClientSecretCredential;
+use reqwest::Client;
+use serde::Deserialize;
+use std::fmt;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+use time::OffsetDateTime;
+use tokio::sync::Mutex;
+use tracing::{debug, info};
+use url::Url;
+
+/// Connect timeout for managed identity endpoints probed by the chain, so a
+/// machine without one fails over quickly.
+const MANAGED_IDENTITY_PROBE_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Default Azure CLI program.
+const AZURE_CLI: &str = "az";
+
+/// One place a [`CredentialChain`] looks for a token.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CredentialSource {
+    /// Service principal from `AZURE_TENANT_ID`, `AZURE_CLIENT_ID` and
+    /// `AZURE_CLIENT_SECRET` or `AZURE_CLIENT_CERTIFICATE_PATH` (the latter
+    /// with the `certificate` feature).
+    Environment,
+    /// Entra Workload ID from `AZURE_TENANT_ID`, `AZURE_CLIENT_ID` and
+    /// `AZURE_FEDERATED_TOKEN_FILE`.
+    WorkloadIdentity,
+    /// A managed identity.
+    ManagedIdentity(ManagedIdentityId),
+    /// The account logged in with `az login`.
+    AzureCli,
+}
+
+impl fmt::Display for CredentialSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CredentialSource::Environment => write!(f, "environment"),
+            CredentialSource::WorkloadIdentity => write!(f, "workload identity"),
+            CredentialSource::ManagedIdentity(_) => write!(f, "managed identity"),
+            CredentialSource::AzureCli => write!(f, "Azure CLI"),
+        }
+    }
+}
+
+/// Ordered list of credential sources for
+/// [`ACSClientBuilder::credential_chain`].
+///
+/// [`ACSClientBuilder::credential_chain`]: crate::adapters::gateways::acs_email::ACSClientBuilder::credential_chain
+#[derive(Clone, Debug)]
+pub struct CredentialChain {
+    sources: Vec<CredentialSource>,
+    azure_cli: PathBuf,
+}
+
+impl Default for CredentialChain {
+    /// Environment → workload identity → system-assigned managed identity →
+    /// Azure CLI.
+    fn default() -> Self {
+        CredentialChain::new(vec![
+            CredentialSource::Environment,
+            CredentialSource::WorkloadIdentity,
+            CredentialSource::ManagedIdentity(ManagedIdentityId::SystemAssigned),
+            CredentialSource::AzureCli,
+        ])
+    }
+}
+
+impl CredentialChain {
+    /// Try `sources` in order.
+    pub fn new(sources: Vec<CredentialSource>) -> Self {
+        CredentialChain {
+            sources,
+            azure_cli: PathBuf::from(AZURE_CLI),
+        }
+    }
+
+    /// Run `program` instead of `az` for [`CredentialSource::AzureCli`].
+    pub fn azure_cli_command(mut self, program: impl Into<PathBuf>) -> Self {
+        self.azure_cli = program.into();
+        self
+    }
+
+    /// The sources, in the order they are tried.
+    pub fn sources(&self) -> &[CredentialSource] {
+        &self.sources
+    }
+}
+
+type EnvLookup = Arc<dyn Fn(&str) -> Option<String> + Send + Sync>;
+
+/// [`TokenCredential`] walking a [`CredentialChain`].
+pub(crate) struct ChainedCredential {
+    chain: CredentialChain,
+    http_client: Client,
+    probe_client: Client,
+    authority_host: Url,
+    managed_identity_endpoint: Option<Url>,
+    env: EnvLookup,
+    selected: Mutex<Option<(CredentialSource, Arc<dyn TokenCredential>)>>,
+}
+
+impl fmt::Debug for ChainedCredential {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChainedCredential")
+            .field("chain", &self.chain)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ChainedCredential {
+    pub(crate) fn new(
+        chain: CredentialChain,
+        http_client: Client,
+        authority_host: Url,
+        managed_identity_endpoint: Option<Url>,
+    ) -> Result<Self, String> {
+        let probe_client = Client::builder()
+            .connect_timeout(MANAGED_IDENTITY_PROBE_TIMEOUT)
+            .build()
+            .map_err(|e| format!("Failed to build HTTP client: {}", e))?;
+        Ok(ChainedCredential {
+            chain,
+            http_client,
+            probe_client,
+            authority_host,
+            managed_identity_endpoint,
+            env: Arc::new(|name| std::env::var(name).ok()),
+            selected: Mutex::new(None),
+        })
+    }
+
+    /// Read environment variables through `env` instead of the process
+    /// environment.
+    #[cfg(test)]
+    pub(crate) fn env(
+        mut self,
+        env: impl Fn(&str) -> Option<String> + Send + Sync + 'static,
+    ) -> Self {
+        self.env = Arc::new(env);
+        self
+    }
+
+    fn var(&self, name: &str) -> Result<String, String> {
+        (self.env)(name)
+            .filter(|value| !value.is_empty())
+            .ok_or_else(|| format!("{} is not set", name))
+    }
+
+    /// Service principal credential from `AZURE_CLIENT_CERTIFICATE_PATH`.
+    #[cfg(feature = "certificate")]
+    fn certificate_credential(
+        &self,
+        tenant_id: &str,
+        client_id: &str,
+        path: String,
+    ) -> Result<Arc<dyn TokenCredential>, String> {
+        let credential = ClientCertificateCredential::new(
+            self.http_client.clone(),
+            &self.authority_host,
+            tenant_id,
+            client_id,
+            path,
+        )?;
+        Ok(Arc::new(
+            match self.var("AZURE_CLIENT_CERTIFICATE_PASSWORD") {
+                Ok(password) => credential.password(&password),
+                Err(_) => credential,
+            },
+        ))
+    }
+
+    /// Certificates are not supported without the `certificate` feature.
+    #[cfg(not(feature = "certificate"))]
+    fn certificate_credential(
+        &self,
+        _tenant_id: &str,
+        _client_id: &str,
+        _path: String,
+    ) -> Result<Arc<dyn TokenCredential>, String> {
+        Err(
+            "AZURE_CLIENT_CERTIFICATE_PATH is set but the `certificate` feature is disabled"
+                .to_string(),
+        )
+    }
+
+    /// The credential for `source`, or why it is not configured.
+    fn credential(&self, source: &CredentialSource) -> Result<Arc<dyn TokenCredential>, String> {
+        match source {
+            CredentialSource::Environment => {
+                let tenant_id = self.var("AZURE_TENANT_ID")?;
+                let client_id = self.var("AZURE_CLIENT_ID")?;
+                if let Ok(secret) = self.var("AZURE_CLIENT_SECRET") {
+                    return Ok(Arc::new(ClientSecretCredential::new(
+                        Arc::new(self.http_client.clone()) as Arc<dyn HttpClient>,
+                        self.authority_host.clone(),
+                        tenant_id,
+                        client_id,
+                        secret,
+                    )));
+                }
+                let path = self.var("AZURE_CLIENT_CERTIFICATE_PATH").map_err(|_| {
+                    "neither AZURE_CLIENT_SECRET nor AZURE_CLIENT_CERTIFICATE_PATH is set"
+                        .to_string()
+                })?;
+                self.certificate_credential(&tenant_id, &client_id, path)
+            }
+            CredentialSource::WorkloadIdentity => Ok(Arc::new(WorkloadIdentityCredential::new(
+                self.http_client.clone(),
+                &self.authority_host,
+                &self.var("AZURE_TENANT_ID")?,
+                &self.var("AZURE_CLIENT_ID")?,
+                self.var("AZURE_FEDERATED_TOKEN_FILE")?,
+            )?)),
+            CredentialSource::ManagedIdentity(id) => {
+                let credential =
+                    ManagedIdentityCredential::new(self.probe_client.clone(), id.clone());
+                Ok(Arc::new(match &self.managed_identity_endpoint {
+                    Some(endpoint) => credential.endpoint(endpoint.clone()),
+                    None => credential,
+                }))
+            }
+            CredentialSource::AzureCli => Ok(Arc::new(AzureCliCredential::new(
+                self.chain.azure_cli.clone(),
+            ))),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl TokenCredential for ChainedCredential {
+    async fn get_token(&self, scopes: &[&str]) -> azure_core::Result<AccessToken> {
+        let mut selected = self.selected.lock().await;
+        if let Some((source, credential)) = selected.as_ref() {
+            match credential.get_token(scopes).await {
+                Ok(token) => return Ok(token),
+                Err(e) => {
+                    debug!(%source, error = %e, "Selected credential source failed; retrying the chain");
+                    *selected = None;
+                }
+            }
+        }
+        let mut failures = Vec::new();
+        for source in &self.chain.sources {
+            debug!(%source, "Trying credential source");
+            let attempt = match self.credential(source) {
+                Ok(credential) => match credential.get_token(scopes).await {
+                    Ok(token) => Ok((credential, token)),
+                    Err(e) => Err(e.to_string()),
+                },
+                Err(reason) => Err(reason),
+            };
+            match attempt {
+                Ok((credential, token)) => {
+                    info!(%source, "Authenticated with credential source");
+                    *selected = Some((source.clone(), credential));
+                    return Ok(token);
+                }
+                Err(reason) => {
+                    debug!(%source, %reason, "Credential source failed");
+                    failures.push(format!("{}: {}", source, reason));
+                }
+            }
+        }
+        Err(Error::message(
+            ErrorKind::Credential,
+            if failures.is_empty() {
+                "the credential chain has no sources".to_string()
+            } else {
+                format!("no credential source succeeded ({})", failures.join("; "))
+            },
+        ))
+    }
+
+    async fn clear_cache(&self) -> azure_core::Result<()> {
+        match self.selected.lock().await.as_ref() {
+            Some((_, credential)) => credential.clear_cache().await,
+            None => Ok(()),
+        }
+    }
+}
+
+/// Credential running `az account get-access-token`.
+///
+/// Requires Azure CLI 2.54 or later, which reports the token expiry as a
+/// Unix timestamp.
+#[derive(Clone, Debug)]
+pub struct AzureCliCredential {
+    program: PathBuf,
+}
+
+impl AzureCliCredential {
+    /// Run `program` (normally `az`).
+    ///
+    /// On Windows the program is started through `cmd /C`, so the `az.cmd`
+    /// batch wrapper the Azure CLI installs is found on `PATH`.
+    pub fn new(program: impl Into<PathBuf>) -> Self {
+        AzureCliCredential {
+            program: program.into(),
+        }
+    }
+}
+
+impl Default for AzureCliCredential {
+    fn default() -> Self {
+        AzureCliCredential::new(AZURE_CLI)
+    }
+}
+
+#[derive(Deserialize)]
+struct CliToken {
+    #[serde(rename = "accessToken")]
+    access_token: String,
+    expires_on: Option<i64>,
+}
+
+#[async_trait::async_trait]
+impl TokenCredential for AzureCliCredential {
+    async fn get_token(&self, scopes: &[&str]) -> azure_core::Result<AccessToken> {
+        let cli_err = |message: String| Error::message(ErrorKind::Credential, message);
+        let [scope] = scopes else {
+            return Err(cli_err("Azure CLI supports exactly one scope".to_string()));
+        };
+        let resource = scope.strip_suffix("/.default").unwrap_or(scope);
+        let mut command = if cfg!(windows) {
+            let mut command = tokio::process::Command::new("cmd");
+            command.arg("/C").arg(&self.program);
+            command
+        } else {
+            tokio::process::Command::new(&self.program)
+        };
+        let output = command
+            .args([
+                "account",
+                "get-access-token",
+                "--output",
+                "json",
+                "--resource",
+                resource,
+            ])
+            .output()
+            .await
+            .map_err(|e| cli_err(format!("failed to run {}: {}", self.program.display(), e)))?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(cli_err(format!(
+                "{} exited with {}: {}",
+                self.program.display(),
+                output.status,
+                stderr.trim()
+            )));
+        }
+        let token: CliToken = serde_json::from_slice(&output.stdout)
+            .map_err(|e| cli_err(format!("unexpected Azure CLI output: {}", e)))?;
+        let expires_on = token
+            .expires_on
+            .and_then(|secs| OffsetDateTime::from_unix_timestamp(secs).ok())
+            .ok_or_else(|| {
+                cli_err("Azure CLI did not report expires_on; upgrade to 2.54 or later".to_string())
+            })?;
+        Ok(AccessToken::new(
+            Secret::new(token.access_token),
+            expires_on,
+        ))
+    }
+
+    async fn clear_cache(&self) -> azure_core::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const SCOPE: &str = "https://communication.azure.com/.default";
+
+    /// An executable script printing `stdout` and exiting with `code`.
+    #[cfg(unix)]
+    fn fake_az(dir: &std::path::Path, stdout: &str, code: i32) -> PathBuf {
+        use std::os::unix::fs::PermissionsExt;
+
+        let program = dir.join("az");
+        std::fs::write(
+            &program,
+            format!(
+                "#!/bin/sh\necho \"$@\" > \"$0.args\"\ncat <<'EOF'\n{stdout}\nEOF\nexit {code}\n"
+            ),
+        )
+        .unwrap();
+        std::fs::set_permissions(&program, std::fs::Permissions::from_mode(0o755)).unwrap();
+        program
+    }
+
+    fn chained(
+        chain: CredentialChain,
+        authority: &str,
+        vars: &[(&str, &str)],
+    ) -> ChainedCredential {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        ChainedCredential::new(chain, Client::new(), Url::parse(authority).unwrap(), None)
+            .unwrap()
+            .env(move |name| vars.get(name).cloned())
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn azure_cli_token_is_parsed() {
+        let dir = tempfile::tempdir().unwrap();
+        let az = fake_az(
+            dir.path(),
+            r#"{"accessToken": "cli-token", "expires_on": 4102444800, "tokenType": "Bearer"}"#,
+            0,
+        );
+        let token = AzureCliCredential::new(&az)
+            .get_token(&[SCOPE])
+            .await
+            .unwrap();
+        assert_eq!(token.token.secret(), "cli-token");
+        assert_eq!(token.expires_on.unix_timestamp(), 4_102_444_800);
+        let args = std::fs::read_to_string(dir.path().join("az.args")).unwrap();
+        assert_eq!(
+            args.trim(),
+            "account get-access-token --output json --resource https://communication.azure.com"
+        );
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn first_working_source_is_selected_and_kept() {
+        let dir = tempfile::tempdir().unwrap();
+        let az = fake_az(
+            dir.path(),
+            r#"{"accessToken": "cli-token", "expires_on": 4102444800}"#,
+            0,
+        );
+        let credential = chained(
+            CredentialChain::new(vec![
+                CredentialSource::Environment,
+                CredentialSource::WorkloadIdentity,
+                CredentialSource::AzureCli,
+            ])
+            .azure_cli_command(&az),
+            "https://login.example.test/",
+            &[],
+        );
+        assert_eq!(
+            credential.get_token(&[SCOPE]).await.unwrap().token.secret(),
+            "cli-token"
+        );
+        let selected = credential.selected.lock().await;
+        assert_eq!(selected.as_ref().unwrap().0, CredentialSource::AzureCli);
+    }
+
+    #[tokio::test]
+    async fn environment_service_principal_wins_over_later_sources() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/tenant-1/oauth2/v2.0/token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "token_type": "Bearer",
+                "expires_in": 3600,
+                "access_token": "env-token"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let credential = chained(
+            CredentialChain::default().azure_cli_command("/nonexistent/az"),
+            &server.uri(),
+            &[
+                ("AZURE_TENANT_ID", "tenant-1"),
+                ("AZURE_CLIENT_ID", "app-1"),
+                ("AZURE_CLIENT_SECRET", "secret-1"),
+            ],
+        );
+        assert_eq!(
+            credential.get_token(&[SCOPE]).await.unwrap().token.secret(),
+            "env-token"
+        );
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn every_failure_is_reported_when_all_sources_fail() {
+        let dir = tempfile::tempdir().unwrap();
+        let az = fake_az(dir.path(), "", 1);
+        let credential = chained(
+            CredentialChain::new(vec![
+                CredentialSource::Environment,
+                CredentialSource::WorkloadIdentity,
+                CredentialSource::AzureCli,
+            ])
+            .azure_cli_command(&az),
+            "https://login.example.test/",
+            &[
+                ("AZURE_TENANT_ID", "tenant-1"),
+                ("AZURE_CLIENT_ID", "app-1"),
+            ],
+        );
+        let message = credential
+            .get_token(&[SCOPE])
+            .await
+            .unwrap_err()
+            .to_string();
+        assert!(message.contains(
+            "environment: neither AZURE_CLIENT_SECRET nor AZURE_CLIENT_CERTIFICATE_PATH is set"
+        ));
+        assert!(message.contains("workload identity: AZURE_FEDERATED_TOKEN_FILE is not set"));
+        assert!(message.contains("Azure CLI:"));
+        assert!(message.contains("exited with"));
+    }
+}
@@ -0,0 +1,878 @@
+//! Token credentials beyond the ones `azure_identity` provides.
+//!
+//! # Managed identity and workload identity
+//!
+//! [`ManagedIdentityCredential`] requests tokens for a system- or
+//! user-assigned managed identity ([`ManagedIdentityId`]) from the Azure
+//! Instance Metadata Service, or from the App Service / Container Apps
+//! identity endpoint when `IDENTITY_ENDPOINT` and `IDENTITY_HEADER` are set.
+//! [`WorkloadIdentityCredential`] exchanges a federated token file (Entra
+//! Workload ID on AKS, `AZURE_FEDERATED_TOKEN_FILE`) for an Entra ID token,
+//! re-reading the file on every fetch so projected-token rotation is picked
+//! up.  Both are selected on the builder:
+//!
+//! ```rust,ignore
+//! let client = ACSClientBuilder::new()
+//!     .host(&host)
+//!     .managed_identity_client_id(&user_assigned_client_id)
+//!     .build()?;
+//!
+//! let client = ACSClientBuilder::new()
+//!     .host(&host)
+//!     .workload_identity(&tenant_id, &client_id, std::env::var("AZURE_FEDERATED_TOKEN_FILE")?)
+//!     .build()?;
+//! ```
+//!
+//! # Client certificate
+//!
+//! `ClientCertificateCredential` authenticates a service principal with a
+//! certificate instead of a secret: a client assertion JWT is signed with the
+//! certificate's private key and exchanged for a token.  The certificate file
+//! (PEM with the private key, or PKCS#12 / PFX) is re-read on every token
+//! fetch, so a certificate rotated on disk is used from the next refresh.
+//! It needs OpenSSL and is only built with the `certificate` Cargo feature.
+//!
+//! # Caller-supplied credentials
+//!
+//! Besides the built-in options, an [`ACSClientBuilder`] accepts any
+//! [`TokenCredential`]:
+//!
+//! - [`ACSClientBuilder::token_credential`] — an `azure_core` credential
+//!   object, for example one reading tokens from a sidecar.
+//! - [`ACSClientBuilder::static_token`] — a fixed token ([`StaticTokenCredential`]),
+//!   for short-lived tools and tests.
+//! - [`ACSClientBuilder::token_provider`] — an async closure returning an
+//!   [`AccessToken`] ([`TokenProviderCredential`]).
+//!
+//! All of them go through the client's `TokenCache`, so the credential is only
+//! asked for a token when the cached one nears expiry or ACS rejects it.
+//!
+//! ```rust,ignore
+//! let client = ACSClientBuilder::new()
+//!     .host(&host)
+//!     .token_provider(|| async {
+//!         let (token, expires_on) = sidecar.fetch_token().await?;
+//!         Ok::<_, SidecarError>(AccessToken::new(Secret::new(token), expires_on))
+//!     })
+//!     .build()?;
+//! ```
+//!
+//! [`ACSClientBuilder`]: crate::adapters::gateways::acs_email::ACSClientBuilder
+//! [`ACSClientBuilder::token_credential`]: crate::adapters::gateways::acs_email::ACSClientBuilder::token_credential
+//! [`ACSClientBuilder::static_token`]: crate::adapters::gateways::acs_email::ACSClientBuilder::static_token
+//! [`ACSClientBuilder::token_provider`]: crate::adapters::gateways::acs_email::ACSClientBuilder::token_provider
+
+use azure_core::auth::{AccessToken, Secret, TokenCredential};
+use azure_core::error::{Error, ErrorKind};
+#[cfg(feature = "certificate")]
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+#[cfg(feature = "certificate")]
+use base64::Engine;
+#[cfg(feature = "certificate")]
+use openssl::hash::MessageDigest;
+#[cfg(feature = "certificate")]
+use openssl::pkcs12::Pkcs12;
+#[cfg(feature = "certificate")]
+use openssl::pkey::{PKey, Private};
+#[cfg(feature = "certificate")]
+use openssl::sign::Signer;
+#[cfg(feature = "certificate")]
+use openssl::x509::X509;
+use reqwest::Client;
+use serde::Deserialize;
+use std::fmt;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use time::OffsetDateTime;
+use url::Url;
+#[cfg(feature = "certificate")]
+use uuid::Uuid;
+
+/// Azure Instance Metadata Service token endpoint.
+const IMDS_ENDPOINT: &str = "http://169.254.169.254/metadata/identity/oauth2/token";
+const IMDS_API_VERSION: &str = "2018-02-01";
+/// App Service / Container Apps identity endpoint API version.
+const IDENTITY_ENDPOINT_API_VERSION: &str = "2019-08-01";
+const IDENTITY_ENDPOINT_ENV: &str = "IDENTITY_ENDPOINT";
+const IDENTITY_HEADER_ENV: &str = "IDENTITY_HEADER";
+const CLIENT_ASSERTION_TYPE: &str = "urn:ietf:params:oauth:client-assertion-type:jwt-bearer";
+/// Validity of a signed client assertion.
+#[cfg(feature = "certificate")]
+const CLIENT_ASSERTION_LIFETIME: time::Duration = time::Duration::minutes(10);
+
+/// Validity reported for a [`StaticTokenCredential`] token; long enough that
+/// the cache never refreshes it.
+const STATIC_TOKEN_LIFETIME: time::Duration = time::Duration::days(365);
+
+/// Credential that always returns the same bearer token.
+///
+/// The token is never refreshed; once ACS rejects it every request fails
+/// with `401 Unauthorized`.
+#[derive(Clone)]
+pub struct StaticTokenCredential {
+    token: Secret,
+}
+
+impl fmt::Debug for StaticTokenCredential {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StaticTokenCredential")
+            .finish_non_exhaustive()
+    }
+}
+
+impl StaticTokenCredential {
+    /// Authenticate every request with `token`.
+    pub fn new(token: &str) -> Self {
+        StaticTokenCredential {
+            token: Secret::new(token.to_string()),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl TokenCredential for StaticTokenCredential {
+    async fn get_token(&self, _scopes: &[&str]) -> azure_core::Result<AccessToken> {
+        Ok(AccessToken::new(
+            self.token.clone(),
+            OffsetDateTime::now_utc() + STATIC_TOKEN_LIFETIME,
+        ))
+    }
+
+    async fn clear_cache(&self) -> azure_core::Result<()> {
+        Ok(())
+    }
+}
+
+fn credential_err(message: impl Into<String>) -> Error {
+    Error::message(ErrorKind::Credential, message.into())
+}
+
+/// Which managed identity a [`ManagedIdentityCredential`] authenticates as.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ManagedIdentityId {
+    /// The identity assigned to the resource itself.
+    SystemAssigned,
+    /// A user-assigned identity, by client (application) ID.
+    ClientId(String),
+    /// A user-assigned identity, by Azure resource ID.
+    ResourceId(String),
+}
+
+/// Managed identity credential supporting user-assigned identities.
+#[derive(Clone)]
+pub struct ManagedIdentityCredential {
+    http_client: Client,
+    endpoint: Url,
+    /// `X-IDENTITY-HEADER` secret; set when `endpoint` is an App Service style
+    /// identity endpoint rather than IMDS.
+    identity_header: Option<String>,
+    id: ManagedIdentityId,
+}
+
+impl fmt::Debug for ManagedIdentityCredential {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ManagedIdentityCredential")
+            .field("endpoint", &self.endpoint.as_str())
+            .field("id", &self.id)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ManagedIdentityCredential {
+    /// Authenticate as `id`, using the identity endpoint from
+    /// `IDENTITY_ENDPOINT` / `IDENTITY_HEADER` when both are set and IMDS
+    /// otherwise.
+    pub fn new(http_client: Client, id: ManagedIdentityId) -> Self {
+        let identity_endpoint = std::env::var(IDENTITY_ENDPOINT_ENV)
+            .ok()
+            .and_then(|endpoint| Url::parse(&endpoint).ok());
+        let identity_header = std::env::var(IDENTITY_HEADER_ENV).ok();
+        let (endpoint, identity_header) = match (identity_endpoint, identity_header) {
+            (Some(endpoint), Some(header)) => (endpoint, Some(header)),
+            _ => (Url::parse(IMDS_ENDPOINT).expect("valid IMDS URL"), None),
+        };
+        ManagedIdentityCredential {
+            http_client,
+            endpoint,
+            identity_header,
+            id,
+        }
+    }
+
+    /// Request tokens from `endpoint` with the IMDS protocol instead.
+    pub fn endpoint(mut self, endpoint: Url) -> Self {
+        self.endpoint = endpoint;
+        self.identity_header = None;
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl TokenCredential for ManagedIdentityCredential {
+    async fn get_token(&self, scopes: &[&str]) -> azure_core::Result<AccessToken> {
+        let [scope] = scopes else {
+            return Err(credential_err(
+                "managed identity supports exactly one scope",
+            ));
+        };
+        let resource = scope.strip_suffix("/.default").unwrap_or(scope);
+        let (api_version, resource_id_param) = match self.identity_header {
+            Some(_) => (IDENTITY_ENDPOINT_API_VERSION, "mi_res_id"),
+            None => (IMDS_API_VERSION, "msi_res_id"),
+        };
+        let mut query = vec![("api-version", api_version), ("resource", resource)];
+        match &self.id {
+            ManagedIdentityId::SystemAssigned => {}
+            ManagedIdentityId::ClientId(client_id) => query.push(("client_id", client_id)),
+            ManagedIdentityId::ResourceId(resource_id) => {
+                query.push((resource_id_param, resource_id))
+            }
+        }
+        let mut request = self.http_client.get(self.endpoint.clone()).query(&query);
+        request = match &self.identity_header {
+            Some(secret) => request.header("X-IDENTITY-HEADER", secret),
+            None => request.header("Metadata", "true"),
+        };
+        let response = request
+            .send()
+            .await
+            .map_err(|e| credential_err(format!("managed identity endpoint unreachable: {}", e)))?;
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        if !status.is_success() {
+            return Err(credential_err(format!(
+                "managed identity endpoint returned {}: {}",
+                status, body
+            )));
+        }
+        let token: ManagedIdentityToken = serde_json::from_str(&body)
+            .map_err(|e| credential_err(format!("invalid managed identity token: {}", e)))?;
+        let expires_on = match &token.expires_on {
+            serde_json::Value::Number(n) => n.as_i64(),
+            serde_json::Value::String(s) => s.parse().ok(),
+            _ => None,
+        }
+        .and_then(|secs| OffsetDateTime::from_unix_timestamp(secs).ok())
+        .ok_or_else(|| credential_err("invalid managed identity token expiry"))?;
+        Ok(AccessToken::new(
+            Secret::new(token.access_token),
+            expires_on,
+        ))
+    }
+
+    async fn clear_cache(&self) -> azure_core::Result<()> {
+        Ok(())
+    }
+}
+
+/// Managed identity token response; `expires_on` is Unix seconds, sent as a
+/// string by IMDS and some identity endpoints.
+#[derive(Deserialize)]
+struct ManagedIdentityToken {
+    access_token: String,
+    expires_on: serde_json::Value,
+}
+
+/// Entra Workload ID credential reading a federated token file.
+///
+/// The file is read on every fetch, so a rotated projected token is used as
+/// soon as the client refreshes its Entra ID token.
+#[derive(Clone, Debug)]
+pub struct WorkloadIdentityCredential {
+    http_client: Client,
+    token_url: Url,
+    client_id: String,
+    token_file: PathBuf,
+}
+
+impl WorkloadIdentityCredential {
+    /// Authenticate as application `client_id` in `tenant_id` with the
+    /// federated token in `token_file`, against `authority_host`.
+    ///
+    /// # Errors
+    ///
+    /// When `authority_host` and `tenant_id` do not form a valid token URL.
+    pub fn new(
+        http_client: Client,
+        authority_host: &Url,
+        tenant_id: &str,
+        client_id: &str,
+        token_file: impl AsRef<Path>,
+    ) -> Result<Self, String> {
+        Ok(WorkloadIdentityCredential {
+            http_client,
+            token_url: token_url(authority_host, tenant_id)?,
+            client_id: client_id.to_string(),
+            token_file: token_file.as_ref().to_path_buf(),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl TokenCredential for WorkloadIdentityCredential {
+    async fn get_token(&self, scopes: &[&str]) -> azure_core::Result<AccessToken> {
+        let assertion = tokio::fs::read_to_string(&self.token_file)
+            .await
+            .map_err(|e| {
+                credential_err(format!(
+                    "failed to read federated token file {}: {}",
+                    self.token_file.display(),
+                    e
+                ))
+            })?;
+        exchange_client_assertion(
+            &self.http_client,
+            &self.token_url,
+            &self.client_id,
+            assertion.trim(),
+            scopes,
+        )
+        .await
+    }
+
+    async fn clear_cache(&self) -> azure_core::Result<()> {
+        Ok(())
+    }
+}
+
+/// Service principal credential authenticating with a client certificate.
+///
+/// Requires the `certificate` Cargo feature.
+#[cfg(feature = "certificate")]
+#[derive(Clone)]
+pub struct ClientCertificateCredential {
+    http_client: Client,
+    token_url: Url,
+    client_id: String,
+    certificate_path: PathBuf,
+    password: Option<String>,
+}
+
+#[cfg(feature = "certificate")]
+impl fmt::Debug for ClientCertificateCredential {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ClientCertificateCredential")
+            .field("token_url", &self.token_url.as_str())
+            .field("client_id", &self.client_id)
+            .field("certificate_path", &self.certificate_path)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "certificate")]
+impl ClientCertificateCredential {
+    /// Authenticate as application `client_id` in `tenant_id` with the
+    /// certificate at `certificate_path`, against `authority_host`.
+    ///
+    /// The file is either PEM holding the certificate and its private key, or
+    /// PKCS#12 / PFX; set [`password`] for a protected PFX.
+    ///
+    /// # Errors
+    ///
+    /// When `authority_host` and `tenant_id` do not form a valid token URL.
+    ///
+    /// [`password`]: ClientCertificateCredential::password
+    pub fn new(
+        http_client: Client,
+        authority_host: &Url,
+        tenant_id: &str,
+        client_id: &str,
+        certificate_path: impl AsRef<Path>,
+    ) -> Result<Self, String> {
+        Ok(ClientCertificateCredential {
+            http_client,
+            token_url: token_url(authority_host, tenant_id)?,
+            client_id: client_id.to_string(),
+            certificate_path: certificate_path.as_ref().to_path_buf(),
+            password: None,
+        })
+    }
+
+    /// Password of a PKCS#12 / PFX certificate file.
+    pub fn password(mut self, password: &str) -> Self {
+        self.password = Some(password.to_string());
+        self
+    }
+
+    /// Read the certificate and private key from disk.
+    async fn load(&self) -> azure_core::Result<(X509, PKey<Private>)> {
+        let bytes = tokio::fs::read(&self.certificate_path).await.map_err(|e| {
+            credential_err(format!(
+                "failed to read certificate {}: {}",
+                self.certificate_path.display(),
+                e
+            ))
+        })?;
+        let invalid = |e: openssl::error::ErrorStack| {
+            credential_err(format!(
+                "invalid certificate {}: {}",
+                self.certificate_path.display(),
+                e
+            ))
+        };
+        if bytes.starts_with(b"-----BEGIN") {
+            let certificate = X509::from_pem(&bytes).map_err(invalid)?;
+            let key = PKey::private_key_from_pem(&bytes).map_err(invalid)?;
+            return Ok((certificate, key));
+        }
+        let parsed = Pkcs12::from_der(&bytes)
+            .and_then(|pfx| pfx.parse2(self.password.as_deref().unwrap_or("")))
+            .map_err(invalid)?;
+        match (parsed.cert, parsed.pkey) {
+            (Some(certificate), Some(key)) => Ok((certificate, key)),
+            _ => Err(credential_err(format!(
+                "certificate {} lacks a certificate or private key",
+                self.certificate_path.display()
+            ))),
+        }
+    }
+
+    /// A client assertion JWT for the token endpoint, signed RS256 and
+    /// identifying the certificate by its SHA-1 thumbprint (`x5t`).
+    fn client_assertion(
+        &self,
+        certificate: &X509,
+        key: &PKey<Private>,
+    ) -> azure_core::Result<String> {
+        let signing_err = |e: openssl::error::ErrorStack| {
+            credential_err(format!("failed to sign client assertion: {}", e))
+        };
+        let thumbprint = certificate
+            .digest(MessageDigest::sha1())
+            .map_err(signing_err)?;
+        let header = serde_json::json!({
+            "alg": "RS256",
+            "typ": "JWT",
+            "x5t": URL_SAFE_NO_PAD.encode(thumbprint),
+        });
+        let now = OffsetDateTime::now_utc();
+        let claims = serde_json::json!({
+            "aud": self.token_url.as_str(),
+            "iss": self.client_id,
+            "sub": self.client_id,
+            "jti": Uuid::new_v4().to_string(),
+            "nbf": now.unix_timestamp(),
+            "exp": (now + CLIENT_ASSERTION_LIFETIME).unix_timestamp(),
+        });
+        let signing_input = format!(
+            "{}.{}",
+            URL_SAFE_NO_PAD.encode(header.to_string()),
+            URL_SAFE_NO_PAD.encode(claims.to_string())
+        );
+        let mut signer = Signer::new(MessageDigest::sha256(), key).map_err(signing_err)?;
+        signer
+            .update(signing_input.as_bytes())
+            .map_err(signing_err)?;
+        let signature = signer.sign_to_vec().map_err(signing_err)?;
+        Ok(format!(
+            "{}.{}",
+            signing_input,
+            URL_SAFE_NO_PAD.encode(signature)
+        ))
+    }
+}
+
+#[cfg(feature = "certificate")]
+#[async_trait::async_trait]
+impl TokenCredential for ClientCertificateCredential {
+    async fn get_token(&self, scopes: &[&str]) -> azure_core::Result<AccessToken> {
+        let (certificate, key) = self.load().await?;
+        let assertion = self.client_assertion(&certificate, &key)?;
+        exchange_client_assertion(
+            &self.http_client,
+            &self.token_url,
+            &self.client_id,
+            &assertion,
+            scopes,
+        )
+        .await
+    }
+
+    async fn clear_cache(&self) -> azure_core::Result<()> {
+        Ok(())
+    }
+}
+
+/// The OAuth2 v2 token endpoint of `tenant_id`.
+pub(crate) fn token_url(authority_host: &Url, tenant_id: &str) -> Result<Url, String> {
+    authority_host
+        .join(&format!("{}/oauth2/v2.0/token", tenant_id))
+        .map_err(|e| format!("Invalid authority host: {}", e))
+}
+
+#[derive(Deserialize)]
+struct EntraToken {
+    access_token: String,
+    expires_in: i64,
+}
+
+/// Exchange a signed `assertion` for an access token with the
+/// client-credentials grant.
+pub(crate) async fn exchange_client_assertion(
+    http_client: &Client,
+    token_url: &Url,
+    client_id: &str,
+    assertion: &str,
+    scopes: &[&str],
+) -> azure_core::Result<AccessToken> {
+    let scope = scopes.join(" ");
+    let response = http_client
+        .post(token_url.clone())
+        .form(&[
+            ("client_id", client_id),
+            ("scope", &scope),
+            ("grant_type", "client_credentials"),
+            ("client_assertion_type", CLIENT_ASSERTION_TYPE),
+            ("client_assertion", assertion),
+        ])
+        .send()
+        .await
+        .map_err(|e| credential_err(format!("token endpoint unreachable: {}", e)))?;
+    let status = response.status();
+    let body = response.text().await.unwrap_or_default();
+    if !status.is_success() {
+        return Err(credential_err(format!(
+            "token endpoint returned {}: {}",
+            status, body
+        )));
+    }
+    let token: EntraToken = serde_json::from_str(&body)
+        .map_err(|e| credential_err(format!("invalid token response: {}", e)))?;
+    Ok(AccessToken::new(
+        Secret::new(token.access_token),
+        OffsetDateTime::now_utc() + time::Duration::seconds(token.expires_in),
+    ))
+}
+
+type TokenFuture = Pin<Box<dyn Future<Output = azure_core::Result<AccessToken>> + Send>>;
+
+/// Credential backed by an async closure.
+///
+/// The closure is called whenever the client needs a new token; its error is
+/// reported as [`ACSError::Auth`].
+///
+/// [`ACSError::Auth`]: crate::domain::entities::models::ACSError::Auth
+pub struct TokenProviderCredential {
+    provider: Box<dyn Fn() -> TokenFuture + Send + Sync>,
+}
+
+impl fmt::Debug for TokenProviderCredential {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TokenProviderCredential")
+            .finish_non_exhaustive()
+    }
+}
+
+impl TokenProviderCredential {
+    /// Fetch tokens by calling `provider`.
+    pub fn new<F, Fut, E>(provider: F) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<AccessToken, E>> + Send + 'static,
+        E: fmt::Display,
+    {
+        TokenProviderCredential {
+            provider: Box::new(move || {
+                let token = provider();
+                Box::pin(async move {
+                    token
+                        .await
+                        .map_err(|e| Error::message(ErrorKind::Credential, e.to_string()))
+                })
+            }),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl TokenCredential for TokenProviderCredential {
+    async fn get_token(&self, _scopes: &[&str]) -> azure_core::Result<AccessToken> {
+        (self.provider)().await
+    }
+
+    async fn clear_cache(&self) -> azure_core::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn static_token_is_returned_unchanged() {
+        let credential = StaticTokenCredential::new("fixed");
+        let token = credential.get_token(&["scope"]).await.unwrap();
+        assert_eq!(token.token.secret(), "fixed");
+        assert!(token.expires_on > OffsetDateTime::now_utc() + time::Duration::days(1));
+    }
+
+    #[tokio::test]
+    async fn provider_is_called_per_token_and_errors_are_credential_errors() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = calls.clone();
+        let credential = TokenProviderCredential::new(move || {
+            let n = counter.fetch_add(1, Ordering::SeqCst) + 1;
+            async move {
+                if n > 1 {
+                    return Err(format!("sidecar unavailable ({n})"));
+                }
+                Ok(AccessToken::new(
+                    Secret::new(format!("token-{n}")),
+                    OffsetDateTime::now_utc() + time::Duration::hours(1),
+                ))
+            }
+        });
+        let token = credential.get_token(&["scope"]).await.unwrap();
+        assert_eq!(token.token.secret(), "token-1");
+        let error = credential.get_token(&["scope"]).await.unwrap_err();
+        assert_eq!(error.kind(), &ErrorKind::Credential);
+        assert!(error.to_string().contains("sidecar unavailable (2)"));
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn managed_identity_debug_redacts_identity_header() {
+        let credential = ManagedIdentityCredential {
+            http_client: Client::new(),
+            endpoint: Url::parse("http://localhost:42356/msi/token").unwrap(),
+            identity_header: Some("identity-header-secret".to_string()),
+            id: ManagedIdentityId::SystemAssigned,
+        };
+        let debug = format!("{credential:?}");
+        assert!(debug.contains("localhost:42356"));
+        assert!(!debug.contains("identity-header-secret"));
+    }
+
+    #[tokio::test]
+    async fn user_assigned_managed_identity_is_selected_by_query() {
+        use wiremock::matchers::{header, method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/msi/token"))
+            .and(header("metadata", "true"))
+            .and(query_param("resource", "https://communication.azure.com"))
+            .and(query_param("client_id", "uami-1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "access_token": "uami-token",
+                "expires_on": "4102444800",
+                "token_type": "Bearer"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/msi/token"))
+            .and(query_param("msi_res_id", "/subscriptions/s/uami"))
+            .respond_with(ResponseTemplate::new(400).set_body_string("identity not found"))
+            .mount(&server)
+            .await;
+
+        let endpoint = Url::parse(&format!("{}/msi/token", server.uri())).unwrap();
+        let by_client_id = ManagedIdentityCredential::new(
+            Client::new(),
+            ManagedIdentityId::ClientId("uami-1".to_string()),
+        )
+        .endpoint(endpoint.clone());
+        let token = by_client_id
+            .get_token(&["https://communication.azure.com/.default"])
+            .await
+            .unwrap();
+        assert_eq!(token.token.secret(), "uami-token");
+        assert_eq!(token.expires_on.unix_timestamp(), 4_102_444_800);
+
+        let by_resource_id = ManagedIdentityCredential::new(
+            Client::new(),
+            ManagedIdentityId::ResourceId("/subscriptions/s/uami".to_string()),
+        )
+        .endpoint(endpoint);
+        let error = by_resource_id
+            .get_token(&["https://communication.azure.com/.default"])
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains("identity not found"));
+    }
+
+    #[tokio::test]
+    async fn workload_identity_rereads_federated_token_file() {
+        use wiremock::matchers::{body_string_contains, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        for (assertion, token) in [("projected-1", "entra-1"), ("projected-2", "entra-2")] {
+            Mock::given(method("POST"))
+                .and(path("/tenant-1/oauth2/v2.0/token"))
+                .and(body_string_contains(format!(
+                    "client_assertion={assertion}"
+                )))
+                .and(body_string_contains("client_id=app-1"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "token_type": "Bearer",
+                    "expires_in": 3600,
+                    "access_token": token
+                })))
+                .expect(1)
+                .mount(&server)
+                .await;
+        }
+
+        let dir = tempfile::tempdir().unwrap();
+        let token_file = dir.path().join("token");
+        std::fs::write(&token_file, "projected-1\n").unwrap();
+        let authority = Url::parse(&server.uri()).unwrap();
+        let credential = WorkloadIdentityCredential::new(
+            Client::new(),
+            &authority,
+            "tenant-1",
+            "app-1",
+            &token_file,
+        )
+        .unwrap();
+        let scopes = ["https://communication.azure.com/.default"];
+        assert_eq!(
+            credential.get_token(&scopes).await.unwrap().token.secret(),
+            "entra-1"
+        );
+
+        std::fs::write(&token_file, "projected-2").unwrap();
+        assert_eq!(
+            credential.get_token(&scopes).await.unwrap().token.secret(),
+            "entra-2"
+        );
+
+        std::fs::remove_file(&token_file).unwrap();
+        let error = credential.get_token(&scopes).await.unwrap_err();
+        assert!(error.to_string().contains("federated token file"));
+    }
+
+    #[cfg(feature = "certificate")]
+    /// A self-signed certificate for `cn` and its key.
+    pub(crate) fn self_signed(cn: &str) -> (X509, PKey<Private>) {
+        use openssl::rsa::Rsa;
+        use openssl::x509::{X509NameBuilder, X509};
+
+        let key = PKey::from_rsa(Rsa::generate(2048).unwrap()).unwrap();
+        let mut name = X509NameBuilder::new().unwrap();
+        name.append_entry_by_text("CN", cn).unwrap();
+        let name = name.build();
+        let mut builder = X509::builder().unwrap();
+        builder.set_version(2).unwrap();
+        builder.set_subject_name(&name).unwrap();
+        builder.set_issuer_name(&name).unwrap();
+        builder.set_pubkey(&key).unwrap();
+        builder
+            .set_not_before(&openssl::asn1::Asn1Time::days_from_now(0).unwrap())
+            .unwrap();
+        builder
+            .set_not_after(&openssl::asn1::Asn1Time::days_from_now(1).unwrap())
+            .unwrap();
+        builder.sign(&key, MessageDigest::sha256()).unwrap();
+        (builder.build(), key)
+    }
+
+    #[cfg(feature = "certificate")]
+    pub(crate) fn write_pem(path: &Path, certificate: &X509, key: &PKey<Private>) {
+        let mut pem = certificate.to_pem().unwrap();
+        pem.extend(key.private_key_to_pem_pkcs8().unwrap());
+        std::fs::write(path, pem).unwrap();
+    }
+
+    #[cfg(feature = "certificate")]
+    /// Verify `assertion` against `certificate` and return its JSON header
+    /// and claims.
+    fn verify_assertion(
+        assertion: &str,
+        certificate: &X509,
+    ) -> (serde_json::Value, serde_json::Value) {
+        let parts: Vec<&str> = assertion.split('.').collect();
+        assert_eq!(parts.len(), 3);
+        let public_key = certificate.public_key().unwrap();
+        let mut verifier =
+            openssl::sign::Verifier::new(MessageDigest::sha256(), &public_key).unwrap();
+        verifier
+            .update(format!("{}.{}", parts[0], parts[1]).as_bytes())
+            .unwrap();
+        assert!(verifier
+            .verify(&URL_SAFE_NO_PAD.decode(parts[2]).unwrap())
+            .unwrap());
+        let decode = |part: &str| {
+            serde_json::from_slice::<serde_json::Value>(&URL_SAFE_NO_PAD.decode(part).unwrap())
+                .unwrap()
+        };
+        (decode(parts[0]), decode(parts[1]))
+    }
+
+    #[cfg(feature = "certificate")]
+    #[tokio::test]
+    async fn certificate_assertion_is_signed_and_reloaded_on_rotation() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("sp.pem");
+        let authority = Url::parse("https://login.example.test/").unwrap();
+        let credential =
+            ClientCertificateCredential::new(Client::new(), &authority, "tenant-1", "app-1", &path)
+                .unwrap();
+
+        let (first, first_key) = self_signed("first");
+        write_pem(&path, &first, &first_key);
+        let (certificate, key) = credential.load().await.unwrap();
+        let (header, claims) = verify_assertion(
+            &credential.client_assertion(&certificate, &key).unwrap(),
+            &first,
+        );
+        assert_eq!(header["alg"], "RS256");
+        assert_eq!(
+            header["x5t"],
+            URL_SAFE_NO_PAD.encode(first.digest(MessageDigest::sha1()).unwrap())
+        );
+        assert_eq!(
+            claims["aud"],
+            "https://login.example.test/tenant-1/oauth2/v2.0/token"
+        );
+        assert_eq!(claims["iss"], "app-1");
+        assert_eq!(claims["sub"], "app-1");
+
+        let (second, second_key) = self_signed("second");
+        write_pem(&path, &second, &second_key);
+        let (certificate, key) = credential.load().await.unwrap();
+        let (header, _) = verify_assertion(
+            &credential.client_assertion(&certificate, &key).unwrap(),
+            &second,
+        );
+        assert_eq!(
+            header["x5t"],
+            URL_SAFE_NO_PAD.encode(second.digest(MessageDigest::sha1()).unwrap())
+        );
+    }
+
+    #[cfg(feature = "certificate")]
+    #[tokio::test]
+    async fn pfx_certificate_requires_its_password() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("sp.pfx");
+        let (certificate, key) = self_signed("pfx");
+        let pfx = Pkcs12::builder()
+            .name("sp")
+            .pkey(&key)
+            .cert(&certificate)
+            .build2("hunter2")
+            .unwrap();
+        std::fs::write(&path, pfx.to_der().unwrap()).unwrap();
+        let authority = Url::parse("https://login.example.test/").unwrap();
+        let credential =
+            ClientCertificateCredential::new(Client::new(), &authority, "tenant-1", "app-1", &path)
+                .unwrap();
+
+        let error = credential.load().await.unwrap_err();
+        assert!(error.to_string().contains("invalid certificate"));
+        let (loaded, _) = credential.password("hunter2").load().await.unwrap();
+        assert_eq!(
+            loaded.digest(MessageDigest::sha1()).unwrap().to_vec(),
+            certificate.digest(MessageDigest::sha1()).unwrap().to_vec()
+        );
+    }
+}
@@ -0,0 +1,346 @@
+//! Broadcast bus of delivery events for every operation sent through a client.
+//!
+//! [`ACSClient::subscribe`] returns a [`broadcast::Receiver`] of
+//! [`EmailEvent`]s, so several independent components (a UI, an audit log,
+//! metrics) can observe delivery progress without owning the stream returned
+//! by a send call.  Events are published by:
+//!
+//! - every successful send — [`EmailEventKind::Submitted`];
+//! - the client's shared status poller, whenever an operation is being waited
+//!   on, streamed or tracked — [`EmailEventKind::StatusChanged`] when a poll
+//!   observes a new non-terminal status, then exactly one
+//!   [`EmailEventKind::Terminal`] or [`EmailEventKind::Error`];
+//! - a [`ResendPolicy`] resending a failed email —
+//!   [`EmailEventKind::Resent`] for the new operation, after its `Submitted`;
+//! - the delivery SLA watchdog, when one is configured —
+//!   [`EmailEventKind::Overdue`] (see the `watchdog` module).
+//!
+//! Without a delivery SLA, operations that are sent but never waited on only
+//! produce `Submitted`.  The bus is shared by every clone of the client.  A
+//! receiver that falls more than [`EVENT_CHANNEL_CAPACITY`] events behind
+//! gets [`broadcast::error::RecvError::Lagged`] and skips to the oldest
+//! retained event.
+//!
+//! ```rust,ignore
+//! let mut events = client.subscribe();
+//! tokio::spawn(async move {
+//!     while let Ok(event) = events.recv().await {
+//!         audit_log.record(&event.operation_id, &event.kind, event.timestamp);
+//!     }
+//! });
+//! ```
+//!
+//! [`ACSClient::subscribe`]: crate::adapters::gateways::acs_email::ACSClient::subscribe
+//! [`ResendPolicy`]: crate::adapters::gateways::resend::ResendPolicy
+
+use crate::adapters::gateways::watchdog::OverdueOperation;
+use crate::domain::entities::models::{ACSError, EmailSendStatusType};
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
+use tokio::sync::broadcast;
+
+/// Number of events buffered for slow receivers.
+pub const EVENT_CHANNEL_CAPACITY: usize = 1024;
+
+/// Submitted operations whose correlation data is remembered until they
+/// finish; the oldest are forgotten beyond this.
+const MAX_REMEMBERED_OPERATIONS: usize = 10_000;
+
+/// What happened to an operation.
+#[derive(Clone, Debug)]
+pub enum EmailEventKind {
+    /// ACS accepted the email.
+    Submitted,
+    /// A poll observed a new non-terminal status.
+    StatusChanged { status: EmailSendStatusType },
+    /// A poll observed a terminal status.  `error` is
+    /// [`ACSError::DeliveryFailed`] when the status is `Failed`.
+    Terminal {
+        status: EmailSendStatusType,
+        error: Option<ACSError>,
+    },
+    /// A status poll failed; the operation is no longer polled.
+    Error { error: ACSError },
+    /// The operation replaces `previous_operation_id`, which ended `Failed`;
+    /// `attempt` counts operations for the email, starting at `1` for the
+    /// original send.
+    Resent {
+        previous_operation_id: String,
+        attempt: u32,
+    },
+    /// The operation is still pending after its delivery SLA `threshold`.
+    /// Published at most once per operation; polling continues.
+    Overdue {
+        status: EmailSendStatusType,
+        age: Duration,
+        threshold: Duration,
+    },
+}
+
+/// One delivery event, published to every [`ACSClient::subscribe`] receiver.
+///
+/// [`ACSClient::subscribe`]: crate::adapters::gateways::acs_email::ACSClient::subscribe
+#[derive(Clone, Debug)]
+pub struct EmailEvent {
+    /// ACS operation ID.
+    pub operation_id: String,
+    /// What happened.
+    pub kind: EmailEventKind,
+    /// When the event was published.
+    pub timestamp: SystemTime,
+    /// When the operation was submitted, if it was sent through this client.
+    pub submitted_at: Option<SystemTime>,
+    /// Caller-supplied [`SendOptions::correlation_id`] of the send.
+    ///
+    /// [`SendOptions::correlation_id`]: crate::adapters::gateways::acs_email::SendOptions::correlation_id
+    pub correlation_id: Option<String>,
+    /// Caller-supplied [`SendOptions::email_class`] of the send.
+    ///
+    /// [`SendOptions::email_class`]: crate::adapters::gateways::acs_email::SendOptions::email_class
+    pub email_class: Option<String>,
+}
+
+/// What is remembered about an operation sent through the client.
+#[derive(Clone, Debug)]
+pub(crate) struct OperationMeta {
+    pub(crate) submitted_at: SystemTime,
+    pub(crate) correlation_id: Option<String>,
+    pub(crate) email_class: Option<String>,
+}
+
+#[derive(Debug, Default)]
+struct Remembered {
+    operations: HashMap<String, OperationMeta>,
+    /// Insertion order of `operations`; may hold IDs already removed.
+    order: VecDeque<String>,
+}
+
+#[derive(Debug)]
+struct Inner {
+    tx: broadcast::Sender<EmailEvent>,
+    remembered: Mutex<Remembered>,
+}
+
+/// Event publisher shared by the clones of an `ACSClient`.
+#[derive(Clone, Debug)]
+pub(crate) struct EventBus {
+    inner: Arc<Inner>,
+}
+
+impl EventBus {
+    pub(crate) fn new() -> Self {
+        let (tx, _) = broadcast::channel(EVENT_CHANNEL_CAPACITY);
+        EventBus {
+            inner: Arc::new(Inner {
+                tx,
+                remembered: Mutex::new(Remembered::default()),
+            }),
+        }
+    }
+
+    pub(crate) fn subscribe(&self) -> broadcast::Receiver<EmailEvent> {
+        self.inner.tx.subscribe()
+    }
+
+    /// Record an accepted send and publish [`EmailEventKind::Submitted`].
+    pub(crate) fn submitted(
+        &self,
+        operation_id: &str,
+        correlation_id: Option<&str>,
+        email_class: Option<&str>,
+    ) {
+        let meta = OperationMeta {
+            submitted_at: SystemTime::now(),
+            correlation_id: correlation_id.map(str::to_string),
+            email_class: email_class.map(str::to_string),
+        };
+        {
+            let mut remembered = self.inner.remembered.lock().unwrap();
+            remembered
+                .operations
+                .insert(operation_id.to_string(), meta.clone());
+            remembered.order.push_back(operation_id.to_string());
+            while remembered.operations.len() > MAX_REMEMBERED_OPERATIONS {
+                if let Some(oldest) = remembered.order.pop_front() {
+                    remembered.operations.remove(&oldest);
+                }
+            }
+            if remembered.order.len() > 2 * MAX_REMEMBERED_OPERATIONS {
+                let Remembered { operations, order } = &mut *remembered;
+                order.retain(|id| operations.contains_key(id));
+            }
+        }
+        self.publish(operation_id, EmailEventKind::Submitted, Some(meta));
+    }
+
+    /// Publish [`EmailEventKind::StatusChanged`].
+    pub(crate) fn status_changed(&self, operation_id: &str, status: EmailSendStatusType) {
+        let meta = self.lookup(operation_id, false);
+        self.publish(operation_id, EmailEventKind::StatusChanged { status }, meta);
+    }
+
+    /// Publish the final event for a poll result that ended tracking, and
+    /// forget the operation.
+    pub(crate) fn finished(
+        &self,
+        operation_id: &str,
+        result: &Result<EmailSendStatusType, ACSError>,
+    ) {
+        let kind = match result {
+            Ok(status) => EmailEventKind::Terminal {
+                status: *status,
+                error: None,
+            },
+            Err(error @ ACSError::DeliveryFailed { .. }) => EmailEventKind::Terminal {
+                status: EmailSendStatusType::Failed,
+                error: Some(error.clone()),
+            },
+            Err(error) => EmailEventKind::Error {
+                error: error.clone(),
+            },
+        };
+        let meta = self.lookup(operation_id, true);
+        self.publish(operation_id, kind, meta);
+    }
+
+    /// Publish [`EmailEventKind::Resent`].
+    pub(crate) fn resent(&self, operation_id: &str, previous_operation_id: &str, attempt: u32) {
+        let kind = EmailEventKind::Resent {
+            previous_operation_id: previous_operation_id.to_string(),
+            attempt,
+        };
+        let meta = self.lookup(operation_id, false);
+        self.publish(operation_id, kind, meta);
+    }
+
+    /// Publish [`EmailEventKind::Overdue`].
+    pub(crate) fn overdue(&self, operation: &OverdueOperation) {
+        let kind = EmailEventKind::Overdue {
+            status: operation.status,
+            age: operation.age,
+            threshold: operation.threshold,
+        };
+        let meta = self.lookup(&operation.operation_id, false);
+        self.publish(&operation.operation_id, kind, meta);
+    }
+
+    /// Submission data of an operation sent through the client, if still
+    /// remembered.
+    pub(crate) fn meta(&self, operation_id: &str) -> Option<OperationMeta> {
+        self.lookup(operation_id, false)
+    }
+
+    fn lookup(&self, operation_id: &str, remove: bool) -> Option<OperationMeta> {
+        let mut remembered = self.inner.remembered.lock().unwrap();
+        if remove {
+            remembered.operations.remove(operation_id)
+        } else {
+            remembered.operations.get(operation_id).cloned()
+        }
+    }
+
+    fn publish(&self, operation_id: &str, kind: EmailEventKind, meta: Option<OperationMeta>) {
+        if self.inner.tx.receiver_count() == 0 {
+            return;
+        }
+        let (submitted_at, correlation_id, email_class) = match meta {
+            Some(meta) => (
+                Some(meta.submitted_at),
+                meta.correlation_id,
+                meta.email_class,
+            ),
+            None => (None, None, None),
+        };
+        // An error only means every receiver was dropped in the meantime.
+        let _ = self.inner.tx.send(EmailEvent {
+            operation_id: operation_id.to_string(),
+            kind,
+            timestamp: SystemTime::now(),
+            submitted_at,
+            correlation_id,
+            email_class,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn events_carry_submission_metadata_until_finished() {
+        let bus = EventBus::new();
+        let mut rx = bus.subscribe();
+
+        bus.submitted("op-1", Some("order-42"), Some("receipt"));
+        bus.status_changed("op-1", EmailSendStatusType::Running);
+        bus.finished("op-1", &Ok(EmailSendStatusType::Succeeded));
+        bus.status_changed("op-1", EmailSendStatusType::Running);
+
+        let submitted = rx.try_recv().unwrap();
+        assert!(matches!(submitted.kind, EmailEventKind::Submitted));
+        assert_eq!(submitted.correlation_id.as_deref(), Some("order-42"));
+        assert_eq!(submitted.email_class.as_deref(), Some("receipt"));
+        let running = rx.try_recv().unwrap();
+        assert!(matches!(
+            running.kind,
+            EmailEventKind::StatusChanged {
+                status: EmailSendStatusType::Running
+            }
+        ));
+        assert_eq!(running.submitted_at, submitted.submitted_at);
+        let terminal = rx.try_recv().unwrap();
+        assert!(matches!(
+            terminal.kind,
+            EmailEventKind::Terminal {
+                status: EmailSendStatusType::Succeeded,
+                error: None
+            }
+        ));
+        assert_eq!(terminal.correlation_id.as_deref(), Some("order-42"));
+        // Forgotten after the terminal event.
+        assert_eq!(rx.try_recv().unwrap().correlation_id, None);
+    }
+
+    #[test]
+    fn delivery_failure_is_terminal_and_poll_error_is_error() {
+        let bus = EventBus::new();
+        let mut rx = bus.subscribe();
+
+        bus.finished(
+            "op-1",
+            &Err(ACSError::DeliveryFailed {
+                operation_id: "op-1".to_string(),
+                error: None,
+                attempts: vec!["op-1".to_string()],
+            }),
+        );
+        bus.finished("op-2", &Err(ACSError::Network("reset".to_string())));
+
+        assert!(matches!(
+            rx.try_recv().unwrap().kind,
+            EmailEventKind::Terminal {
+                status: EmailSendStatusType::Failed,
+                error: Some(ACSError::DeliveryFailed { .. })
+            }
+        ));
+        assert!(matches!(
+            rx.try_recv().unwrap().kind,
+            EmailEventKind::Error {
+                error: ACSError::Network(_)
+            }
+        ));
+    }
+
+    #[test]
+    fn remembered_operations_are_bounded() {
+        let bus = EventBus::new();
+        for i in 0..MAX_REMEMBERED_OPERATIONS + 10 {
+            bus.submitted(&format!("op-{i}"), None, None);
+        }
+        let remembered = bus.inner.remembered.lock().unwrap();
+        assert_eq!(remembered.operations.len(), MAX_REMEMBERED_OPERATIONS);
+        assert!(!remembered.operations.contains_key("op-0"));
+    }
+}
@@ -1,2 +1,18 @@
+pub mod access_keys;
 pub mod acs_email;
 mod acs_shared_key;
+pub mod batch;
+pub mod bulk;
+pub mod cloud;
+pub mod concurrency;
+pub mod credential_chain;
+pub mod credentials;
+pub mod events;
+pub mod poll;
+pub mod poller;
+pub mod rate_limit;
+pub mod resend;
+pub mod retry;
+pub mod token_cache;
+pub mod tracker;
+pub mod watchdog;
@@ -0,0 +1,202 @@
+//! Poll strategies for tracking pending ACS email operations.
+//!
+//! A [`PollStrategy`] decides how long to wait before each status poll of an
+//! operation.  It is installed with [`ACSClientBuilder::poll_strategy`] and
+//! governs every polling API — the waiting, streaming and callback variants,
+//! [`wait_for_operation`] and [`track_operation`] — since they all go through
+//! the client's shared operation tracker.
+//!
+//! Three strategies are built in:
+//!
+//! | Strategy | Delay before poll `n` (0-based) |
+//! |---|---|
+//! | [`FixedInterval`] (default, 5 s) | The same interval every time |
+//! | [`ExponentialPoll`] | `min(max, initial · factor^n)` |
+//! | [`FibonacciPoll`] | `min(max, unit · fib(n + 1))`, i.e. 1, 1, 2, 3, 5, 8 … units |
+//!
+//! # Server hints
+//!
+//! When an operation-status response carries a `Retry-After` header (in any of
+//! the formats described in the `retry` module), the next poll of that
+//! operation waits the requested delay instead of the strategy's, clamped to
+//! [`PollStrategy::max_retry_after`].
+//!
+//! ```rust,ignore
+//! let client = ACSClientBuilder::new()
+//!     .connection_string(&conn_str)
+//!     .poll_strategy(ExponentialPoll::new(Duration::from_millis(500), Duration::from_secs(30)))
+//!     .build()?;
+//! ```
+//!
+//! [`ACSClientBuilder::poll_strategy`]: crate::adapters::gateways::acs_email::ACSClientBuilder::poll_strategy
+//! [`wait_for_operation`]: crate::adapters::gateways::acs_email::ACSClient::wait_for_operation
+//! [`track_operation`]: crate::adapters::gateways::acs_email::ACSClient::track_operation
+
+use std::fmt::Debug;
+use std::time::Duration;
+
+/// Default cap on server-requested poll delays.
+pub const DEFAULT_MAX_POLL_RETRY_AFTER: Duration = Duration::from_secs(60);
+
+/// Decides the delay before each status poll of an operation.
+pub trait PollStrategy: Debug + Send + Sync {
+    /// Delay before poll number `attempt`, counted from `0` for the first
+    /// poll after the operation starts being tracked.
+    fn delay(&self, attempt: u32) -> Duration;
+
+    /// Upper bound for a delay requested by the service with `Retry-After`.
+    fn max_retry_after(&self) -> Duration {
+        DEFAULT_MAX_POLL_RETRY_AFTER
+    }
+}
+
+/// The same delay before every poll.
+#[derive(Clone, Debug)]
+pub struct FixedInterval {
+    interval: Duration,
+}
+
+impl FixedInterval {
+    /// Poll every `interval`.
+    pub fn new(interval: Duration) -> Self {
+        FixedInterval { interval }
+    }
+}
+
+impl PollStrategy for FixedInterval {
+    fn delay(&self, _attempt: u32) -> Duration {
+        self.interval
+    }
+}
+
+/// Delays that grow geometrically from `initial` up to `max`.
+///
+/// Suits workloads where most operations finish within seconds but a few run
+/// for minutes: early polls are quick, late ones are sparse.
+#[derive(Clone, Debug)]
+pub struct ExponentialPoll {
+    initial: Duration,
+    max: Duration,
+    factor: f64,
+}
+
+impl ExponentialPoll {
+    /// Start at `initial` and double the delay after every poll, up to `max`.
+    pub fn new(initial: Duration, max: Duration) -> Self {
+        ExponentialPoll {
+            initial,
+            max,
+            factor: 2.0,
+        }
+    }
+
+    /// Growth factor applied after every poll. Values below `1.0` are treated
+    /// as `1.0`. Default: `2.0`.
+    pub fn factor(mut self, factor: f64) -> Self {
+        self.factor = factor;
+        self
+    }
+}
+
+impl PollStrategy for ExponentialPoll {
+    fn delay(&self, attempt: u32) -> Duration {
+        let scale = self.factor.max(1.0).powf(f64::from(attempt));
+        Duration::try_from_secs_f64(self.initial.as_secs_f64() * scale)
+            .unwrap_or(self.max)
+            .min(self.max)
+    }
+}
+
+/// Delays that follow the Fibonacci sequence in multiples of `unit`, up to
+/// `max`.  Grows more gently than [`ExponentialPoll`] with factor `2`.
+#[derive(Clone, Debug)]
+pub struct FibonacciPoll {
+    unit: Duration,
+    max: Duration,
+}
+
+impl FibonacciPoll {
+    /// Wait 1, 1, 2, 3, 5, 8 … × `unit` before successive polls, up to `max`.
+    pub fn new(unit: Duration, max: Duration) -> Self {
+        FibonacciPoll { unit, max }
+    }
+}
+
+impl PollStrategy for FibonacciPoll {
+    fn delay(&self, attempt: u32) -> Duration {
+        let (mut current, mut next) = (1u32, 1u32);
+        for _ in 0..attempt {
+            (current, next) = (next, current.saturating_add(next));
+            if self.unit.saturating_mul(current) >= self.max {
+                break;
+            }
+        }
+        self.unit.saturating_mul(current).min(self.max)
+    }
+}
+
+/// Delay before the next poll: the server's `Retry-After` hint when present
+/// (clamped to the strategy's cap), otherwise the strategy's own delay.
+pub(crate) fn next_poll_delay(
+    strategy: &dyn PollStrategy,
+    attempt: u32,
+    retry_after: Option<Duration>,
+) -> Duration {
+    match retry_after {
+        Some(hint) => hint.min(strategy.max_retry_after()),
+        None => strategy.delay(attempt),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixed_interval_is_constant() {
+        let strategy = FixedInterval::new(Duration::from_millis(250));
+        assert_eq!(strategy.delay(0), Duration::from_millis(250));
+        assert_eq!(strategy.delay(50), Duration::from_millis(250));
+    }
+
+    #[test]
+    fn exponential_poll_grows_and_caps() {
+        let strategy = ExponentialPoll::new(Duration::from_millis(100), Duration::from_secs(1));
+        assert_eq!(strategy.delay(0), Duration::from_millis(100));
+        assert_eq!(strategy.delay(1), Duration::from_millis(200));
+        assert_eq!(strategy.delay(3), Duration::from_millis(800));
+        assert_eq!(strategy.delay(4), Duration::from_secs(1));
+        assert_eq!(strategy.delay(u32::MAX), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn exponential_poll_factor_below_one_is_constant() {
+        let strategy =
+            ExponentialPoll::new(Duration::from_millis(100), Duration::from_secs(1)).factor(0.5);
+        assert_eq!(strategy.delay(5), Duration::from_millis(100));
+    }
+
+    #[test]
+    fn fibonacci_poll_follows_sequence_and_caps() {
+        let strategy = FibonacciPoll::new(Duration::from_millis(10), Duration::from_millis(100));
+        let delays: Vec<u64> = (0..8)
+            .map(|n| strategy.delay(n).as_millis() as u64)
+            .collect();
+        assert_eq!(delays, vec![10, 10, 20, 30, 50, 80, 100, 100]);
+        assert_eq!(strategy.delay(u32::MAX), Duration::from_millis(100));
+    }
+
+    #[test]
+    fn retry_after_hint_overrides_strategy_and_is_clamped() {
+        let strategy = FixedInterval::new(Duration::from_secs(5));
+        assert_eq!(
+            next_poll_delay(&strategy, 0, Some(Duration::from_secs(2))),
+            Duration::from_secs(2)
+        );
+        assert_eq!(
+            next_poll_delay(&strategy, 0, Some(Duration::from_secs(3600))),
+            DEFAULT_MAX_POLL_RETRY_AFTER
+        );
+        assert_eq!(next_poll_delay(&strategy, 0, None), Duration::from_secs(5));
+    }
+}
@@ -0,0 +1,331 @@
+//! Long-running-operation pollers that can be persisted and resumed.
+//!
+//! An [`EmailPoller`] follows one ACS email operation the way the official
+//! Azure SDKs follow long-running operations: call [`poll`] for a single
+//! status check, or [`wait`] to poll on the client's [`PollStrategy`] until a
+//! terminal status.  Both go through the client's shared status poller, so
+//! they publish events, count against the status poll rate and are watched
+//! by the delivery SLA like any other wait.  Its state — operation ID, time
+//! of the first poll, attempt count, last status (with the error of a failed
+//! operation) and API version — can be saved with
+//! [`to_resume_token`] and picked up by another process with
+//! [`ACSClient::resume_poller`]:
+//!
+//! ```rust,ignore
+//! // Process A: send and hand the operation off.
+//! let operation_id = client.send_email(&email).await?;
+//! let token = client.poller(&operation_id).to_resume_token();
+//! queue.push(token).await?;
+//!
+//! // Process B: carry on polling.
+//! let mut poller = client.resume_poller(&queue.pop().await?)?;
+//! let status = poller.wait(Duration::from_secs(300)).await?;
+//! ```
+//!
+//! The token is an opaque URL-safe string; its format is not a stable API.
+//!
+//! [`poll`]: EmailPoller::poll
+//! [`wait`]: EmailPoller::wait
+//! [`to_resume_token`]: EmailPoller::to_resume_token
+//! [`PollStrategy`]: crate::adapters::gateways::poll::PollStrategy
+//! [`ACSClient::resume_poller`]: crate::adapters::gateways::acs_email::ACSClient::resume_poller
+
+use crate::adapters::gateways::acs_email::{
+    create_missing_status_error, is_terminal_status, ACSApiVersion, ACSClient,
+};
+use crate::adapters::gateways::tracker::OperationSubscription;
+use crate::domain::entities::models::{ACSError, EmailSendStatusType, ErrorDetail};
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::time::Instant;
+use tracing::{debug, instrument};
+
+/// The persisted part of a poller.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+struct PollerState {
+    operation_id: String,
+    /// Time of the first poll, as milliseconds since the Unix epoch.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    first_poll: Option<u64>,
+    attempts: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    last_status: Option<EmailSendStatusType>,
+    /// Error reported with a `Failed` status.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    error: Option<Box<ErrorDetail>>,
+    api_version: String,
+}
+
+/// Resumable poller for one ACS email operation.  See the [module docs](self).
+pub struct EmailPoller {
+    client: ACSClient,
+    state: PollerState,
+    api_version: ACSApiVersion,
+}
+
+impl fmt::Debug for EmailPoller {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EmailPoller")
+            .field("operation_id", &self.state.operation_id)
+            .field("attempts", &self.state.attempts)
+            .field("last_status", &self.state.last_status)
+            .field("api_version", &self.state.api_version)
+            .finish()
+    }
+}
+
+impl EmailPoller {
+    pub(crate) fn new(client: ACSClient, operation_id: &str) -> Self {
+        let api_version = client.api_version().clone();
+        EmailPoller {
+            state: PollerState {
+                operation_id: operation_id.to_string(),
+                first_poll: None,
+                attempts: 0,
+                last_status: None,
+                error: None,
+                api_version: api_version.as_str().to_string(),
+            },
+            client,
+            api_version,
+        }
+    }
+
+    pub(crate) fn from_resume_token(client: ACSClient, token: &str) -> Result<Self, ACSError> {
+        let invalid =
+            |reason: String| ACSError::Deserialization(format!("invalid resume token: {reason}"));
+        let bytes = URL_SAFE_NO_PAD
+            .decode(token.trim())
+            .map_err(|e| invalid(e.to_string()))?;
+        let state: PollerState =
+            serde_json::from_slice(&bytes).map_err(|e| invalid(e.to_string()))?;
+        let api_version = ACSApiVersion::from_version_str(&state.api_version)
+            .ok_or_else(|| invalid(format!("unknown API version {:?}", state.api_version)))?;
+        Ok(EmailPoller {
+            client,
+            state,
+            api_version,
+        })
+    }
+
+    /// Serialise the poller's state into an opaque token for
+    /// [`ACSClient::resume_poller`].
+    ///
+    /// [`ACSClient::resume_poller`]: crate::adapters::gateways::acs_email::ACSClient::resume_poller
+    pub fn to_resume_token(&self) -> String {
+        let json = serde_json::to_vec(&self.state).expect("poller state is serialisable");
+        URL_SAFE_NO_PAD.encode(json)
+    }
+
+    /// The operation being polled.
+    pub fn operation_id(&self) -> &str {
+        &self.state.operation_id
+    }
+
+    /// Number of successful status polls made so far, including those made
+    /// before the poller was resumed.
+    pub fn attempts(&self) -> u32 {
+        self.state.attempts
+    }
+
+    /// Wall-clock time of the first poll, if any poll has been made.
+    pub fn first_poll(&self) -> Option<SystemTime> {
+        self.state
+            .first_poll
+            .map(|ms| UNIX_EPOCH + Duration::from_millis(ms))
+    }
+
+    /// Status observed by the latest poll.
+    pub fn last_status(&self) -> Option<EmailSendStatusType> {
+        self.state.last_status
+    }
+
+    /// API version used for the status requests.
+    pub fn api_version(&self) -> &ACSApiVersion {
+        &self.api_version
+    }
+
+    /// `true` once a terminal status has been observed.
+    pub fn is_done(&self) -> bool {
+        self.state
+            .last_status
+            .as_ref()
+            .is_some_and(is_terminal_status)
+    }
+
+    /// Poll the operation once and record the result.
+    ///
+    /// Like [`get_email_status`], a `Failed` operation is returned as
+    /// `Ok(EmailSendStatusType::Failed)`; its error is kept for [`wait`].
+    /// If the operation is already being polled for another waiter, this
+    /// shares that waiter's next GET.
+    ///
+    /// [`get_email_status`]: crate::adapters::gateways::acs_email::ACSClient::get_email_status
+    /// [`wait`]: EmailPoller::wait
+    pub async fn poll(&mut self) -> Result<EmailSendStatusType, ACSError> {
+        let mut updates = self.subscribe(Instant::now());
+        self.next_status(&mut updates).await
+    }
+
+    /// Poll on the client's [`PollStrategy`] until the operation reaches a
+    /// terminal status or `timeout` elapses.
+    ///
+    /// The schedule continues from [`attempts`](EmailPoller::attempts), so a
+    /// resumed poller does not restart at the strategy's shortest delay.  If
+    /// a terminal status has already been observed it is returned without a
+    /// request.
+    ///
+    /// # Errors
+    ///
+    /// - [`ACSError::DeliveryFailed`] when the operation failed.
+    /// - [`ACSError::Timeout`] when `timeout` elapses first.  The poller state
+    ///   is kept, so a fresh token can be taken and polling resumed later.
+    /// - [`ACSError::Canceled`] once the client has been shut down.
+    /// - Any error from the status request.
+    ///
+    /// [`PollStrategy`]: crate::adapters::gateways::poll::PollStrategy
+    #[instrument(skip(self), fields(operation_id = %self.state.operation_id))]
+    pub async fn wait(&mut self, timeout: Duration) -> Result<EmailSendStatusType, ACSError> {
+        if let Some(status) = self.state.last_status.filter(is_terminal_status) {
+            return self.terminal_result(status);
+        }
+        let delay = self.client.poll_strategy().delay(self.state.attempts);
+        debug!(
+            attempt = self.state.attempts,
+            ?delay,
+            "Waiting for next poll"
+        );
+        let mut updates = self.subscribe(Instant::now() + delay);
+        tokio::time::timeout(timeout, async {
+            loop {
+                let status = self.next_status(&mut updates).await?;
+                if is_terminal_status(&status) {
+                    return self.terminal_result(status);
+                }
+            }
+        })
+        .await
+        .map_err(|_| ACSError::Timeout)?
+    }
+
+    fn subscribe(&self, first_poll: Instant) -> OperationSubscription {
+        self.client.operation_tracker().subscribe_from(
+            &self.client,
+            &self.state.operation_id,
+            &self.api_version,
+            self.state.attempts,
+            first_poll,
+        )
+    }
+
+    /// Record the next poll result of `updates`.  Only successful polls are
+    /// counted.
+    async fn next_status(
+        &mut self,
+        updates: &mut OperationSubscription,
+    ) -> Result<EmailSendStatusType, ACSError> {
+        let status = match updates.recv().await {
+            Some(Ok(status)) => status,
+            Some(Err(ACSError::DeliveryFailed { error, .. })) => {
+                self.state.error = error;
+                EmailSendStatusType::Failed
+            }
+            Some(Err(e)) => return Err(e),
+            None => return Err(create_missing_status_error()),
+        };
+        self.state
+            .first_poll
+            .get_or_insert_with(|| unix_millis(SystemTime::now()));
+        self.state.attempts = self.state.attempts.saturating_add(1);
+        self.state.last_status = Some(status);
+        Ok(status)
+    }
+
+    fn terminal_result(
+        &self,
+        status: EmailSendStatusType,
+    ) -> Result<EmailSendStatusType, ACSError> {
+        match status {
+            EmailSendStatusType::Failed => Err(ACSError::DeliveryFailed {
+                operation_id: self.state.operation_id.clone(),
+                error: self.state.error.clone(),
+                attempts: vec![self.state.operation_id.clone()],
+            }),
+            status => Ok(status),
+        }
+    }
+}
+
+fn unix_millis(time: SystemTime) -> u64 {
+    let millis = time
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis();
+    u64::try_from(millis).unwrap_or(u64::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::gateways::acs_email::ACSClientBuilder;
+
+    fn client() -> ACSClient {
+        ACSClientBuilder::new()
+            .connection_string("endpoint=https://fake.communication.azure.com;accesskey=c2VjcmV0")
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn resume_token_round_trips_state() {
+        let client = client();
+        let mut poller = client.poller("op-1");
+        poller.state.first_poll = Some(1_700_000_000_000);
+        poller.state.attempts = 4;
+        poller.state.last_status = Some(EmailSendStatusType::Running);
+
+        let resumed = client.resume_poller(&poller.to_resume_token()).unwrap();
+        assert_eq!(resumed.to_resume_token(), poller.to_resume_token());
+        assert_eq!(resumed.operation_id(), "op-1");
+        assert_eq!(resumed.attempts(), 4);
+        assert_eq!(
+            resumed.first_poll(),
+            Some(UNIX_EPOCH + Duration::from_millis(1_700_000_000_000))
+        );
+        assert!(!resumed.is_done());
+    }
+
+    #[test]
+    fn fresh_poller_uses_client_api_version() {
+        let poller = client().poller("op-1");
+        assert_eq!(poller.api_version().as_str(), "2023-03-31");
+        assert_eq!(poller.attempts(), 0);
+        assert_eq!(poller.first_poll(), None);
+        assert_eq!(poller.last_status(), None);
+    }
+
+    #[test]
+    fn malformed_token_is_rejected() {
+        let client = client();
+        assert!(matches!(
+            client.resume_poller("not a token!"),
+            Err(ACSError::Deserialization(_))
+        ));
+        let not_state = URL_SAFE_NO_PAD.encode(b"{\"hello\":1}");
+        assert!(matches!(
+            client.resume_poller(&not_state),
+            Err(ACSError::Deserialization(_))
+        ));
+    }
+
+    #[test]
+    fn unknown_api_version_is_rejected() {
+        let token = URL_SAFE_NO_PAD
+            .encode(br#"{"operation_id":"op-1","attempts":0,"api_version":"1999-01-01"}"#);
+        let err = client().resume_poller(&token).unwrap_err();
+        assert!(err.to_string().contains("1999-01-01"));
+    }
+}
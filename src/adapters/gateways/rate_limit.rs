@@ -0,0 +1,381 @@
+//! Client-side token-bucket rate limiting matched to ACS sending quotas.
+//!
+//! ACS enforces per-minute and per-hour email quotas per resource.  A
+//! [`RateLimiter`] keeps local token buckets for those windows so sends wait
+//! for capacity up front instead of discovering the quota through `429`
+//! responses and burning retries.
+//!
+//! Up to four buckets are checked per send:
+//!
+//! | Bucket | Cost of one send |
+//! |---|---|
+//! | Requests per minute / per hour | `1` |
+//! | Recipients per minute / per hour | number of `to` + `cc` + `bcc` addresses |
+//!
+//! Each bucket starts full and refills continuously at `capacity / window`.
+//! In [`RateLimitMode::Wait`] a send reserves its cost immediately — the
+//! balance may go negative — and then sleeps until the deficit has refilled,
+//! so concurrent waiters are served in arrival order.  A wait that is
+//! abandoned — the send was cancelled or timed out — refunds its reservation.
+//! In [`RateLimitMode::FailFast`] a send that does not fit fails with
+//! [`ACSError::QuotaExhausted`] and consumes nothing.
+//!
+//! The limiter lives behind an `Arc`, so every clone of an [`ACSClient`] —
+//! and every send of a [`send_emails_batch`] call — draws from the same buckets.
+//!
+//! [`ACSClient`]: crate::adapters::gateways::acs_email::ACSClient
+//! [`send_emails_batch`]: crate::adapters::gateways::acs_email::ACSClient::send_emails_batch
+
+use crate::domain::entities::models::ACSError;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::time::{sleep, Instant};
+use tracing::debug;
+
+const MINUTE: Duration = Duration::from_secs(60);
+const HOUR: Duration = Duration::from_secs(3600);
+
+/// What a send does when the rate limiter has no capacity.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RateLimitMode {
+    /// Wait until capacity is available (default).
+    #[default]
+    Wait,
+    /// Fail immediately with [`ACSError::QuotaExhausted`].
+    FailFast,
+}
+
+/// A single continuously-refilling token bucket.
+#[derive(Debug)]
+struct TokenBucket {
+    capacity: f64,
+    tokens: f64,
+    refill_per_sec: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    fn new(capacity: u32, window: Duration, now: Instant) -> Self {
+        let capacity = f64::from(capacity);
+        TokenBucket {
+            capacity,
+            tokens: capacity,
+            refill_per_sec: capacity / window.as_secs_f64(),
+            last_refill: now,
+        }
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.last_refill);
+        self.tokens =
+            (self.tokens + elapsed.as_secs_f64() * self.refill_per_sec).min(self.capacity);
+        self.last_refill = now;
+    }
+
+    /// Cost clamped to capacity, so an oversized send waits for a full bucket
+    /// instead of never fitting.
+    fn effective_cost(&self, cost: f64) -> f64 {
+        cost.min(self.capacity)
+    }
+
+    /// Time until `cost` tokens are available (zero when they already are).
+    fn wait_for(&self, cost: f64) -> Duration {
+        let deficit = self.effective_cost(cost) - self.tokens;
+        if deficit <= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(deficit / self.refill_per_sec)
+        }
+    }
+
+    fn take(&mut self, cost: f64) {
+        self.tokens -= self.effective_cost(cost);
+    }
+
+    fn refund(&mut self, cost: f64) {
+        self.tokens = (self.tokens + self.effective_cost(cost)).min(self.capacity);
+    }
+}
+
+/// The buckets guarded by the limiter's mutex.
+#[derive(Debug, Default)]
+struct Buckets {
+    requests: Vec<TokenBucket>,
+    recipients: Vec<TokenBucket>,
+}
+
+impl Buckets {
+    fn refill(&mut self, now: Instant) {
+        for bucket in self.requests.iter_mut().chain(self.recipients.iter_mut()) {
+            bucket.refill(now);
+        }
+    }
+
+    /// Longest wait across all buckets before a send of `recipients` fits.
+    fn wait_for(&self, recipients: f64) -> Duration {
+        let request_wait = self.requests.iter().map(|b| b.wait_for(1.0));
+        let recipient_wait = self.recipients.iter().map(|b| b.wait_for(recipients));
+        request_wait
+            .chain(recipient_wait)
+            .max()
+            .unwrap_or(Duration::ZERO)
+    }
+
+    fn take(&mut self, recipients: f64) {
+        for bucket in self.requests.iter_mut() {
+            bucket.take(1.0);
+        }
+        for bucket in self.recipients.iter_mut() {
+            bucket.take(recipients);
+        }
+    }
+
+    fn refund(&mut self, recipients: f64) {
+        for bucket in self.requests.iter_mut() {
+            bucket.refund(1.0);
+        }
+        for bucket in self.recipients.iter_mut() {
+            bucket.refund(recipients);
+        }
+    }
+}
+
+/// Capacity taken by a waiting [`RateLimiter::acquire`]; returned to the
+/// buckets if the wait is dropped before it completes.
+struct Reservation<'a> {
+    buckets: &'a Mutex<Buckets>,
+    recipients: f64,
+}
+
+impl Reservation<'_> {
+    fn commit(self) {
+        std::mem::forget(self);
+    }
+}
+
+impl Drop for Reservation<'_> {
+    fn drop(&mut self) {
+        let mut buckets = self.buckets.lock().unwrap();
+        buckets.refill(Instant::now());
+        buckets.refund(self.recipients);
+        debug!("Rate-limit wait abandoned; capacity refunded");
+    }
+}
+
+/// Shared token-bucket limiter for ACS sends.
+///
+/// Configure it through [`ACSClientBuilder::rate_limit`],
+/// [`ACSClientBuilder::recipient_rate_limit`] and
+/// [`ACSClientBuilder::rate_limit_mode`]; the client applies it to every send.
+/// [`ACSClient::rate_limiter`] exposes it for callers that want to check
+/// capacity themselves.
+///
+/// [`ACSClientBuilder::rate_limit`]: crate::adapters::gateways::acs_email::ACSClientBuilder::rate_limit
+/// [`ACSClientBuilder::recipient_rate_limit`]: crate::adapters::gateways::acs_email::ACSClientBuilder::recipient_rate_limit
+/// [`ACSClientBuilder::rate_limit_mode`]: crate::adapters::gateways::acs_email::ACSClientBuilder::rate_limit_mode
+/// [`ACSClient::rate_limiter`]: crate::adapters::gateways::acs_email::ACSClient::rate_limiter
+#[derive(Clone, Debug)]
+pub struct RateLimiter {
+    buckets: Arc<Mutex<Buckets>>,
+    mode: RateLimitMode,
+}
+
+impl RateLimiter {
+    /// Build a limiter from optional `(per_minute, per_hour)` request and
+    /// recipient quotas.  Returns `None` when neither quota is set.
+    pub(crate) fn from_quotas(
+        requests: Option<(u32, u32)>,
+        recipients: Option<(u32, u32)>,
+        mode: RateLimitMode,
+    ) -> Option<Self> {
+        if requests.is_none() && recipients.is_none() {
+            return None;
+        }
+        let now = Instant::now();
+        let windows = |(per_minute, per_hour): (u32, u32)| {
+            vec![
+                TokenBucket::new(per_minute, MINUTE, now),
+                TokenBucket::new(per_hour, HOUR, now),
+            ]
+        };
+        let buckets = Buckets {
+            requests: requests.map(windows).unwrap_or_default(),
+            recipients: recipients.map(windows).unwrap_or_default(),
+        };
+        Some(RateLimiter {
+            buckets: Arc::new(Mutex::new(buckets)),
+            mode,
+        })
+    }
+
+    /// The behaviour applied by the client when capacity is exhausted.
+    pub fn mode(&self) -> RateLimitMode {
+        self.mode
+    }
+
+    /// Reserve capacity for one send to `recipients` addresses, waiting as
+    /// long as necessary.
+    ///
+    /// Dropping the future before it completes — for example when racing it
+    /// against a cancellation token — gives the reserved capacity back.
+    pub async fn acquire(&self, recipients: usize) {
+        let recipients = recipients as f64;
+        let wait = {
+            let mut buckets = self.buckets.lock().unwrap();
+            buckets.refill(Instant::now());
+            let wait = buckets.wait_for(recipients);
+            buckets.take(recipients);
+            wait
+        };
+        if !wait.is_zero() {
+            let reservation = Reservation {
+                buckets: &self.buckets,
+                recipients,
+            };
+            debug!(
+                wait_ms = wait.as_millis() as u64,
+                "Waiting for rate-limit capacity"
+            );
+            sleep(wait).await;
+            reservation.commit();
+        }
+    }
+
+    /// Reserve capacity for one send to `recipients` addresses if it is
+    /// available right now.
+    ///
+    /// # Errors
+    ///
+    /// [`ACSError::QuotaExhausted`] carrying the time until the send would
+    /// fit.  Nothing is consumed in that case.
+    pub fn try_acquire(&self, recipients: usize) -> Result<(), ACSError> {
+        let mut buckets = self.buckets.lock().unwrap();
+        buckets.refill(Instant::now());
+        let wait = buckets.wait_for(recipients as f64);
+        if wait.is_zero() {
+            buckets.take(recipients as f64);
+            Ok(())
+        } else {
+            Err(ACSError::QuotaExhausted { retry_after: wait })
+        }
+    }
+
+    /// Reserve capacity according to [`mode`](Self::mode).
+    pub(crate) async fn reserve(&self, recipients: usize) -> Result<(), ACSError> {
+        match self.mode {
+            RateLimitMode::Wait => {
+                self.acquire(recipients).await;
+                Ok(())
+            }
+            RateLimitMode::FailFast => self.try_acquire(recipients),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limiter(
+        requests: Option<(u32, u32)>,
+        recipients: Option<(u32, u32)>,
+        mode: RateLimitMode,
+    ) -> RateLimiter {
+        RateLimiter::from_quotas(requests, recipients, mode).unwrap()
+    }
+
+    #[test]
+    fn from_quotas_without_limits_is_none() {
+        assert!(RateLimiter::from_quotas(None, None, RateLimitMode::Wait).is_none());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn try_acquire_fails_once_minute_quota_is_spent() {
+        let limiter = limiter(Some((2, 100)), None, RateLimitMode::FailFast);
+        assert!(limiter.try_acquire(1).is_ok());
+        assert!(limiter.try_acquire(1).is_ok());
+        match limiter.try_acquire(1) {
+            Err(ACSError::QuotaExhausted { retry_after }) => {
+                assert!(retry_after > Duration::ZERO);
+                assert!(retry_after <= Duration::from_secs(30));
+            }
+            other => panic!("expected QuotaExhausted, got {other:?}"),
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn tokens_refill_over_time() {
+        let limiter = limiter(Some((1, 100)), None, RateLimitMode::FailFast);
+        assert!(limiter.try_acquire(1).is_ok());
+        assert!(limiter.try_acquire(1).is_err());
+        tokio::time::advance(Duration::from_secs(60)).await;
+        assert!(limiter.try_acquire(1).is_ok());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn hour_quota_applies_independently_of_minute_quota() {
+        let limiter = limiter(Some((10, 2)), None, RateLimitMode::FailFast);
+        assert!(limiter.try_acquire(1).is_ok());
+        assert!(limiter.try_acquire(1).is_ok());
+        match limiter.try_acquire(1) {
+            Err(ACSError::QuotaExhausted { retry_after }) => {
+                assert!(retry_after > Duration::from_secs(60));
+            }
+            other => panic!("expected QuotaExhausted, got {other:?}"),
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn recipients_are_counted() {
+        let limiter = limiter(None, Some((5, 100)), RateLimitMode::FailFast);
+        assert!(limiter.try_acquire(3).is_ok());
+        assert!(limiter.try_acquire(3).is_err());
+        assert!(limiter.try_acquire(2).is_ok());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn failed_try_acquire_consumes_nothing() {
+        let limiter = limiter(Some((1, 100)), Some((10, 100)), RateLimitMode::FailFast);
+        assert!(limiter.try_acquire(1).is_ok());
+        assert!(limiter.try_acquire(9).is_err());
+        tokio::time::advance(Duration::from_secs(60)).await;
+        assert!(limiter.try_acquire(9).is_ok());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn acquire_waits_for_capacity() {
+        let limiter = limiter(Some((1, 100)), None, RateLimitMode::Wait);
+        let started = Instant::now();
+        limiter.acquire(1).await;
+        limiter.acquire(1).await;
+        assert!(started.elapsed() >= Duration::from_secs(60));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn abandoned_wait_refunds_its_reservation() {
+        let limiter = limiter(Some((1, 100)), Some((5, 100)), RateLimitMode::Wait);
+        limiter.acquire(2).await;
+        let wait = tokio::time::timeout(Duration::from_secs(1), limiter.acquire(2)).await;
+        assert!(wait.is_err());
+
+        // Only the first send's cost is outstanding: the request bucket has
+        // a token again one minute after it, not two.
+        tokio::time::advance(Duration::from_secs(60)).await;
+        assert!(limiter.try_acquire(3).is_ok());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn oversized_send_waits_for_full_bucket() {
+        let limiter = limiter(None, Some((5, 100)), RateLimitMode::FailFast);
+        assert!(limiter.try_acquire(50).is_ok());
+        assert!(limiter.try_acquire(1).is_err());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn clones_share_buckets() {
+        let limiter = limiter(Some((1, 100)), None, RateLimitMode::FailFast);
+        let clone = limiter.clone();
+        assert!(limiter.try_acquire(1).is_ok());
+        assert!(clone.try_acquire(1).is_err());
+    }
+}
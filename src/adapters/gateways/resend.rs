@@ -0,0 +1,166 @@
+//! Automatic resend of emails whose operation ends `Failed`.
+//!
+//! ACS can accept an email and later fail it for a reason that goes away on
+//! its own (a busy downstream, a transient infrastructure fault).  A
+//! [`ResendPolicy`] lets the waiting APIs send such an email again instead of
+//! returning [`ACSError::DeliveryFailed`]:
+//!
+//! - The failure's [`ErrorDetail::code`] — or the code of any nested detail —
+//!   is looked up in the policy's rules table.  Only listed codes are resent,
+//!   after the rule's delay.
+//! - Every resend is a new operation with a fresh idempotency key, so ACS
+//!   does not deduplicate it against the failed attempt.
+//! - At most [`ResendPolicy::max_attempts`] operations are created per email,
+//!   the original send included.  The waiting deadline covers all attempts.
+//!
+//! The operation IDs of all attempts are returned in
+//! [`SendOutcome::attempts`] — or in the `attempts` of the final
+//! [`ACSError::DeliveryFailed`] when every attempt failed — and each resend publishes
+//! [`EmailEventKind::Resent`] linking it to the attempt it replaces.
+//!
+//! Install a policy per call with [`SendOptions::resend`], or for a client
+//! with [`ACSClientBuilder::resend_policy`], which lists the APIs it covers:
+//!
+//! ```rust,ignore
+//! let policy = ResendPolicy::new(3)
+//!     .rule("ServiceUnavailable", Duration::from_secs(30))
+//!     .rule("InternalServerError", Duration::from_secs(60));
+//! let options = SendOptions::new()
+//!     .resend(policy)
+//!     .wait(WaitMode::Terminal(Duration::from_secs(600)));
+//! let outcome = client.send_email_with(&email, &options).await?;
+//! println!("delivered as {} after {:?}", outcome.operation_id, outcome.attempts);
+//! ```
+//!
+//! [`SendOutcome::attempts`]: crate::adapters::gateways::acs_email::SendOutcome::attempts
+//! [`SendOptions::resend`]: crate::adapters::gateways::acs_email::SendOptions::resend
+//! [`ACSClientBuilder::resend_policy`]: crate::adapters::gateways::acs_email::ACSClientBuilder::resend_policy
+//! [`EmailEventKind::Resent`]: crate::adapters::gateways::events::EmailEventKind::Resent
+
+use crate::domain::entities::models::{ACSError, ErrorDetail};
+use std::time::Duration;
+
+/// Which `Failed` operations to resend, after what delay, and how often.
+#[derive(Clone, Debug)]
+pub struct ResendPolicy {
+    max_attempts: u32,
+    rules: Vec<(String, Duration)>,
+}
+
+impl ResendPolicy {
+    /// A policy that creates at most `max_attempts` operations per email
+    /// (including the original send) and resends nothing until rules are
+    /// added.
+    pub fn new(max_attempts: u32) -> Self {
+        ResendPolicy {
+            max_attempts,
+            rules: Vec::new(),
+        }
+    }
+
+    /// Resend after `delay` when the failure carries error code `code`
+    /// (compared case-insensitively).  Earlier rules win.
+    pub fn rule(mut self, code: &str, delay: Duration) -> Self {
+        self.rules.push((code.to_string(), delay));
+        self
+    }
+
+    /// Maximum number of operations per email, the original send included.
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Delay before resending after `error`, when `attempts` operations have
+    /// been created so far.  `None` when the email must not be resent: the
+    /// error is not a delivery failure, no rule matches its codes, or the
+    /// attempt cap is reached.
+    pub fn resend_delay(&self, error: &ACSError, attempts: u32) -> Option<Duration> {
+        if attempts >= self.max_attempts {
+            return None;
+        }
+        let ACSError::DeliveryFailed {
+            error: Some(detail),
+            ..
+        } = error
+        else {
+            return None;
+        };
+        let mut codes = Vec::new();
+        collect_codes(detail, &mut codes);
+        self.rules.iter().find_map(|(code, delay)| {
+            codes
+                .iter()
+                .any(|candidate| candidate.eq_ignore_ascii_case(code))
+                .then_some(*delay)
+        })
+    }
+}
+
+/// Every error code in `detail` and its nested details, outermost first.
+fn collect_codes<'a>(detail: &'a ErrorDetail, codes: &mut Vec<&'a str>) {
+    if let Some(code) = detail.code.as_deref() {
+        codes.push(code);
+    }
+    for nested in detail.details.iter().flatten() {
+        collect_codes(nested, codes);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn failed(code: &str, nested: Option<&str>) -> ACSError {
+        let detail = |code: &str| ErrorDetail {
+            additional_info: None,
+            code: Some(code.to_string()),
+            message: None,
+            target: None,
+            details: None,
+        };
+        let mut outer = detail(code);
+        outer.details = nested.map(|nested| vec![detail(nested)]);
+        ACSError::DeliveryFailed {
+            operation_id: "op-1".to_string(),
+            error: Some(Box::new(outer)),
+            attempts: vec!["op-1".to_string()],
+        }
+    }
+
+    #[test]
+    fn matches_top_level_and_nested_codes() {
+        let policy = ResendPolicy::new(3)
+            .rule("ServiceUnavailable", Duration::from_secs(30))
+            .rule("Throttled", Duration::from_secs(60));
+        assert_eq!(
+            policy.resend_delay(&failed("serviceunavailable", None), 1),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(
+            policy.resend_delay(&failed("DeliveryFailed", Some("Throttled")), 1),
+            Some(Duration::from_secs(60))
+        );
+        assert_eq!(
+            policy.resend_delay(&failed("InvalidRecipient", None), 1),
+            None
+        );
+    }
+
+    #[test]
+    fn stops_at_attempt_cap_and_ignores_other_errors() {
+        let policy = ResendPolicy::new(2).rule("ServiceUnavailable", Duration::ZERO);
+        assert!(policy
+            .resend_delay(&failed("ServiceUnavailable", None), 1)
+            .is_some());
+        assert!(policy
+            .resend_delay(&failed("ServiceUnavailable", None), 2)
+            .is_none());
+        assert!(policy.resend_delay(&ACSError::Timeout, 1).is_none());
+        let no_detail = ACSError::DeliveryFailed {
+            operation_id: "op-1".to_string(),
+            error: None,
+            attempts: vec!["op-1".to_string()],
+        };
+        assert!(policy.resend_delay(&no_detail, 1).is_none());
+    }
+}
@@ -0,0 +1,541 @@
+//! Retry policies for requests sent by [`ACSClient`].
+//!
+//! A [`RetryPolicy`] decides three things for every failed attempt:
+//!
+//! 1. Whether the failure is retryable at all — by HTTP status code
+//!    ([`RetryPolicy::is_retryable_status`]) or by [`ACSError`] kind
+//!    ([`RetryPolicy::is_retryable_error`]).
+//! 2. How long to wait before the next attempt ([`RetryPolicy::backoff`]).
+//!    A `Retry-After` header sent by the service always takes precedence over
+//!    the computed back-off.
+//! 3. When to give up — after [`RetryPolicy::max_retries`] retries, or once
+//!    the next attempt would start later than [`RetryPolicy::max_elapsed`]
+//!    after the first one.
+//!
+//! Two policies are built in:
+//!
+//! | Policy | Back-off |
+//! |---|---|
+//! | [`ExponentialBackoff`] (default) | Random delay in `[0, min(max_delay, base_delay · 2^n)]` ("full jitter") |
+//! | [`FixedDelay`] | The same delay before every retry |
+//!
+//! Both retry `429`, `500`, `502`, `503` and `504` responses and
+//! [`ACSError::Network`] failures (connection resets, timeouts, DNS errors)
+//! by default.  The same policy governs `emails:send` requests and
+//! status polls.  Every `emails:send` attempt carries the same
+//! `repeatability-request-id` — the caller's idempotency key, or one generated
+//! for the call — so ACS does not deliver an email twice when a request it
+//! accepted is retried after a timeout or `5xx`.
+//!
+//! # Server throttling hints
+//!
+//! Azure services signal how long to back off in several formats, all of which
+//! are honoured (first match wins):
+//!
+//! | Header | Format |
+//! |---|---|
+//! | `retry-after-ms` | Milliseconds |
+//! | `x-ms-retry-after-ms` | Milliseconds |
+//! | `Retry-After` | Seconds (integer or fractional) or an HTTP-date |
+//!
+//! The requested delay is clamped to [`RetryPolicy::max_retry_after`]
+//! (default: 60 s) so a misbehaving proxy cannot park a request indefinitely.
+//!
+//! [`ACSClient`]: crate::adapters::gateways::acs_email::ACSClient
+
+use crate::domain::entities::models::{ACSError, ACSErrorKind};
+use rand::Rng;
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use reqwest::StatusCode;
+use std::fmt::Debug;
+use std::time::{Duration, SystemTime};
+
+/// Default number of retries after the initial attempt.
+pub const DEFAULT_MAX_RETRIES: u32 = 3;
+
+/// Default upper bound on a server-requested `Retry-After` delay.
+pub const DEFAULT_MAX_RETRY_AFTER: Duration = Duration::from_secs(60);
+
+/// Millisecond throttling headers, in order of precedence over `Retry-After`.
+const RETRY_AFTER_MS_HEADERS: [&str; 2] = ["retry-after-ms", "x-ms-retry-after-ms"];
+
+/// Status codes retried by the built-in policies unless configured otherwise.
+const DEFAULT_RETRYABLE_STATUSES: [StatusCode; 5] = [
+    StatusCode::TOO_MANY_REQUESTS,
+    StatusCode::INTERNAL_SERVER_ERROR,
+    StatusCode::BAD_GATEWAY,
+    StatusCode::SERVICE_UNAVAILABLE,
+    StatusCode::GATEWAY_TIMEOUT,
+];
+
+/// Error kinds retried by the built-in policies unless configured otherwise.
+const DEFAULT_RETRYABLE_ERRORS: [ACSErrorKind; 1] = [ACSErrorKind::Network];
+
+/// Decides whether, when and how often a failed request is retried.
+///
+/// Install a policy with [`ACSClientBuilder::retry_policy`].  Implementations
+/// must be cheap to call — they are consulted on every failed attempt — and
+/// are shared across all clones of the client.
+///
+/// [`ACSClientBuilder::retry_policy`]: crate::adapters::gateways::acs_email::ACSClientBuilder::retry_policy
+pub trait RetryPolicy: Debug + Send + Sync {
+    /// Maximum number of retries after the initial attempt.
+    fn max_retries(&self) -> u32;
+
+    /// Upper bound on the total time spent on one logical request, measured
+    /// from the start of the first attempt.  `None` means unbounded.
+    fn max_elapsed(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Delay before retry number `retry` (0-based).
+    fn backoff(&self, retry: u32) -> Duration;
+
+    /// Upper bound applied to a delay requested by the server through a
+    /// throttling header.  Default: [`DEFAULT_MAX_RETRY_AFTER`].
+    fn max_retry_after(&self) -> Duration {
+        DEFAULT_MAX_RETRY_AFTER
+    }
+
+    /// Whether a response with `status` should be retried.
+    fn is_retryable_status(&self, status: StatusCode) -> bool;
+
+    /// Whether a request that failed with `error` before a response was
+    /// received should be retried.
+    fn is_retryable_error(&self, error: &ACSError) -> bool;
+}
+
+/// Which failures a built-in policy retries.
+#[derive(Clone, Debug)]
+struct RetryConditions {
+    statuses: Vec<StatusCode>,
+    errors: Vec<ACSErrorKind>,
+}
+
+impl Default for RetryConditions {
+    fn default() -> Self {
+        RetryConditions {
+            statuses: DEFAULT_RETRYABLE_STATUSES.to_vec(),
+            errors: DEFAULT_RETRYABLE_ERRORS.to_vec(),
+        }
+    }
+}
+
+impl RetryConditions {
+    fn matches_status(&self, status: StatusCode) -> bool {
+        self.statuses.contains(&status)
+    }
+
+    fn matches_error(&self, error: &ACSError) -> bool {
+        self.errors.contains(&error.kind())
+    }
+}
+
+/// Exponential back-off with full jitter — the default policy.
+///
+/// Retry `n` waits a uniformly random delay in
+/// `[0, min(max_delay, base_delay · 2^n)]`, which spreads retries of many
+/// concurrent requests instead of synchronising them into bursts.
+///
+/// Defaults: 3 retries, 1 s base delay, 30 s maximum delay, no elapsed-time cap.
+///
+/// ```rust,ignore
+/// let policy = ExponentialBackoff::new()
+///     .max_retries(5)
+///     .base_delay(Duration::from_millis(500))
+///     .max_elapsed(Duration::from_secs(60));
+/// let client = ACSClientBuilder::new()
+///     .connection_string(&conn_str)
+///     .retry_policy(policy)
+///     .build()?;
+/// ```
+#[derive(Clone, Debug)]
+pub struct ExponentialBackoff {
+    max_retries: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+    max_elapsed: Option<Duration>,
+    max_retry_after: Duration,
+    conditions: RetryConditions,
+}
+
+impl Default for ExponentialBackoff {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ExponentialBackoff {
+    /// Create a policy with the default settings.
+    pub fn new() -> Self {
+        ExponentialBackoff {
+            max_retries: DEFAULT_MAX_RETRIES,
+            base_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(30),
+            max_elapsed: None,
+            max_retry_after: DEFAULT_MAX_RETRY_AFTER,
+            conditions: RetryConditions::default(),
+        }
+    }
+
+    /// Maximum number of retries after the initial attempt. Default: `3`.
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Upper bound of the jitter window for the first retry. Default: 1 s.
+    pub fn base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// Cap on the jitter window for any retry. Default: 30 s.
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Give up once the next attempt would start more than `max_elapsed`
+    /// after the first one. Default: unbounded.
+    pub fn max_elapsed(mut self, max_elapsed: Duration) -> Self {
+        self.max_elapsed = Some(max_elapsed);
+        self
+    }
+
+    /// Clamp server-requested `Retry-After` delays to at most `max`. Default: 60 s.
+    pub fn max_retry_after(mut self, max: Duration) -> Self {
+        self.max_retry_after = max;
+        self
+    }
+
+    /// Replace the set of retryable HTTP status codes.
+    pub fn retryable_statuses(mut self, statuses: &[StatusCode]) -> Self {
+        self.conditions.statuses = statuses.to_vec();
+        self
+    }
+
+    /// Replace the set of retryable [`ACSError`] kinds.
+    pub fn retryable_errors(mut self, kinds: &[ACSErrorKind]) -> Self {
+        self.conditions.errors = kinds.to_vec();
+        self
+    }
+}
+
+impl RetryPolicy for ExponentialBackoff {
+    fn max_retries(&self) -> u32 {
+        self.max_retries
+    }
+
+    fn max_elapsed(&self) -> Option<Duration> {
+        self.max_elapsed
+    }
+
+    fn max_retry_after(&self) -> Duration {
+        self.max_retry_after
+    }
+
+    fn backoff(&self, retry: u32) -> Duration {
+        let window = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(retry))
+            .min(self.max_delay);
+        full_jitter(window)
+    }
+
+    fn is_retryable_status(&self, status: StatusCode) -> bool {
+        self.conditions.matches_status(status)
+    }
+
+    fn is_retryable_error(&self, error: &ACSError) -> bool {
+        self.conditions.matches_error(error)
+    }
+}
+
+/// Constant delay between retries.
+///
+/// Defaults: 3 retries, 1 s delay, no elapsed-time cap.
+#[derive(Clone, Debug)]
+pub struct FixedDelay {
+    max_retries: u32,
+    delay: Duration,
+    max_elapsed: Option<Duration>,
+    max_retry_after: Duration,
+    conditions: RetryConditions,
+}
+
+impl FixedDelay {
+    /// Create a policy that waits `delay` before every retry.
+    pub fn new(delay: Duration) -> Self {
+        FixedDelay {
+            max_retries: DEFAULT_MAX_RETRIES,
+            delay,
+            max_elapsed: None,
+            max_retry_after: DEFAULT_MAX_RETRY_AFTER,
+            conditions: RetryConditions::default(),
+        }
+    }
+
+    /// Maximum number of retries after the initial attempt. Default: `3`.
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Give up once the next attempt would start more than `max_elapsed`
+    /// after the first one. Default: unbounded.
+    pub fn max_elapsed(mut self, max_elapsed: Duration) -> Self {
+        self.max_elapsed = Some(max_elapsed);
+        self
+    }
+
+    /// Clamp server-requested `Retry-After` delays to at most `max`. Default: 60 s.
+    pub fn max_retry_after(mut self, max: Duration) -> Self {
+        self.max_retry_after = max;
+        self
+    }
+
+    /// Replace the set of retryable HTTP status codes.
+    pub fn retryable_statuses(mut self, statuses: &[StatusCode]) -> Self {
+        self.conditions.statuses = statuses.to_vec();
+        self
+    }
+
+    /// Replace the set of retryable [`ACSError`] kinds.
+    pub fn retryable_errors(mut self, kinds: &[ACSErrorKind]) -> Self {
+        self.conditions.errors = kinds.to_vec();
+        self
+    }
+}
+
+impl RetryPolicy for FixedDelay {
+    fn max_retries(&self) -> u32 {
+        self.max_retries
+    }
+
+    fn max_elapsed(&self) -> Option<Duration> {
+        self.max_elapsed
+    }
+
+    fn max_retry_after(&self) -> Duration {
+        self.max_retry_after
+    }
+
+    fn backoff(&self, _retry: u32) -> Duration {
+        self.delay
+    }
+
+    fn is_retryable_status(&self, status: StatusCode) -> bool {
+        self.conditions.matches_status(status)
+    }
+
+    fn is_retryable_error(&self, error: &ACSError) -> bool {
+        self.conditions.matches_error(error)
+    }
+}
+
+/// Extract the delay requested by a throttling response, if any.
+///
+/// Checks `retry-after-ms`, `x-ms-retry-after-ms` and `Retry-After` in that
+/// order.  `Retry-After` may carry (fractional) seconds or an HTTP-date, which
+/// is converted to a delay relative to `now`; a date in the past yields zero.
+/// Unparseable values are ignored so the caller falls back to its own back-off.
+pub(crate) fn parse_retry_after(headers: &HeaderMap, now: SystemTime) -> Option<Duration> {
+    let header_str = |name: &str| headers.get(name).and_then(|v| v.to_str().ok());
+
+    for name in RETRY_AFTER_MS_HEADERS {
+        if let Some(ms) = header_str(name).and_then(|v| v.trim().parse::<f64>().ok()) {
+            if let Ok(delay) = Duration::try_from_secs_f64(ms / 1000.0) {
+                return Some(delay);
+            }
+        }
+    }
+
+    let value = header_str(RETRY_AFTER.as_str())?.trim();
+    if let Ok(secs) = value.parse::<f64>() {
+        return Duration::try_from_secs_f64(secs).ok();
+    }
+    httpdate::parse_http_date(value)
+        .ok()
+        .map(|date| date.duration_since(now).unwrap_or(Duration::ZERO))
+}
+
+/// Pick a uniformly random duration in `[0, window]`.
+fn full_jitter(window: Duration) -> Duration {
+    let max_nanos = u64::try_from(window.as_nanos()).unwrap_or(u64::MAX);
+    if max_nanos == 0 {
+        return Duration::ZERO;
+    }
+    Duration::from_nanos(rand::thread_rng().gen_range(0..=max_nanos))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exponential_defaults() {
+        let policy = ExponentialBackoff::default();
+        assert_eq!(RetryPolicy::max_retries(&policy), DEFAULT_MAX_RETRIES);
+        assert_eq!(RetryPolicy::max_elapsed(&policy), None);
+    }
+
+    #[test]
+    fn exponential_backoff_stays_within_jitter_window() {
+        let policy = ExponentialBackoff::new()
+            .base_delay(Duration::from_millis(100))
+            .max_delay(Duration::from_secs(1));
+        for _ in 0..100 {
+            assert!(policy.backoff(0) <= Duration::from_millis(100));
+            assert!(policy.backoff(2) <= Duration::from_millis(400));
+            assert!(policy.backoff(10) <= Duration::from_secs(1));
+        }
+    }
+
+    #[test]
+    fn exponential_backoff_large_retry_index_does_not_overflow() {
+        let policy = ExponentialBackoff::new().max_delay(Duration::from_secs(5));
+        assert!(policy.backoff(u32::MAX) <= Duration::from_secs(5));
+    }
+
+    #[test]
+    fn exponential_backoff_zero_base_is_zero() {
+        let policy = ExponentialBackoff::new().base_delay(Duration::ZERO);
+        assert_eq!(policy.backoff(3), Duration::ZERO);
+    }
+
+    #[test]
+    fn default_retryable_statuses() {
+        let policy = ExponentialBackoff::new();
+        for status in [429, 500, 502, 503, 504] {
+            assert!(policy.is_retryable_status(StatusCode::from_u16(status).unwrap()));
+        }
+        for status in [400, 401, 403, 404, 409] {
+            assert!(!policy.is_retryable_status(StatusCode::from_u16(status).unwrap()));
+        }
+    }
+
+    #[test]
+    fn default_retryable_errors_is_network_only() {
+        let policy = ExponentialBackoff::new();
+        assert!(policy.is_retryable_error(&ACSError::Network("reset".to_string())));
+        assert!(!policy.is_retryable_error(&ACSError::Auth("denied".to_string())));
+    }
+
+    #[test]
+    fn retryable_conditions_can_be_replaced() {
+        let policy = FixedDelay::new(Duration::from_millis(10))
+            .retryable_statuses(&[StatusCode::CONFLICT])
+            .retryable_errors(&[ACSErrorKind::Auth]);
+        assert!(policy.is_retryable_status(StatusCode::CONFLICT));
+        assert!(!policy.is_retryable_status(StatusCode::TOO_MANY_REQUESTS));
+        assert!(policy.is_retryable_error(&ACSError::Auth("expired".to_string())));
+        assert!(!policy.is_retryable_error(&ACSError::Network("reset".to_string())));
+    }
+
+    #[test]
+    fn max_retry_after_defaults_and_overrides() {
+        assert_eq!(
+            ExponentialBackoff::new().max_retry_after,
+            DEFAULT_MAX_RETRY_AFTER
+        );
+        let policy = FixedDelay::new(Duration::ZERO).max_retry_after(Duration::from_secs(5));
+        assert_eq!(
+            RetryPolicy::max_retry_after(&policy),
+            Duration::from_secs(5)
+        );
+    }
+
+    fn headers(pairs: &[(&'static str, &str)]) -> HeaderMap {
+        let mut map = HeaderMap::new();
+        for (name, value) in pairs {
+            map.insert(*name, value.parse().unwrap());
+        }
+        map
+    }
+
+    #[test]
+    fn parse_retry_after_absent_is_none() {
+        assert_eq!(
+            parse_retry_after(&HeaderMap::new(), SystemTime::now()),
+            None
+        );
+    }
+
+    #[test]
+    fn parse_retry_after_integer_seconds() {
+        let h = headers(&[("retry-after", "7")]);
+        assert_eq!(
+            parse_retry_after(&h, SystemTime::now()),
+            Some(Duration::from_secs(7))
+        );
+    }
+
+    #[test]
+    fn parse_retry_after_fractional_seconds() {
+        let h = headers(&[("retry-after", "1.5")]);
+        assert_eq!(
+            parse_retry_after(&h, SystemTime::now()),
+            Some(Duration::from_millis(1500))
+        );
+    }
+
+    #[test]
+    fn parse_retry_after_http_date() {
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let date = httpdate::fmt_http_date(now + Duration::from_secs(30));
+        let h = headers(&[("retry-after", &date)]);
+        assert_eq!(parse_retry_after(&h, now), Some(Duration::from_secs(30)));
+    }
+
+    #[test]
+    fn parse_retry_after_http_date_in_the_past_is_zero() {
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let date = httpdate::fmt_http_date(now - Duration::from_secs(30));
+        let h = headers(&[("retry-after", &date)]);
+        assert_eq!(parse_retry_after(&h, now), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn parse_retry_after_ms_headers() {
+        let h = headers(&[("retry-after-ms", "250")]);
+        assert_eq!(
+            parse_retry_after(&h, SystemTime::now()),
+            Some(Duration::from_millis(250))
+        );
+        let h = headers(&[("x-ms-retry-after-ms", "1200")]);
+        assert_eq!(
+            parse_retry_after(&h, SystemTime::now()),
+            Some(Duration::from_millis(1200))
+        );
+    }
+
+    #[test]
+    fn parse_retry_after_ms_takes_precedence() {
+        let h = headers(&[("retry-after", "30"), ("x-ms-retry-after-ms", "100")]);
+        assert_eq!(
+            parse_retry_after(&h, SystemTime::now()),
+            Some(Duration::from_millis(100))
+        );
+    }
+
+    #[test]
+    fn parse_retry_after_garbage_is_ignored() {
+        let h = headers(&[("retry-after", "soon"), ("retry-after-ms", "-5")]);
+        assert_eq!(parse_retry_after(&h, SystemTime::now()), None);
+    }
+
+    #[test]
+    fn fixed_delay_is_constant() {
+        let policy = FixedDelay::new(Duration::from_millis(250))
+            .max_retries(9)
+            .max_elapsed(Duration::from_secs(2));
+        assert_eq!(policy.backoff(0), Duration::from_millis(250));
+        assert_eq!(policy.backoff(7), Duration::from_millis(250));
+        assert_eq!(RetryPolicy::max_retries(&policy), 9);
+        assert_eq!(
+            RetryPolicy::max_elapsed(&policy),
+            Some(Duration::from_secs(2))
+        );
+    }
+}
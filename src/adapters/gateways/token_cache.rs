@@ -0,0 +1,273 @@
+//! Shared bearer-token cache for Entra ID authentication.
+//!
+//! Service principal and managed identity clients authenticate every ACS
+//! request with a bearer token.  Fetching one costs a round trip to Entra ID
+//! or the managed identity endpoint, so the client keeps its credential and
+//! the current token in a [`TokenCache`] shared by all of its clones:
+//!
+//! - A cached token is reused until it is within the refresh margin of its
+//!   expiry (default [`DEFAULT_REFRESH_MARGIN`]), then refreshed ahead of time.
+//!   If that early refresh fails while the old token is still valid, the old
+//!   token is used and the refresh is retried on the next request.
+//! - Refreshes are single-flight: concurrent requests that need a new token
+//!   wait for one fetch instead of each starting their own.
+//! - A `401 Unauthorized` from ACS forces one refresh (again single-flight)
+//!   and the request is retried once with the new token.
+//!
+//! ```rust,ignore
+//! let client = ACSClientBuilder::new()
+//!     .host(&host)
+//!     .managed_identity()
+//!     .token_refresh_margin(Duration::from_secs(600))
+//!     .build()?;
+//! client.send_email(&email).await?;
+//! if let Some(cache) = client.token_cache() {
+//!     println!("token fetches so far: {}", cache.refreshes());
+//! }
+//! ```
+
+use crate::adapters::gateways::acs_email::auth_err;
+use crate::domain::entities::models::ACSError;
+use azure_core::auth::{AccessToken, TokenCredential};
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use time::OffsetDateTime;
+use tokio::sync::Mutex;
+use tracing::{debug, warn};
+
+/// Default time before expiry at which a cached token is refreshed.
+pub const DEFAULT_REFRESH_MARGIN: Duration = Duration::from_secs(300);
+
+struct Inner {
+    credential: Arc<dyn TokenCredential>,
+    scope: String,
+    refresh_margin: Duration,
+    /// Held while a token is checked or fetched, making refreshes single-flight.
+    current: Mutex<Option<AccessToken>>,
+    /// Incremented on every successful fetch.
+    generation: AtomicU64,
+}
+
+/// Bearer token cache shared by the clones of an `ACSClient`.
+///
+/// Obtain it with [`ACSClient::token_cache`].
+///
+/// [`ACSClient::token_cache`]: crate::adapters::gateways::acs_email::ACSClient::token_cache
+#[derive(Clone)]
+pub struct TokenCache {
+    inner: Arc<Inner>,
+}
+
+impl fmt::Debug for TokenCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TokenCache")
+            .field("scope", &self.inner.scope)
+            .field("refresh_margin", &self.inner.refresh_margin)
+            .field("refreshes", &self.refreshes())
+            .finish()
+    }
+}
+
+impl TokenCache {
+    /// Cache tokens for `scope` obtained from `credential`.
+    pub(crate) fn new(
+        credential: Arc<dyn TokenCredential>,
+        scope: &str,
+        refresh_margin: Duration,
+    ) -> Self {
+        TokenCache {
+            inner: Arc::new(Inner {
+                credential,
+                scope: scope.to_string(),
+                refresh_margin,
+                current: Mutex::new(None),
+                generation: AtomicU64::new(0),
+            }),
+        }
+    }
+
+    /// A valid token, from the cache or freshly fetched.
+    ///
+    /// # Errors
+    ///
+    /// [`ACSError::Auth`] when a token is needed and the credential fails.
+    pub async fn token(&self) -> Result<String, ACSError> {
+        self.token_with_generation().await.map(|(token, _)| token)
+    }
+
+    /// [`token`](TokenCache::token) together with the value of
+    /// [`refreshes`](TokenCache::refreshes) it was fetched at, read under the
+    /// same lock so a concurrent refresh cannot fall in between.
+    pub(crate) async fn token_with_generation(&self) -> Result<(String, u64), ACSError> {
+        let mut current = self.inner.current.lock().await;
+        let now = OffsetDateTime::now_utc();
+        if let Some(token) = current.as_ref() {
+            if token.expires_on - self.inner.refresh_margin > now {
+                return Ok((token.token.secret().to_owned(), self.refreshes()));
+            }
+        }
+        match self.fetch(current.is_some()).await {
+            Ok(token) => {
+                let secret = token.token.secret().to_owned();
+                *current = Some(token);
+                Ok((secret, self.refreshes()))
+            }
+            Err(e) => match current.as_ref() {
+                Some(token) if token.expires_on > now => {
+                    warn!(error = %e, "Early token refresh failed; using the current token");
+                    Ok((token.token.secret().to_owned(), self.refreshes()))
+                }
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Discard the cached token and fetch a new one.
+    ///
+    /// # Errors
+    ///
+    /// [`ACSError::Auth`] when the credential fails.
+    pub async fn refresh(&self) -> Result<String, ACSError> {
+        let mut current = self.inner.current.lock().await;
+        let token = self.fetch(true).await?;
+        let secret = token.token.secret().to_owned();
+        *current = Some(token);
+        Ok(secret)
+    }
+
+    /// Number of tokens fetched so far, by this client and all its clones.
+    pub fn refreshes(&self) -> u64 {
+        self.inner.generation.load(Ordering::Acquire)
+    }
+
+    /// Refresh after ACS rejected a token obtained when [`refreshes`] was
+    /// `seen`, unless another request has refreshed since.
+    ///
+    /// [`refreshes`]: TokenCache::refreshes
+    pub(crate) async fn refresh_rejected(&self, seen: u64) -> Result<(), ACSError> {
+        let mut current = self.inner.current.lock().await;
+        if self.refreshes() != seen {
+            return Ok(());
+        }
+        *current = Some(self.fetch(true).await?);
+        Ok(())
+    }
+
+    /// Fetch a new token; `bypass` clears the credential's own cache first so
+    /// it cannot hand back the token being replaced.
+    async fn fetch(&self, bypass: bool) -> Result<AccessToken, ACSError> {
+        if bypass {
+            let _ = self.inner.credential.clear_cache().await;
+        }
+        let token = self
+            .inner
+            .credential
+            .get_token(&[self.inner.scope.as_str()])
+            .await
+            .map_err(|e| auth_err(format!("Failed to get access token: {}", e)))?;
+        let generation = self.inner.generation.fetch_add(1, Ordering::AcqRel) + 1;
+        debug!(generation, expires_on = %token.expires_on, "Fetched access token");
+        Ok(token)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use azure_core::auth::Secret;
+    use std::sync::atomic::AtomicUsize;
+
+    /// Hands out `token-1`, `token-2`, … valid for `lifetime`; fails while
+    /// `failing` is set.
+    #[derive(Debug)]
+    struct CountingCredential {
+        calls: AtomicUsize,
+        lifetime: Duration,
+        failing: std::sync::atomic::AtomicBool,
+    }
+
+    impl CountingCredential {
+        fn new(lifetime: Duration) -> Arc<Self> {
+            Arc::new(CountingCredential {
+                calls: AtomicUsize::new(0),
+                lifetime,
+                failing: std::sync::atomic::AtomicBool::new(false),
+            })
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl TokenCredential for CountingCredential {
+        async fn get_token(&self, _scopes: &[&str]) -> azure_core::Result<AccessToken> {
+            if self.failing.load(Ordering::SeqCst) {
+                return Err(azure_core::Error::message(
+                    azure_core::error::ErrorKind::Credential,
+                    "unavailable",
+                ));
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            let n = self.calls.fetch_add(1, Ordering::SeqCst) + 1;
+            Ok(AccessToken::new(
+                Secret::new(format!("token-{n}")),
+                OffsetDateTime::now_utc() + self.lifetime,
+            ))
+        }
+
+        async fn clear_cache(&self) -> azure_core::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn concurrent_requests_share_one_fetch() {
+        let credential = CountingCredential::new(Duration::from_secs(3600));
+        let cache = TokenCache::new(credential.clone(), "scope", DEFAULT_REFRESH_MARGIN);
+        let tokens = futures::future::join_all(
+            (0..10)
+                .map(|_| cache.clone())
+                .map(|cache| async move { cache.token().await.unwrap() }),
+        )
+        .await;
+        assert!(tokens.iter().all(|token| token == "token-1"));
+        assert_eq!(credential.calls.load(Ordering::SeqCst), 1);
+        assert_eq!(cache.refreshes(), 1);
+    }
+
+    #[tokio::test]
+    async fn token_inside_refresh_margin_is_refreshed_early() {
+        let credential = CountingCredential::new(Duration::from_secs(200));
+        let cache = TokenCache::new(credential.clone(), "scope", DEFAULT_REFRESH_MARGIN);
+        assert_eq!(cache.token().await.unwrap(), "token-1");
+        assert_eq!(cache.token().await.unwrap(), "token-2");
+
+        // A failed early refresh falls back to the still-valid token.
+        credential.failing.store(true, Ordering::SeqCst);
+        let (token, generation) = cache.token_with_generation().await.unwrap();
+        assert_eq!((token.as_str(), generation), ("token-2", 2));
+    }
+
+    #[tokio::test]
+    async fn rejected_token_is_refreshed_once() {
+        let credential = CountingCredential::new(Duration::from_secs(3600));
+        let cache = TokenCache::new(credential.clone(), "scope", DEFAULT_REFRESH_MARGIN);
+        let (token, seen) = cache.token_with_generation().await.unwrap();
+        assert_eq!((token.as_str(), seen), ("token-1", 1));
+        let (first, second) =
+            tokio::join!(cache.refresh_rejected(seen), cache.refresh_rejected(seen));
+        first.unwrap();
+        second.unwrap();
+        let (token, generation) = cache.token_with_generation().await.unwrap();
+        assert_eq!((token.as_str(), generation), ("token-2", 2));
+        assert_eq!(credential.calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn credential_failure_without_token_is_auth_error() {
+        let credential = CountingCredential::new(Duration::from_secs(3600));
+        credential.failing.store(true, Ordering::SeqCst);
+        let cache = TokenCache::new(credential, "scope", DEFAULT_REFRESH_MARGIN);
+        assert!(matches!(cache.token().await, Err(ACSError::Auth(_))));
+    }
+}
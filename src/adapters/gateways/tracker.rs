@@ -0,0 +1,559 @@
+//! Shared status poller for pending ACS email operations.
+//!
+//! Every [`ACSClient`] owns one [`OperationTracker`].  Instead of each
+//! waiting helper running its own sleep-then-GET loop, they all subscribe to
+//! the tracker, which runs a single scheduler task for every pending
+//! operation of the client and its clones:
+//!
+//! - Each operation is polled on the schedule of the client's
+//!   [`PollStrategy`], counted from when it was first tracked (or from a
+//!   resumed poller's attempt count), or after the delay a status response
+//!   requested with `Retry-After`.  One GET serves
+//!   all subscribers of that operation.
+//! - Status GETs are spread out by an optional client-wide rate limit
+//!   ([`ACSClientBuilder::status_poll_rate`]) and at most
+//!   [`MAX_CONCURRENT_POLLS`] run at once.  Each result is handled as soon as
+//!   its GET completes, so a GET held up by retries only delays its own
+//!   operation.
+//! - Every poll result is fanned out to the operation's subscribers.  An
+//!   operation is evicted once it reaches a terminal status, a poll fails, or
+//!   all of its subscribers have gone away (the last only without a
+//!   [`DeliverySla`]).
+//! - New statuses, terminal statuses and poll errors are also published on
+//!   the client's event bus (see [`ACSClient::subscribe`]).
+//! - With a [`DeliverySla`] configured, every operation the client sends is
+//!   tracked — polled without subscribers if nobody waits on it — until it
+//!   reaches a terminal status, and the scheduler wakes up at each delivery
+//!   deadline to report operations that are still pending.
+//!
+//! The scheduler task is started on the first subscription and exits when no
+//! operations are left, so an idle client holds no background task.
+//!
+//! [`ACSClient`]: crate::adapters::gateways::acs_email::ACSClient
+//! [`ACSClient::subscribe`]: crate::adapters::gateways::acs_email::ACSClient::subscribe
+//! [`PollStrategy`]: crate::adapters::gateways::poll::PollStrategy
+//! [`DeliverySla`]: crate::adapters::gateways::watchdog::DeliverySla
+//! [`ACSClientBuilder::status_poll_rate`]: crate::adapters::gateways::acs_email::ACSClientBuilder::status_poll_rate
+
+use crate::adapters::gateways::acs_email::{
+    is_terminal_status, ACSApiVersion, ACSClient, EmailOperation,
+};
+use crate::adapters::gateways::poll::next_poll_delay;
+use crate::adapters::gateways::retry::parse_retry_after;
+use crate::adapters::gateways::watchdog::{DeliverySla, SlaSummary, Watch};
+use crate::domain::entities::models::{ACSError, EmailSendStatusType};
+use futures::stream::Stream;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use std::time::{Duration, SystemTime};
+use tokio::sync::{mpsc, Notify};
+use tokio::task::{Id, JoinHandle, JoinSet};
+use tokio::time::{sleep_until, Instant};
+use tokio_util::sync::CancellationToken;
+use tokio_util::task::TaskTracker;
+use tracing::debug;
+
+/// Maximum number of status GETs the tracker runs concurrently.
+pub const MAX_CONCURRENT_POLLS: usize = 16;
+
+type StatusResult = Result<EmailSendStatusType, ACSError>;
+
+/// Operations whose GET may start now, with the API version to poll them at.
+type DuePolls = Vec<(String, ACSApiVersion)>;
+
+/// A pending operation and the subscribers waiting on it.
+#[derive(Debug)]
+struct Tracked {
+    subscribers: Vec<mpsc::UnboundedSender<StatusResult>>,
+    next_poll: Instant,
+    /// A status GET for the operation is running.
+    in_flight: bool,
+    /// Polls completed so far.
+    attempts: u32,
+    /// API version of the status GETs.
+    api_version: ACSApiVersion,
+    /// Status seen by the latest poll, for change detection.
+    last_status: Option<EmailSendStatusType>,
+    /// Delivery deadline, when a [`DeliverySla`] is configured.
+    watch: Option<Watch>,
+}
+
+#[derive(Debug, Default)]
+struct TrackerState {
+    operations: HashMap<String, Tracked>,
+    running: bool,
+    /// Set by `shutdown`; no new operations are accepted afterwards.
+    closed: bool,
+}
+
+#[derive(Debug)]
+struct Inner {
+    state: Mutex<TrackerState>,
+    wake: Notify,
+    poll_rate: Option<u32>,
+    sla: Option<DeliverySla>,
+    /// The scheduler task and every callback task of the client.
+    tasks: TaskTracker,
+    /// Stops the scheduler once the shutdown grace period is over.
+    cancel: CancellationToken,
+}
+
+/// One polling scheduler shared by all pending operations of an `ACSClient`.
+///
+/// Obtain it with [`ACSClient::operation_tracker`] for monitoring; subscribe
+/// to an operation with [`ACSClient::track_operation`].
+///
+/// [`ACSClient::operation_tracker`]: crate::adapters::gateways::acs_email::ACSClient::operation_tracker
+/// [`ACSClient::track_operation`]: crate::adapters::gateways::acs_email::ACSClient::track_operation
+#[derive(Clone, Debug)]
+pub struct OperationTracker {
+    inner: Arc<Inner>,
+}
+
+/// Status updates for one tracked operation.
+///
+/// Yields one item per poll, ending after the terminal status or the first
+/// poll error.  A `Failed` status is delivered as
+/// [`ACSError::DeliveryFailed`].  Use it as a [`Stream`] or call
+/// [`recv`](OperationSubscription::recv); dropping it unsubscribes.
+#[derive(Debug)]
+pub struct OperationSubscription {
+    operation_id: String,
+    rx: mpsc::UnboundedReceiver<StatusResult>,
+}
+
+impl OperationSubscription {
+    /// The operation this subscription follows.
+    pub fn operation_id(&self) -> &str {
+        &self.operation_id
+    }
+
+    /// Wait for the next update; `None` once the operation has been evicted.
+    pub async fn recv(&mut self) -> Option<StatusResult> {
+        self.rx.recv().await
+    }
+}
+
+impl Stream for OperationSubscription {
+    type Item = StatusResult;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.rx.poll_recv(cx)
+    }
+}
+
+impl OperationTracker {
+    /// A tracker that limits status GETs to `poll_rate` per second, if set,
+    /// and watches pending operations against `sla`, if set.
+    pub(crate) fn new(poll_rate: Option<u32>, sla: Option<DeliverySla>) -> Self {
+        OperationTracker {
+            inner: Arc::new(Inner {
+                state: Mutex::new(TrackerState::default()),
+                wake: Notify::new(),
+                poll_rate: poll_rate.filter(|rate| *rate > 0),
+                sla,
+                tasks: TaskTracker::new(),
+                cancel: CancellationToken::new(),
+            }),
+        }
+    }
+
+    /// Number of operations currently being polled.
+    pub fn pending(&self) -> usize {
+        self.inner.state.lock().unwrap().operations.len()
+    }
+
+    /// `true` while `operation_id` is being polled.
+    pub fn is_tracking(&self, operation_id: &str) -> bool {
+        self.inner
+            .state
+            .lock()
+            .unwrap()
+            .operations
+            .contains_key(operation_id)
+    }
+
+    /// Number of background tasks (the scheduler and callback tasks) that
+    /// have not finished yet.
+    pub fn background_tasks(&self) -> usize {
+        self.inner.tasks.len()
+    }
+
+    /// `true` once the client has been shut down.
+    pub fn is_closed(&self) -> bool {
+        self.inner.state.lock().unwrap().closed
+    }
+
+    /// Operations currently past their delivery SLA; empty without a
+    /// [`DeliverySla`].
+    pub fn sla_summary(&self) -> SlaSummary {
+        let state = self.inner.state.lock().unwrap();
+        let now = SystemTime::now();
+        let mut summary = SlaSummary::default();
+        for (id, tracked) in &state.operations {
+            if let Some(watch) = &tracked.watch {
+                summary.watched += 1;
+                summary
+                    .overdue
+                    .extend(watch.check(id, tracked.last_status, now));
+            }
+        }
+        summary
+            .overdue
+            .sort_by_key(|operation| std::cmp::Reverse(operation.age));
+        summary
+    }
+
+    /// Spawn a task that [`shutdown`](OperationTracker::shutdown) waits for.
+    pub(crate) fn spawn<F>(&self, task: F) -> JoinHandle<F::Output>
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        self.inner.tasks.spawn(task)
+    }
+
+    /// Stop accepting operations, give pending ones up to `grace` to finish,
+    /// then cancel the rest and return their IDs.
+    ///
+    /// Subscribers of cancelled operations receive [`ACSError::Canceled`].
+    pub(crate) async fn shutdown(&self, grace: Duration) -> Vec<String> {
+        self.inner.state.lock().unwrap().closed = true;
+        self.inner.tasks.close();
+        if tokio::time::timeout(grace, self.inner.tasks.wait())
+            .await
+            .is_ok()
+        {
+            return Vec::new();
+        }
+        let mut unresolved: Vec<String> = {
+            let mut state = self.inner.state.lock().unwrap();
+            state
+                .operations
+                .drain()
+                .map(|(id, tracked)| {
+                    for tx in tracked.subscribers {
+                        let _ = tx.send(Err(ACSError::Canceled));
+                    }
+                    id
+                })
+                .collect()
+        };
+        self.inner.cancel.cancel();
+        unresolved.sort();
+        debug!(
+            unresolved = unresolved.len(),
+            "Grace period over; cancelled pending operations"
+        );
+        unresolved
+    }
+
+    /// Subscribe to `operation_id`, starting the scheduler on `client` if it
+    /// is not running.  After shutdown the subscription only yields
+    /// [`ACSError::Canceled`].
+    pub(crate) fn subscribe(
+        &self,
+        client: &ACSClient,
+        operation_id: &str,
+    ) -> OperationSubscription {
+        let first_poll = Instant::now() + client.poll_strategy().delay(0);
+        self.subscribe_from(client, operation_id, client.api_version(), 0, first_poll)
+    }
+
+    /// [`subscribe`](OperationTracker::subscribe) for a poller that has
+    /// already made `attempts` polls: a newly tracked operation continues the
+    /// poll schedule from there, using `api_version`.  The operation is
+    /// polled no later than `first_poll`.
+    pub(crate) fn subscribe_from(
+        &self,
+        client: &ACSClient,
+        operation_id: &str,
+        api_version: &ACSApiVersion,
+        attempts: u32,
+        first_poll: Instant,
+    ) -> OperationSubscription {
+        let (tx, rx) = mpsc::unbounded_channel();
+        if !self.track(
+            client,
+            operation_id,
+            api_version,
+            attempts,
+            first_poll,
+            Some(tx.clone()),
+        ) {
+            let _ = tx.send(Err(ACSError::Canceled));
+        }
+        OperationSubscription {
+            operation_id: operation_id.to_string(),
+            rx,
+        }
+    }
+
+    /// Track `operation_id` without a subscriber, so that the [`DeliverySla`]
+    /// covers it until it reaches a terminal status.  Does nothing without
+    /// an SLA or after shutdown.
+    pub(crate) fn watch(&self, client: &ACSClient, operation_id: &str) {
+        if self.inner.sla.is_some() {
+            let first_poll = Instant::now() + client.poll_strategy().delay(0);
+            self.track(
+                client,
+                operation_id,
+                client.api_version(),
+                0,
+                first_poll,
+                None,
+            );
+        }
+    }
+
+    /// Start tracking `operation_id` as described for
+    /// [`subscribe_from`](OperationTracker::subscribe_from) unless it is
+    /// tracked already, attach `subscriber` and make sure the scheduler is
+    /// running.  `false` after shutdown.
+    fn track(
+        &self,
+        client: &ACSClient,
+        operation_id: &str,
+        api_version: &ACSApiVersion,
+        attempts: u32,
+        first_poll: Instant,
+        subscriber: Option<mpsc::UnboundedSender<StatusResult>>,
+    ) -> bool {
+        let start = {
+            let mut state = self.inner.state.lock().unwrap();
+            if state.closed {
+                return false;
+            }
+            let tracked = state
+                .operations
+                .entry(operation_id.to_string())
+                .or_insert_with(|| Tracked {
+                    subscribers: Vec::new(),
+                    next_poll: first_poll,
+                    in_flight: false,
+                    attempts,
+                    api_version: api_version.clone(),
+                    last_status: None,
+                    watch: self
+                        .inner
+                        .sla
+                        .as_ref()
+                        .map(|sla| sla.watch(client.events().meta(operation_id))),
+                });
+            tracked.next_poll = tracked.next_poll.min(first_poll);
+            tracked.subscribers.extend(subscriber);
+            !std::mem::replace(&mut state.running, true)
+        };
+        if start {
+            self.inner.tasks.spawn(self.clone().run(client.clone()));
+        } else {
+            self.inner.wake.notify_one();
+        }
+        true
+    }
+
+    /// Scheduler task; returns once no operations are left or on shutdown.
+    async fn run(self, client: ACSClient) {
+        tokio::select! {
+            _ = self.inner.cancel.cancelled() => {}
+            _ = self.schedule(&client) => {}
+        }
+    }
+
+    async fn schedule(&self, client: &ACSClient) {
+        debug!("Operation tracker started");
+        let spacing = self
+            .inner
+            .poll_rate
+            .map(|rate| Duration::from_secs_f64(1.0 / f64::from(rate)));
+        let mut next_slot = Instant::now();
+        let mut polls: JoinSet<(String, Result<EmailOperation, ACSError>)> = JoinSet::new();
+        let mut running: HashMap<Id, String> = HashMap::new();
+        loop {
+            let deadline = self.report_overdue(client);
+            let now = Instant::now();
+            let mut capacity = MAX_CONCURRENT_POLLS - polls.len();
+            if spacing.is_some() {
+                // One GET per rate-limit slot.
+                capacity = if next_slot <= now { capacity.min(1) } else { 0 };
+            }
+            let Some((due, next_due)) = self.start_due_polls(capacity) else {
+                debug!("Operation tracker idle; stopping");
+                return;
+            };
+            for (operation_id, api_version) in due {
+                if let Some(spacing) = spacing {
+                    next_slot = next_slot.max(now) + spacing;
+                }
+                let client = client.clone();
+                let poll_id = operation_id.clone();
+                let task = polls.spawn(async move {
+                    let result = client.get_email_operation_at(&poll_id, &api_version).await;
+                    (poll_id, result)
+                });
+                running.insert(task.id(), operation_id);
+            }
+
+            let wake_at = match next_due {
+                // Due, but every poll slot is busy: the next finished GET
+                // wakes the scheduler.
+                Some(due) if due <= now && polls.len() >= MAX_CONCURRENT_POLLS => None,
+                Some(due) if spacing.is_some() => Some(due.max(next_slot)),
+                other => other,
+            };
+            let wake_at = match (wake_at, deadline) {
+                (Some(due), Some(deadline)) => Some(due.min(deadline)),
+                (due, deadline) => due.or(deadline),
+            };
+            tokio::select! {
+                Some(joined) = polls.join_next_with_id(), if !polls.is_empty() => {
+                    match joined {
+                        Ok((task, (id, result))) => {
+                            running.remove(&task);
+                            self.dispatch(client, &id, result);
+                        }
+                        Err(e) => {
+                            if let Some(id) = running.remove(&e.id()) {
+                                self.poll_aborted(&id);
+                            }
+                        }
+                    }
+                }
+                _ = async {
+                    match wake_at {
+                        Some(at) => sleep_until(at).await,
+                        None => std::future::pending().await,
+                    }
+                } => {}
+                _ = self.inner.wake.notified() => {}
+            }
+        }
+    }
+
+    /// Drop abandoned operations, then mark up to `capacity` due operations
+    /// in flight and return their IDs and API versions, along with the
+    /// earliest time an operation not in flight is due (which may already
+    /// have passed).  `None` (and the scheduler marked stopped) when nothing
+    /// is left to track.
+    fn start_due_polls(&self, capacity: usize) -> Option<(DuePolls, Option<Instant>)> {
+        let mut state = self.inner.state.lock().unwrap();
+        state.operations.retain(|_, tracked| {
+            tracked.subscribers.retain(|tx| !tx.is_closed());
+            !tracked.subscribers.is_empty() || tracked.watch.is_some()
+        });
+        if state.operations.is_empty() {
+            state.running = false;
+            return None;
+        }
+        let now = Instant::now();
+        let mut waiting: Vec<(&String, &mut Tracked)> = state
+            .operations
+            .iter_mut()
+            .filter(|(_, tracked)| !tracked.in_flight)
+            .collect();
+        waiting.sort_by_key(|(_, tracked)| tracked.next_poll);
+        let mut due = Vec::new();
+        let mut next_due = None;
+        for (id, tracked) in waiting {
+            if tracked.next_poll <= now && due.len() < capacity {
+                tracked.in_flight = true;
+                due.push((id.clone(), tracked.api_version.clone()));
+            } else {
+                next_due = Some(tracked.next_poll);
+                break;
+            }
+        }
+        Some((due, next_due))
+    }
+
+    /// Make `operation_id` pollable again after its GET task died without
+    /// a result.
+    fn poll_aborted(&self, operation_id: &str) {
+        let mut state = self.inner.state.lock().unwrap();
+        if let Some(tracked) = state.operations.get_mut(operation_id) {
+            tracked.in_flight = false;
+        }
+    }
+
+    /// Report every watched operation that has passed its deadline since the
+    /// last check, and return when the next deadline falls.
+    fn report_overdue(&self, client: &ACSClient) -> Option<Instant> {
+        let sla = self.inner.sla.as_ref()?;
+        let now = SystemTime::now();
+        let mut overdue = Vec::new();
+        let mut next_deadline: Option<Duration> = None;
+        {
+            let mut state = self.inner.state.lock().unwrap();
+            for (id, tracked) in state.operations.iter_mut() {
+                let Some(watch) = tracked.watch.as_mut().filter(|watch| !watch.reported) else {
+                    continue;
+                };
+                match watch.check(id, tracked.last_status, now) {
+                    Some(operation) => {
+                        watch.reported = true;
+                        overdue.push(operation);
+                    }
+                    None => {
+                        let left = watch.deadline().duration_since(now).unwrap_or_default();
+                        next_deadline = Some(next_deadline.map_or(left, |next| next.min(left)));
+                    }
+                }
+            }
+        }
+        for operation in overdue {
+            debug!(
+                operation_id = %operation.operation_id,
+                age = ?operation.age,
+                threshold = ?operation.threshold,
+                "Operation exceeded its delivery SLA"
+            );
+            client.events().overdue(&operation);
+            sla.notify(&operation);
+        }
+        next_deadline.map(|left| Instant::now() + left)
+    }
+
+    /// Fan a poll result out to the operation's subscribers and reschedule or
+    /// evict it.
+    fn dispatch(
+        &self,
+        client: &ACSClient,
+        operation_id: &str,
+        result: Result<EmailOperation, ACSError>,
+    ) {
+        let retry_after = result
+            .as_ref()
+            .ok()
+            .and_then(|operation| parse_retry_after(&operation.headers, SystemTime::now()));
+        let result = result.and_then(EmailOperation::into_status);
+        let mut state = self.inner.state.lock().unwrap();
+        let Some(tracked) = state.operations.get_mut(operation_id) else {
+            return;
+        };
+        let finished = match &result {
+            Ok(status) => is_terminal_status(status),
+            Err(_) => true,
+        };
+        if finished {
+            client.events().finished(operation_id, &result);
+        } else if let Ok(status) = &result {
+            if tracked.last_status.replace(*status) != Some(*status) {
+                client.events().status_changed(operation_id, *status);
+            }
+        }
+        tracked
+            .subscribers
+            .retain(|tx| tx.send(result.clone()).is_ok());
+        if finished || (tracked.subscribers.is_empty() && tracked.watch.is_none()) {
+            state.operations.remove(operation_id);
+        } else {
+            tracked.in_flight = false;
+            tracked.attempts = tracked.attempts.saturating_add(1);
+            let delay = next_poll_delay(client.poll_strategy(), tracked.attempts, retry_after);
+            tracked.next_poll = Instant::now() + delay;
+        }
+    }
+}
@@ -0,0 +1,250 @@
+//! Delivery SLA watchdog for operations that stay pending too long.
+//!
+//! A [`DeliverySla`] installed with [`ACSClientBuilder::delivery_sla`] gives
+//! every operation the client sends, and every operation tracked by its
+//! shared status poller, a deadline.  Sent operations are tracked until they
+//! reach a terminal status even when nobody waits on them.  As soon as an
+//! operation has been `NotStarted` / `Running` for longer than its threshold,
+//! the status poller
+//!
+//! - publishes [`EmailEventKind::Overdue`] on the client's event bus, and
+//! - calls the handler set with [`DeliverySla::on_overdue`], if any.
+//!
+//! Deadlines are checked on a timer, not only when a poll completes, so a
+//! slow or retrying status GET does not delay the report.
+//!
+//! Each operation is reported at most once.  The threshold is chosen by the
+//! [`SendOptions::email_class`] tag of the send, falling back to the default
+//! threshold for untagged sends, unknown classes and operations that were not
+//! sent through the client.  Age is counted from the accepted send, or from
+//! when tracking started for operations sent elsewhere.
+//!
+//! [`ACSClient::sla_summary`] lists the operations that are overdue right now,
+//! for a health endpoint.
+//!
+//! ```rust,ignore
+//! let client = ACSClientBuilder::new()
+//!     .connection_string(&conn_str)
+//!     .delivery_sla(
+//!         DeliverySla::new(Duration::from_secs(120))
+//!             .class("newsletter", Duration::from_secs(900))
+//!             .on_overdue(|op| tracing::warn!(id = %op.operation_id, age = ?op.age, "email overdue")),
+//!     )
+//!     .build()?;
+//! ```
+//!
+//! [`ACSClientBuilder::delivery_sla`]: crate::adapters::gateways::acs_email::ACSClientBuilder::delivery_sla
+//! [`ACSClient::sla_summary`]: crate::adapters::gateways::acs_email::ACSClient::sla_summary
+//! [`SendOptions::email_class`]: crate::adapters::gateways::acs_email::SendOptions::email_class
+//! [`EmailEventKind::Overdue`]: crate::adapters::gateways::events::EmailEventKind::Overdue
+
+use crate::adapters::gateways::events::OperationMeta;
+use crate::domain::entities::models::EmailSendStatusType;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+type OverdueHandler = Arc<dyn Fn(&OverdueOperation) + Send + Sync>;
+
+/// Delivery deadlines for pending operations, optionally per email class.
+#[derive(Clone)]
+pub struct DeliverySla {
+    threshold: Duration,
+    classes: HashMap<String, Duration>,
+    handler: Option<OverdueHandler>,
+}
+
+impl fmt::Debug for DeliverySla {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DeliverySla")
+            .field("threshold", &self.threshold)
+            .field("classes", &self.classes)
+            .field("handler", &self.handler.is_some())
+            .finish()
+    }
+}
+
+impl DeliverySla {
+    /// Report operations still pending after `threshold`.
+    pub fn new(threshold: Duration) -> Self {
+        DeliverySla {
+            threshold,
+            classes: HashMap::new(),
+            handler: None,
+        }
+    }
+
+    /// Use `threshold` for sends tagged with [`SendOptions::email_class`]
+    /// `class`.
+    ///
+    /// [`SendOptions::email_class`]: crate::adapters::gateways::acs_email::SendOptions::email_class
+    pub fn class(mut self, class: &str, threshold: Duration) -> Self {
+        self.classes.insert(class.to_string(), threshold);
+        self
+    }
+
+    /// Call `handler` once for every operation that becomes overdue.
+    ///
+    /// The handler runs on the status poller's task, so it should return
+    /// quickly; hand slow work (paging, HTTP calls) off to another task.
+    pub fn on_overdue(
+        mut self,
+        handler: impl Fn(&OverdueOperation) + Send + Sync + 'static,
+    ) -> Self {
+        self.handler = Some(Arc::new(handler));
+        self
+    }
+
+    /// The threshold for operations of `class`.
+    pub fn threshold_for(&self, class: Option<&str>) -> Duration {
+        class
+            .and_then(|class| self.classes.get(class))
+            .copied()
+            .unwrap_or(self.threshold)
+    }
+
+    /// Start the clock for a newly tracked operation.
+    pub(crate) fn watch(&self, meta: Option<OperationMeta>) -> Watch {
+        let (since, email_class, correlation_id) = match meta {
+            Some(meta) => (meta.submitted_at, meta.email_class, meta.correlation_id),
+            None => (SystemTime::now(), None, None),
+        };
+        Watch {
+            threshold: self.threshold_for(email_class.as_deref()),
+            since,
+            email_class,
+            correlation_id,
+            reported: false,
+        }
+    }
+
+    pub(crate) fn notify(&self, operation: &OverdueOperation) {
+        if let Some(handler) = &self.handler {
+            handler(operation);
+        }
+    }
+}
+
+/// A pending operation that has exceeded its delivery SLA.
+#[derive(Clone, Debug)]
+pub struct OverdueOperation {
+    /// ACS operation ID.
+    pub operation_id: String,
+    /// Latest polled status; `NotStarted` before the first poll.
+    pub status: EmailSendStatusType,
+    /// Time since the send was accepted (or tracking started).
+    pub age: Duration,
+    /// The threshold that was exceeded.
+    pub threshold: Duration,
+    /// [`SendOptions::email_class`] of the send.
+    ///
+    /// [`SendOptions::email_class`]: crate::adapters::gateways::acs_email::SendOptions::email_class
+    pub email_class: Option<String>,
+    /// [`SendOptions::correlation_id`] of the send.
+    ///
+    /// [`SendOptions::correlation_id`]: crate::adapters::gateways::acs_email::SendOptions::correlation_id
+    pub correlation_id: Option<String>,
+}
+
+/// Snapshot returned by [`ACSClient::sla_summary`].
+///
+/// [`ACSClient::sla_summary`]: crate::adapters::gateways::acs_email::ACSClient::sla_summary
+#[derive(Clone, Debug, Default)]
+pub struct SlaSummary {
+    /// Pending operations currently under watch.
+    pub watched: usize,
+    /// Operations past their threshold, oldest first.
+    pub overdue: Vec<OverdueOperation>,
+}
+
+impl SlaSummary {
+    /// `true` when no operation is overdue.
+    pub fn is_healthy(&self) -> bool {
+        self.overdue.is_empty()
+    }
+}
+
+/// SLA state of one tracked operation.
+#[derive(Debug)]
+pub(crate) struct Watch {
+    threshold: Duration,
+    since: SystemTime,
+    email_class: Option<String>,
+    correlation_id: Option<String>,
+    /// Set once the operation has been reported overdue.
+    pub(crate) reported: bool,
+}
+
+impl Watch {
+    /// When the operation becomes overdue.
+    pub(crate) fn deadline(&self) -> SystemTime {
+        self.since + self.threshold
+    }
+
+    /// The operation as an [`OverdueOperation`] if it is past its threshold
+    /// at `now`.
+    pub(crate) fn check(
+        &self,
+        operation_id: &str,
+        status: Option<EmailSendStatusType>,
+        now: SystemTime,
+    ) -> Option<OverdueOperation> {
+        let age = now.duration_since(self.since).unwrap_or_default();
+        (age >= self.threshold).then(|| OverdueOperation {
+            operation_id: operation_id.to_string(),
+            status: status.unwrap_or(EmailSendStatusType::NotStarted),
+            age,
+            threshold: self.threshold,
+            email_class: self.email_class.clone(),
+            correlation_id: self.correlation_id.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn meta(class: Option<&str>, age: Duration) -> OperationMeta {
+        OperationMeta {
+            submitted_at: SystemTime::now() - age,
+            correlation_id: Some("order-1".to_string()),
+            email_class: class.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn class_threshold_overrides_default() {
+        let sla = DeliverySla::new(Duration::from_secs(120))
+            .class("newsletter", Duration::from_secs(900));
+        assert_eq!(sla.threshold_for(None), Duration::from_secs(120));
+        assert_eq!(
+            sla.threshold_for(Some("newsletter")),
+            Duration::from_secs(900)
+        );
+        assert_eq!(sla.threshold_for(Some("other")), Duration::from_secs(120));
+    }
+
+    #[test]
+    fn watch_counts_age_from_submission() {
+        let sla = DeliverySla::new(Duration::from_secs(120))
+            .class("newsletter", Duration::from_secs(900));
+        let late = sla.watch(Some(meta(None, Duration::from_secs(300))));
+        let tolerant = sla.watch(Some(meta(Some("newsletter"), Duration::from_secs(300))));
+        let now = SystemTime::now();
+
+        let overdue = late.check("op-1", None, now).unwrap();
+        assert_eq!(overdue.status, EmailSendStatusType::NotStarted);
+        assert_eq!(overdue.threshold, Duration::from_secs(120));
+        assert!(overdue.age >= Duration::from_secs(300));
+        assert_eq!(overdue.correlation_id.as_deref(), Some("order-1"));
+
+        assert!(tolerant
+            .check("op-2", Some(EmailSendStatusType::Running), now)
+            .is_none());
+
+        let untracked = sla.watch(None);
+        assert!(untracked.check("op-3", None, SystemTime::now()).is_none());
+    }
+}
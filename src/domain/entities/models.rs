@@ -5,6 +5,7 @@
 //! | Type | Role |
 //! |---|---|
 //! | [`ACSError`] | Typed error returned by every public `ACSClient` method |
+//! | [`ACSErrorKind`] | Field-less discriminant of `ACSError` for classification |
 //! | [`SentEmail`] / [`SentEmailBuilder`] | Top-level email payload |
 //! | [`EmailAttachment`] / [`EmailAttachmentBuilder`] | File attachment with sync and async build paths |
 //! | [`EmailSendStatusType`] | Delivery status enum (`NotStarted`, `Running`, `Succeeded`, …) |
@@ -26,9 +27,10 @@ use std::io::Read;
 use std::path::Path;
 use std::path::PathBuf;
 use std::str::FromStr;
+use std::time::Duration;
 
 /// Typed error returned by all public `ACSClient` methods.
-#[derive(Debug, thiserror::Error)]
+#[derive(Clone, Debug, thiserror::Error)]
 pub enum ACSError {
     /// HTTP request could not be sent (DNS, TLS, connection refused, …).
     #[error("network error: {0}")]
@@ -66,8 +68,15 @@ pub enum ACSError {
     MissingField(&'static str),
 
     /// Rate limit hit and all retries were exhausted.
+    ///
+    /// `retry_after` is the back-off the service requested on the final
+    /// throttled response (clamped to the retry policy's maximum), or `None`
+    /// when the response carried no throttling header.
     #[error("rate limit exceeded after {retries} retries")]
-    RateLimitExceeded { retries: u32 },
+    RateLimitExceeded {
+        retries: u32,
+        retry_after: Option<Duration>,
+    },
 
     /// [`send_email_and_wait`] did not observe a terminal status within the given timeout.
     ///
@@ -83,6 +92,90 @@ pub enum ACSError {
     /// [`send_email_and_wait_cancellable`]: crate::adapters::gateways::acs_email::ACSClient::send_email_and_wait_cancellable
     #[error("polling cancelled by caller before terminal status was observed")]
     Canceled,
+
+    /// The client-side rate limiter had no capacity and is configured to fail
+    /// fast.  `retry_after` is the time until the send would fit.
+    #[error("client-side send quota exhausted; capacity available in {retry_after:?}")]
+    QuotaExhausted { retry_after: Duration },
+
+    /// A bulk-job checkpoint file could not be read or written.
+    #[error("checkpoint error: {0}")]
+    Checkpoint(String),
+
+    /// ACS reported the email operation as `Failed`.
+    ///
+    /// `error` is the error tree ACS attached to the operation — its `code`,
+    /// `message`, nested `details` and `additional_info` distinguish, for
+    /// example, a rejected recipient from a blocked sender.  `attempts` lists
+    /// the operation IDs of every send of the email, oldest first and ending
+    /// with `operation_id`; it has more than one entry only when a
+    /// [`ResendPolicy`] resent the email before giving up.
+    ///
+    /// [`ResendPolicy`]: crate::adapters::gateways::resend::ResendPolicy
+    #[error("delivery failed for operation {operation_id}: {}", describe_error_detail(error.as_deref()))]
+    DeliveryFailed {
+        operation_id: String,
+        error: Option<Box<ErrorDetail>>,
+        attempts: Vec<String>,
+    },
+}
+
+fn describe_error_detail(detail: Option<&ErrorDetail>) -> String {
+    match detail {
+        Some(ErrorDetail {
+            code,
+            message: Some(message),
+            ..
+        }) => format!("{}: {}", code.as_deref().unwrap_or("unknown"), message),
+        Some(ErrorDetail {
+            code: Some(code), ..
+        }) => code.clone(),
+        _ => "no error details".to_string(),
+    }
+}
+
+/// Field-less discriminant of [`ACSError`].
+///
+/// Used where errors are classified rather than inspected — e.g. to declare
+/// which failures a retry policy treats as transient.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ACSErrorKind {
+    Network,
+    InvalidUrl,
+    Serialization,
+    Deserialization,
+    Auth,
+    Header,
+    Api,
+    MissingField,
+    RateLimitExceeded,
+    Timeout,
+    Canceled,
+    QuotaExhausted,
+    Checkpoint,
+    DeliveryFailed,
+}
+
+impl ACSError {
+    /// Return the [`ACSErrorKind`] of this error.
+    pub fn kind(&self) -> ACSErrorKind {
+        match self {
+            ACSError::Network(_) => ACSErrorKind::Network,
+            ACSError::InvalidUrl(_) => ACSErrorKind::InvalidUrl,
+            ACSError::Serialization(_) => ACSErrorKind::Serialization,
+            ACSError::Deserialization(_) => ACSErrorKind::Deserialization,
+            ACSError::Auth(_) => ACSErrorKind::Auth,
+            ACSError::Header(_) => ACSErrorKind::Header,
+            ACSError::Api { .. } => ACSErrorKind::Api,
+            ACSError::MissingField(_) => ACSErrorKind::MissingField,
+            ACSError::RateLimitExceeded { .. } => ACSErrorKind::RateLimitExceeded,
+            ACSError::Timeout => ACSErrorKind::Timeout,
+            ACSError::Canceled => ACSErrorKind::Canceled,
+            ACSError::QuotaExhausted { .. } => ACSErrorKind::QuotaExhausted,
+            ACSError::Checkpoint(_) => ACSErrorKind::Checkpoint,
+            ACSError::DeliveryFailed { .. } => ACSErrorKind::DeliveryFailed,
+        }
+    }
 }
 
 impl From<ErrorResponse> for ACSError {
@@ -113,7 +206,7 @@ impl EmailSendStatus {
 }
 
 /// Enum representing the possible statuses of an email send operation.
-#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
 pub enum EmailSendStatusType {
     Unknown,
     Canceled,
@@ -140,7 +233,7 @@ pub struct SentEmailResponse {
 }
 
 /// Represents the details of an error.
-#[derive(Serialize, Deserialize, Debug, Default)]
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
 pub struct ErrorDetail {
     /// Additional information about the error.
     #[serde(rename = "additionalInfo")]
@@ -157,14 +250,19 @@ pub struct ErrorDetail {
     /// The target of the error.
     #[serde(rename = "target")]
     pub target: Option<String>,
+
+    /// Nested errors that caused this one.
+    #[serde(rename = "details", default, skip_serializing_if = "Option::is_none")]
+    pub details: Option<Vec<ErrorDetail>>,
 }
 
 /// Represents additional information about an error.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct ErrorAdditionalInfo {
-    /// The additional information.
+    /// The additional information — an arbitrary JSON value whose shape
+    /// depends on `info_type`.
     #[serde(rename = "info")]
-    pub info: Option<String>,
+    pub info: Option<serde_json::Value>,
 
     /// The type of the additional information.
     #[serde(rename = "type")]
@@ -577,6 +675,16 @@ pub struct Recipients {
     pub b_cc: Option<Vec<EmailAddress>>,
 }
 
+impl Recipients {
+    /// Total number of `to`, `cc` and `bcc` addresses.
+    pub fn count(&self) -> usize {
+        [&self.to, &self.cc, &self.b_cc]
+            .into_iter()
+            .map(|list| list.as_ref().map_or(0, Vec::len))
+            .sum()
+    }
+}
+
 /// Represents an email address.
 #[derive(Serialize, Deserialize, Debug)]
 pub struct EmailAddress {
@@ -696,6 +804,41 @@ mod tests {
     use super::*;
     use serde_json::json;
 
+    #[test]
+    fn acs_error_kind_matches_variant() {
+        assert_eq!(
+            ACSError::Network("x".to_string()).kind(),
+            ACSErrorKind::Network
+        );
+        assert_eq!(
+            ACSError::RateLimitExceeded {
+                retries: 1,
+                retry_after: None
+            }
+            .kind(),
+            ACSErrorKind::RateLimitExceeded
+        );
+        assert_eq!(ACSError::Timeout.kind(), ACSErrorKind::Timeout);
+        assert_eq!(
+            ACSError::MissingField("id").kind(),
+            ACSErrorKind::MissingField
+        );
+    }
+
+    #[test]
+    fn recipients_count_sums_all_lists() {
+        let address = || EmailAddress {
+            email: Some("a@example.com".to_string()),
+            display_name: None,
+        };
+        let recipients = Recipients {
+            to: Some(vec![address(), address()]),
+            cc: None,
+            b_cc: Some(vec![address()]),
+        };
+        assert_eq!(recipients.count(), 3);
+    }
+
     #[test]
     fn sent_email_builder_with_missing_content() {
         let result = SentEmailBuilder::new()
@@ -1112,7 +1255,7 @@ mod tests {
 
         let json = serde_json::to_value(&result).unwrap();
         assert_eq!(json["contentType"], "image/png");
-        assert!(json["contentInBase64"].as_str().unwrap().len() > 0);
+        assert!(!json["contentInBase64"].as_str().unwrap().is_empty());
     }
 
     #[tokio::test]
@@ -1134,7 +1277,6 @@ mod tests {
 
     #[tokio::test]
     async fn build_async_sets_filename_from_path() {
-        use std::io::Write;
         let dir = tempfile::tempdir().unwrap();
         let file_path = dir.path().join("report.pdf");
         std::fs::write(&file_path, b"%PDF-1.4").unwrap();
@@ -2,9 +2,10 @@
 //!
 //! Rust client library for the [Azure Email Communication Service (ACS)] REST API.
 //!
-//! Supports sending emails and polling delivery status with three authentication
-//! methods, configurable retry/timeout, async attachment loading, and structured
-//! `tracing` telemetry — all over the ACS data-plane API.
+//! Supports sending emails and polling delivery status with shared-key or
+//! Entra ID authentication, configurable retry/timeout, async attachment
+//! loading, and structured `tracing` telemetry — all over the ACS data-plane
+//! API.
 //!
 //! [Azure Email Communication Service (ACS)]: https://learn.microsoft.com/en-us/azure/communication-services/concepts/email/email-overview
 //!
@@ -52,13 +53,32 @@
 //!
 //! | Method | Builder call |
 //! |---|---|
-//! | Shared Key | `.connection_string("endpoint=...;accesskey=...")` |
+//! | Shared Key | `.connection_string("endpoint=...;accesskey=...")`, optionally `.secondary_key(key)` |
 //! | Service Principal | `.host(url).service_principal(tenant, client_id, secret)` |
+//! | Service Principal (certificate) | `.host(url).service_principal_certificate(tenant, client_id, path)` (`certificate` feature) |
 //! | Managed Identity | `.managed_identity().host(url)` |
+//! | User-assigned Managed Identity | `.host(url).managed_identity_client_id(id)` or `.managed_identity_resource_id(id)` |
+//! | Workload Identity | `.host(url).workload_identity(tenant, client_id, token_file)` |
+//! | Credential chain | `.host(url).default_credential_chain()` or `.credential_chain(chain)` |
+//! | Caller-supplied token | `.host(url).token_credential(credential)`, `.static_token(token)` or `.token_provider(closure)` |
+//!
+//! Entra ID tokens are cached and refreshed ahead of expiry
+//! ([`token_cache`](adapters::gateways::token_cache)).  Sovereign and private
+//! clouds are selected with `.cloud(AzureCloud::UsGov)` and friends
+//! ([`cloud`](adapters::gateways::cloud)), and shared keys can be rotated at
+//! runtime with `ACSClient::rotate_key`
+//! ([`access_keys`](adapters::gateways::access_keys)).  See the
+//! [`credentials`](adapters::gateways::credentials) and
+//! [`credential_chain`](adapters::gateways::credential_chain) modules for the
+//! credential types behind these builder calls.
 //!
 //! ## Feature flags
 //!
-//! This crate has no optional Cargo features. All functionality is always available.
+//! | Feature | Enables |
+//! |---|---|
+//! | `certificate` | Service principal authentication with a client certificate (`ClientCertificateCredential`, `.service_principal_certificate(..)`); links OpenSSL |
+//!
+//! No features are enabled by default.
 //!
 //! ## API versions
 //!
@@ -69,7 +89,23 @@ pub mod domain;
 
 pub mod adapters {
     pub mod gateways {
+        pub mod access_keys;
         pub mod acs_email;
         mod acs_shared_key;
+        pub mod batch;
+        pub mod bulk;
+        pub mod cloud;
+        pub mod concurrency;
+        pub mod credential_chain;
+        pub mod credentials;
+        pub mod events;
+        pub mod poll;
+        pub mod poller;
+        pub mod rate_limit;
+        pub mod resend;
+        pub mod retry;
+        pub mod token_cache;
+        pub mod tracker;
+        pub mod watchdog;
     }
 }